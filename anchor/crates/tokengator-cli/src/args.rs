@@ -0,0 +1,406 @@
+//! Serde mirrors of the program instruction arguments.
+//!
+//! The program types only implement Borsh, these are what config files are read into
+//! and what `--dry-run` prints back, converting both ways.
+
+use anchor_lang::prelude::Pubkey;
+use serde::{Deserialize, Serialize};
+use tokengator_client::tokengator_minter::{
+    self as program,
+    args::{MinterApplicationConfigArgs, PaymentConfigArgs},
+    state,
+};
+
+use crate::config::pubkey;
+
+#[derive(Serialize, Deserialize)]
+pub enum IdentityProvider {
+    Discord,
+    GitHub,
+    Google,
+    Twitter,
+    Telegram,
+    Solana,
+    EmailDomain(String),
+    Oidc(String),
+}
+
+impl From<IdentityProvider> for state::IdentityProvider {
+    fn from(provider: IdentityProvider) -> Self {
+        match provider {
+            IdentityProvider::Discord => Self::Discord,
+            IdentityProvider::GitHub => Self::GitHub,
+            IdentityProvider::Google => Self::Google,
+            IdentityProvider::Twitter => Self::Twitter,
+            IdentityProvider::Telegram => Self::Telegram,
+            IdentityProvider::Solana => Self::Solana,
+            IdentityProvider::EmailDomain(value) => Self::EmailDomain(value),
+            IdentityProvider::Oidc(value) => Self::Oidc(value),
+        }
+    }
+}
+
+impl From<state::IdentityProvider> for IdentityProvider {
+    fn from(provider: state::IdentityProvider) -> Self {
+        match provider {
+            state::IdentityProvider::Discord => Self::Discord,
+            state::IdentityProvider::GitHub => Self::GitHub,
+            state::IdentityProvider::Google => Self::Google,
+            state::IdentityProvider::Twitter => Self::Twitter,
+            state::IdentityProvider::Telegram => Self::Telegram,
+            state::IdentityProvider::Solana => Self::Solana,
+            state::IdentityProvider::EmailDomain(value) => Self::EmailDomain(value),
+            state::IdentityProvider::Oidc(value) => Self::Oidc(value),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PaymentTier {
+    #[serde(default)]
+    pub label: Option<String>,
+    pub price: u64,
+    #[serde(with = "pubkey")]
+    pub mint: Pubkey,
+    #[serde(default)]
+    pub days: Option<u16>,
+}
+
+impl From<PaymentTier> for state::PaymentTier {
+    fn from(tier: PaymentTier) -> Self {
+        Self {
+            label: tier.label,
+            price: tier.price,
+            mint: tier.mint,
+            days: tier.days,
+        }
+    }
+}
+
+impl From<state::PaymentTier> for PaymentTier {
+    fn from(tier: state::PaymentTier) -> Self {
+        Self {
+            label: tier.label,
+            price: tier.price,
+            mint: tier.mint,
+            days: tier.days,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PaymentConfig {
+    pub amount: u16,
+    pub price: u64,
+    #[serde(with = "pubkey")]
+    pub mint: Pubkey,
+    pub days: u8,
+}
+
+impl From<PaymentConfig> for PaymentConfigArgs {
+    fn from(config: PaymentConfig) -> Self {
+        Self {
+            amount: config.amount,
+            price: config.price,
+            mint: config.mint,
+            days: config.days,
+        }
+    }
+}
+
+impl From<PaymentConfigArgs> for PaymentConfig {
+    fn from(config: PaymentConfigArgs) -> Self {
+        Self {
+            amount: config.amount,
+            price: config.price,
+            mint: config.mint,
+            days: config.days,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ApplicationConfig {
+    #[serde(default)]
+    pub identities: Vec<IdentityProvider>,
+    pub payment_tiers: Vec<PaymentTier>,
+}
+
+impl From<ApplicationConfig> for MinterApplicationConfigArgs {
+    fn from(config: ApplicationConfig) -> Self {
+        Self {
+            identities: config.identities.into_iter().map(Into::into).collect(),
+            payment_tiers: config.payment_tiers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<MinterApplicationConfigArgs> for ApplicationConfig {
+    fn from(config: MinterApplicationConfigArgs) -> Self {
+        Self {
+            identities: config.identities.into_iter().map(Into::into).collect(),
+            payment_tiers: config.payment_tiers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MetadataConfig {
+    pub name: String,
+    pub symbol: String,
+    #[serde(default)]
+    pub metadata: Option<Vec<[String; 2]>>,
+    pub uri: String,
+}
+
+impl From<MetadataConfig> for state::MinterMetadataConfig {
+    fn from(config: MetadataConfig) -> Self {
+        Self {
+            name: config.name,
+            symbol: config.symbol,
+            metadata: config.metadata,
+            uri: config.uri,
+        }
+    }
+}
+
+impl From<state::MinterMetadataConfig> for MetadataConfig {
+    fn from(config: state::MinterMetadataConfig) -> Self {
+        Self {
+            name: config.name,
+            symbol: config.symbol,
+            metadata: config.metadata,
+            uri: config.uri,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct InterestConfig {
+    pub rate: i16,
+}
+
+impl From<InterestConfig> for state::MinterInterestConfig {
+    fn from(config: InterestConfig) -> Self {
+        Self { rate: config.rate }
+    }
+}
+
+impl From<state::MinterInterestConfig> for InterestConfig {
+    fn from(config: state::MinterInterestConfig) -> Self {
+        Self { rate: config.rate }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TransferFeeConfig {
+    pub transfer_fee_basis_points: u16,
+    pub max_fee_rate: u64,
+}
+
+impl From<TransferFeeConfig> for state::MinterTransferFeeConfig {
+    fn from(config: TransferFeeConfig) -> Self {
+        Self {
+            transfer_fee_basis_points: config.transfer_fee_basis_points,
+            max_fee_rate: config.max_fee_rate,
+        }
+    }
+}
+
+impl From<state::MinterTransferFeeConfig> for TransferFeeConfig {
+    fn from(config: state::MinterTransferFeeConfig) -> Self {
+        Self {
+            transfer_fee_basis_points: config.transfer_fee_basis_points,
+            max_fee_rate: config.max_fee_rate,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateMinterWNSArgs {
+    pub community: String,
+    pub name: String,
+    pub description: String,
+    pub image_url: String,
+    pub payment_config: PaymentConfig,
+    pub application_config: ApplicationConfig,
+    pub metadata_config: MetadataConfig,
+    #[serde(default)]
+    pub interest_config: Option<InterestConfig>,
+    #[serde(default)]
+    pub transfer_fee_config: Option<TransferFeeConfig>,
+    #[serde(default)]
+    pub transferable: bool,
+    #[serde(default)]
+    pub website: Option<String>,
+    #[serde(default)]
+    pub royalty_basis_points: Option<u16>,
+}
+
+impl From<CreateMinterWNSArgs> for program::CreateMinterWNSArgs {
+    fn from(args: CreateMinterWNSArgs) -> Self {
+        Self {
+            community: args.community,
+            name: args.name,
+            description: args.description,
+            image_url: args.image_url,
+            payment_config: args.payment_config.into(),
+            application_config: args.application_config.into(),
+            metadata_config: args.metadata_config.into(),
+            interest_config: args.interest_config.map(Into::into),
+            transfer_fee_config: args.transfer_fee_config.map(Into::into),
+            transferable: args.transferable,
+            website: args.website,
+            royalty_basis_points: args.royalty_basis_points,
+        }
+    }
+}
+
+impl From<program::CreateMinterWNSArgs> for CreateMinterWNSArgs {
+    fn from(args: program::CreateMinterWNSArgs) -> Self {
+        Self {
+            community: args.community,
+            name: args.name,
+            description: args.description,
+            image_url: args.image_url,
+            payment_config: args.payment_config.into(),
+            application_config: args.application_config.into(),
+            metadata_config: args.metadata_config.into(),
+            interest_config: args.interest_config.map(Into::into),
+            transfer_fee_config: args.transfer_fee_config.map(Into::into),
+            transferable: args.transferable,
+            website: args.website,
+            royalty_basis_points: args.royalty_basis_points,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AddMinterAuthorityArgs {
+    #[serde(with = "pubkey")]
+    pub new_authority: Pubkey,
+}
+
+impl From<AddMinterAuthorityArgs> for program::AddMinterAuthorityArgs {
+    fn from(args: AddMinterAuthorityArgs) -> Self {
+        Self {
+            new_authority: args.new_authority,
+        }
+    }
+}
+
+impl From<program::AddMinterAuthorityArgs> for AddMinterAuthorityArgs {
+    fn from(args: program::AddMinterAuthorityArgs) -> Self {
+        Self {
+            new_authority: args.new_authority,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RemoveMinterAuthorityArgs {
+    #[serde(with = "pubkey")]
+    pub authority_to_remove: Pubkey,
+}
+
+impl From<RemoveMinterAuthorityArgs> for program::RemoveMinterAuthorityArgs {
+    fn from(args: RemoveMinterAuthorityArgs) -> Self {
+        Self {
+            authority_to_remove: args.authority_to_remove,
+        }
+    }
+}
+
+impl From<program::RemoveMinterAuthorityArgs> for RemoveMinterAuthorityArgs {
+    fn from(args: program::RemoveMinterAuthorityArgs) -> Self {
+        Self {
+            authority_to_remove: args.authority_to_remove,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateMemberMetadataWNSArgs {
+    pub field: String,
+    pub new_value: String,
+}
+
+impl From<UpdateMemberMetadataWNSArgs> for program::UpdateMemberMetadataWNSArgs {
+    fn from(args: UpdateMemberMetadataWNSArgs) -> Self {
+        Self {
+            field: args.field,
+            new_value: args.new_value,
+        }
+    }
+}
+
+impl From<program::UpdateMemberMetadataWNSArgs> for UpdateMemberMetadataWNSArgs {
+    fn from(args: program::UpdateMemberMetadataWNSArgs) -> Self {
+        Self {
+            field: args.field,
+            new_value: args.new_value,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateActivityArgs {
+    pub label: String,
+    #[serde(default)]
+    pub start_date: Option<i64>,
+    #[serde(default)]
+    pub end_date: Option<i64>,
+}
+
+impl From<CreateActivityArgs> for program::CreateActivityArgs {
+    fn from(args: CreateActivityArgs) -> Self {
+        Self {
+            label: args.label,
+            start_date: args.start_date,
+            end_date: args.end_date,
+        }
+    }
+}
+
+impl From<program::CreateActivityArgs> for CreateActivityArgs {
+    fn from(args: program::CreateActivityArgs) -> Self {
+        Self {
+            label: args.label,
+            start_date: args.start_date,
+            end_date: args.end_date,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AppendActivityEntryArgs {
+    #[serde(default)]
+    pub timestamp: Option<i64>,
+    pub message: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub points: Option<u8>,
+}
+
+impl From<AppendActivityEntryArgs> for program::AppendActivityEntryArgs {
+    fn from(args: AppendActivityEntryArgs) -> Self {
+        Self {
+            timestamp: args.timestamp,
+            message: args.message,
+            url: args.url,
+            points: args.points,
+        }
+    }
+}
+
+impl From<program::AppendActivityEntryArgs> for AppendActivityEntryArgs {
+    fn from(args: program::AppendActivityEntryArgs) -> Self {
+        Self {
+            timestamp: args.timestamp,
+            message: args.message,
+            url: args.url,
+            points: args.points,
+        }
+    }
+}
@@ -0,0 +1,483 @@
+//! One builder per command, deriving every account offline from the config.
+
+use anchor_lang::{prelude::Pubkey, solana_program::sysvar, system_program};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id},
+    token_2022,
+};
+use anyhow::Result;
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use tokengator_client::{instructions::minter, pda, tokengator_minter::accounts, WNS_PROGRAM_ID};
+
+use crate::config::*;
+
+/// Instructions of a command together with every keypair that has to sign them
+pub struct Built {
+    pub instructions: Vec<Instruction>,
+    pub recent_blockhash: Hash,
+    pub fee_payer: Keypair,
+    pub signers: Vec<Keypair>,
+}
+
+fn minter_address(minter: &MinterRef) -> Pubkey {
+    pda::minter(&minter.mint, &minter.name).0
+}
+
+pub fn create_minter_wns(config: CreateMinterWNSConfig) -> Result<Built> {
+    let fee_payer = config.signers.fee_payer()?;
+    let authority = config.signers.authority()?;
+    let mint = read_keypair(&config.mint)?;
+
+    let mint_key = mint.pubkey();
+    let authority_key = authority.pubkey();
+    let minter = pda::minter(&mint_key, &config.args.name).0;
+
+    let instruction = minter::create_minter_wns(
+        accounts::CreateMinterWNS {
+            group: pda::wns_group(&mint_key).0,
+            manager: pda::wns_manager().0,
+            receipt: pda::receipt(&config.receipt_sender, &authority_key, &config.payment_mint).0,
+            minter,
+            minter_token_account: get_associated_token_address_with_program_id(
+                &minter,
+                &mint_key,
+                &token_2022::ID,
+            ),
+            mint: mint_key,
+            fee_payer: fee_payer.pubkey(),
+            authority: authority_key,
+            authority_token_account: get_associated_token_address_with_program_id(
+                &authority_key,
+                &config.payment_mint,
+                &token_2022::ID,
+            ),
+            protocol_config: pda::protocol_config().0,
+            payment_mint: config.payment_mint,
+            rent: sysvar::rent::ID,
+            wns_program: WNS_PROGRAM_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        config.args.into(),
+    );
+
+    Ok(Built {
+        instructions: vec![instruction],
+        recent_blockhash: config.signers.recent_blockhash()?,
+        fee_payer,
+        signers: vec![authority, mint],
+    })
+}
+
+pub fn add_minter_authority(config: AddMinterAuthorityConfig) -> Result<Built> {
+    let fee_payer = config.signers.fee_payer()?;
+    let authority = config.signers.authority()?;
+
+    let instruction = minter::add_minter_authority(
+        accounts::AddMinterAuthority {
+            minter: minter_address(&config.minter),
+            authority: authority.pubkey(),
+            fee_payer: fee_payer.pubkey(),
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        },
+        config.args.into(),
+    );
+
+    Ok(Built {
+        instructions: vec![instruction],
+        recent_blockhash: config.signers.recent_blockhash()?,
+        fee_payer,
+        signers: vec![authority],
+    })
+}
+
+pub fn remove_minter_authority(config: RemoveMinterAuthorityConfig) -> Result<Built> {
+    let fee_payer = config.signers.fee_payer()?;
+    let authority = config.signers.authority()?;
+
+    let instruction = minter::remove_minter_authority(
+        accounts::RemoveMinterAuthority {
+            minter: minter_address(&config.minter),
+            fee_payer: fee_payer.pubkey(),
+            authority: authority.pubkey(),
+            protocol_config: pda::protocol_config().0,
+        },
+        config.args.into(),
+    );
+
+    Ok(Built {
+        instructions: vec![instruction],
+        recent_blockhash: config.signers.recent_blockhash()?,
+        fee_payer,
+        signers: vec![authority],
+    })
+}
+
+pub fn update_member_metadata_wns(config: UpdateMemberMetadataWNSConfig) -> Result<Built> {
+    let fee_payer = config.signers.fee_payer()?;
+
+    let instruction = minter::update_member_metadata_wns(
+        accounts::UpdateMemberMetadataWNS {
+            minter: minter_address(&config.minter),
+            group: pda::wns_group(&config.minter.mint).0,
+            member: pda::wns_member(&config.member_mint).0,
+            mint: config.member_mint,
+            fee_payer: fee_payer.pubkey(),
+            protocol_config: pda::protocol_config().0,
+            wns_program: WNS_PROGRAM_ID,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        config.args.into(),
+    );
+
+    Ok(Built {
+        instructions: vec![instruction],
+        recent_blockhash: config.signers.recent_blockhash()?,
+        fee_payer,
+        signers: vec![],
+    })
+}
+
+pub fn create_activity(config: CreateActivityConfig) -> Result<Built> {
+    let fee_payer = config.signers.fee_payer()?;
+
+    let instruction = minter::create_activity(
+        accounts::CreateActivity {
+            activity: pda::activity(&config.member_mint, &config.args.label).0,
+            minter: minter_address(&config.minter),
+            group: pda::wns_group(&config.minter.mint).0,
+            member: pda::wns_member(&config.member_mint).0,
+            mint: config.member_mint,
+            fee_payer: fee_payer.pubkey(),
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        },
+        config.args.into(),
+    );
+
+    Ok(Built {
+        instructions: vec![instruction],
+        recent_blockhash: config.signers.recent_blockhash()?,
+        fee_payer,
+        signers: vec![],
+    })
+}
+
+pub fn append_activity_entry(config: AppendActivityEntryConfig) -> Result<Built> {
+    let fee_payer = config.signers.fee_payer()?;
+
+    let instruction = minter::append_activity_entry(
+        accounts::AppendActivityEntry {
+            activity: pda::activity(&config.member_mint, &config.label).0,
+            minter: minter_address(&config.minter),
+            fee_payer: fee_payer.pubkey(),
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        },
+        config.args.into(),
+    );
+
+    Ok(Built {
+        instructions: vec![instruction],
+        recent_blockhash: config.signers.recent_blockhash()?,
+        fee_payer,
+        signers: vec![],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use anchor_lang::ToAccountMetas;
+    use solana_sdk::signature::write_keypair_file;
+
+    use super::*;
+    use crate::args::*;
+
+    fn write_keypair(name: &str) -> (PathBuf, Pubkey) {
+        let keypair = Keypair::new();
+        let path =
+            std::env::temp_dir().join(format!("tokengator-cli-{}-{name}.json", std::process::id()));
+        write_keypair_file(&keypair, &path).unwrap();
+        (path, keypair.pubkey())
+    }
+
+    /// Writes a fresh fee payer and optional authority keypair for one test
+    fn signers(test: &str, authority: bool) -> (SignerConfig, Pubkey, Option<Pubkey>) {
+        let (fee_payer_path, fee_payer) = write_keypair(&format!("{test}-fee-payer"));
+        let (authority_path, authority) = if authority {
+            let (path, pubkey) = write_keypair(&format!("{test}-authority"));
+            (Some(path), Some(pubkey))
+        } else {
+            (None, None)
+        };
+
+        let config = SignerConfig {
+            recent_blockhash: Hash::new_unique().to_string(),
+            fee_payer: fee_payer_path,
+            authority: authority_path,
+        };
+
+        (config, fee_payer, authority)
+    }
+
+    fn minter_ref() -> MinterRef {
+        MinterRef {
+            mint: Pubkey::new_unique(),
+            name: "Badges".to_owned(),
+        }
+    }
+
+    fn signer_keys(built: &Built) -> Vec<Pubkey> {
+        built.signers.iter().map(Signer::pubkey).collect()
+    }
+
+    #[test]
+    fn create_minter_wns_derives_accounts() {
+        let (signers, fee_payer, authority) = signers("create-minter", true);
+        let authority = authority.unwrap();
+        let (mint_path, mint) = write_keypair("create-minter-mint");
+        let receipt_sender = Pubkey::new_unique();
+        let payment_mint = Pubkey::new_unique();
+
+        let config = CreateMinterWNSConfig {
+            signers,
+            mint: mint_path,
+            receipt_sender,
+            payment_mint,
+            args: CreateMinterWNSArgs {
+                community: "TokenGator".to_owned(),
+                name: "Badges".to_owned(),
+                description: "Community badges".to_owned(),
+                image_url: "https://tokengator.app/badges.png".to_owned(),
+                payment_config: PaymentConfig {
+                    amount: 1,
+                    price: 0,
+                    mint: payment_mint,
+                    days: 30,
+                },
+                application_config: ApplicationConfig {
+                    identities: vec![],
+                    payment_tiers: vec![],
+                },
+                metadata_config: MetadataConfig {
+                    name: "Badge".to_owned(),
+                    symbol: "BDG".to_owned(),
+                    metadata: None,
+                    uri: "https://tokengator.app/badge.json".to_owned(),
+                },
+                interest_config: None,
+                transfer_fee_config: None,
+                transferable: false,
+                website: None,
+                royalty_basis_points: None,
+            },
+        };
+
+        let built = create_minter_wns(config).unwrap();
+        let minter = pda::minter(&mint, "Badges").0;
+        let expected = accounts::CreateMinterWNS {
+            group: pda::wns_group(&mint).0,
+            manager: pda::wns_manager().0,
+            receipt: pda::receipt(&receipt_sender, &authority, &payment_mint).0,
+            minter,
+            minter_token_account: get_associated_token_address_with_program_id(
+                &minter,
+                &mint,
+                &token_2022::ID,
+            ),
+            mint,
+            fee_payer,
+            authority,
+            authority_token_account: get_associated_token_address_with_program_id(
+                &authority,
+                &payment_mint,
+                &token_2022::ID,
+            ),
+            protocol_config: pda::protocol_config().0,
+            payment_mint,
+            rent: sysvar::rent::ID,
+            wns_program: WNS_PROGRAM_ID,
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        };
+
+        assert_eq!(built.instructions.len(), 1);
+        assert_eq!(
+            built.instructions[0].accounts,
+            expected.to_account_metas(None)
+        );
+        assert_eq!(built.fee_payer.pubkey(), fee_payer);
+        assert_eq!(signer_keys(&built), vec![authority, mint]);
+    }
+
+    #[test]
+    fn add_minter_authority_derives_accounts() {
+        let (signers, fee_payer, authority) = signers("add-authority", true);
+        let authority = authority.unwrap();
+        let minter = minter_ref();
+        let expected = accounts::AddMinterAuthority {
+            minter: pda::minter(&minter.mint, &minter.name).0,
+            authority,
+            fee_payer,
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        };
+
+        let built = add_minter_authority(AddMinterAuthorityConfig {
+            signers,
+            minter,
+            args: AddMinterAuthorityArgs {
+                new_authority: Pubkey::new_unique(),
+            },
+        })
+        .unwrap();
+
+        assert_eq!(
+            built.instructions[0].accounts,
+            expected.to_account_metas(None)
+        );
+        assert_eq!(signer_keys(&built), vec![authority]);
+    }
+
+    #[test]
+    fn remove_minter_authority_derives_accounts() {
+        let (signers, fee_payer, authority) = signers("remove-authority", true);
+        let authority = authority.unwrap();
+        let minter = minter_ref();
+        let expected = accounts::RemoveMinterAuthority {
+            minter: pda::minter(&minter.mint, &minter.name).0,
+            fee_payer,
+            authority,
+            protocol_config: pda::protocol_config().0,
+        };
+
+        let built = remove_minter_authority(RemoveMinterAuthorityConfig {
+            signers,
+            minter,
+            args: RemoveMinterAuthorityArgs {
+                authority_to_remove: Pubkey::new_unique(),
+            },
+        })
+        .unwrap();
+
+        assert_eq!(
+            built.instructions[0].accounts,
+            expected.to_account_metas(None)
+        );
+        assert_eq!(signer_keys(&built), vec![authority]);
+    }
+
+    #[test]
+    fn update_member_metadata_wns_derives_accounts() {
+        let (signers, fee_payer, _) = signers("update-metadata", false);
+        let minter = minter_ref();
+        let member_mint = Pubkey::new_unique();
+        let expected = accounts::UpdateMemberMetadataWNS {
+            minter: pda::minter(&minter.mint, &minter.name).0,
+            group: pda::wns_group(&minter.mint).0,
+            member: pda::wns_member(&member_mint).0,
+            mint: member_mint,
+            fee_payer,
+            protocol_config: pda::protocol_config().0,
+            wns_program: WNS_PROGRAM_ID,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        };
+
+        let built = update_member_metadata_wns(UpdateMemberMetadataWNSConfig {
+            signers,
+            minter,
+            member_mint,
+            args: UpdateMemberMetadataWNSArgs {
+                field: "role".to_owned(),
+                new_value: "moderator".to_owned(),
+            },
+        })
+        .unwrap();
+
+        assert_eq!(
+            built.instructions[0].accounts,
+            expected.to_account_metas(None)
+        );
+        assert!(built.signers.is_empty());
+    }
+
+    #[test]
+    fn create_activity_derives_accounts() {
+        let (signers, fee_payer, _) = signers("create-activity", false);
+        let minter = minter_ref();
+        let member_mint = Pubkey::new_unique();
+        let expected = accounts::CreateActivity {
+            activity: pda::activity(&member_mint, "hackathon").0,
+            minter: pda::minter(&minter.mint, &minter.name).0,
+            group: pda::wns_group(&minter.mint).0,
+            member: pda::wns_member(&member_mint).0,
+            mint: member_mint,
+            fee_payer,
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        };
+
+        let built = create_activity(CreateActivityConfig {
+            signers,
+            minter,
+            member_mint,
+            args: CreateActivityArgs {
+                label: "hackathon".to_owned(),
+                start_date: None,
+                end_date: None,
+            },
+        })
+        .unwrap();
+
+        assert_eq!(
+            built.instructions[0].accounts,
+            expected.to_account_metas(None)
+        );
+        assert!(built.signers.is_empty());
+    }
+
+    #[test]
+    fn append_activity_entry_derives_accounts() {
+        let (signers, fee_payer, _) = signers("append-entry", false);
+        let minter = minter_ref();
+        let member_mint = Pubkey::new_unique();
+        let expected = accounts::AppendActivityEntry {
+            activity: pda::activity(&member_mint, "hackathon").0,
+            minter: pda::minter(&minter.mint, &minter.name).0,
+            fee_payer,
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        };
+
+        let built = append_activity_entry(AppendActivityEntryConfig {
+            signers,
+            minter,
+            member_mint,
+            label: "hackathon".to_owned(),
+            args: AppendActivityEntryArgs {
+                timestamp: None,
+                message: "Shipped the demo".to_owned(),
+                url: None,
+                points: Some(5),
+            },
+        })
+        .unwrap();
+
+        assert_eq!(
+            built.instructions[0].accounts,
+            expected.to_account_metas(None)
+        );
+        assert!(built.signers.is_empty());
+    }
+}
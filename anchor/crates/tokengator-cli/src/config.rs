@@ -0,0 +1,264 @@
+//! Command config files, read from TOML or JSON depending on the file extension.
+
+use std::{fs, path::Path, path::PathBuf};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{de::DeserializeOwned, Deserialize};
+use solana_sdk::{
+    hash::Hash,
+    signature::{read_keypair_file, Keypair},
+};
+
+use crate::args::*;
+
+/// Serializes pubkeys as base58 strings instead of byte arrays
+pub mod pubkey {
+    use std::str::FromStr;
+
+    use anchor_lang::prelude::Pubkey;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(pubkey)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Pubkey::from_str(&value).map_err(D::Error::custom)
+    }
+}
+
+pub fn load<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => {
+            toml::from_str(&contents).with_context(|| format!("parsing {}", path.display()))
+        }
+        Some("json") => {
+            serde_json::from_str(&contents).with_context(|| format!("parsing {}", path.display()))
+        }
+        _ => bail!("{} must be a .toml or .json file", path.display()),
+    }
+}
+
+pub fn read_keypair(path: &Path) -> Result<Keypair> {
+    read_keypair_file(path).map_err(|err| anyhow!("reading keypair {}: {err}", path.display()))
+}
+
+/// Signers and blockhash shared by every command
+#[derive(Deserialize)]
+pub struct SignerConfig {
+    pub recent_blockhash: String,
+    pub fee_payer: PathBuf,
+    #[serde(default)]
+    pub authority: Option<PathBuf>,
+}
+
+impl SignerConfig {
+    pub fn recent_blockhash(&self) -> Result<Hash> {
+        self.recent_blockhash
+            .parse()
+            .map_err(|err| anyhow!("invalid recent_blockhash: {err}"))
+    }
+
+    pub fn fee_payer(&self) -> Result<Keypair> {
+        read_keypair(&self.fee_payer)
+    }
+
+    pub fn authority(&self) -> Result<Keypair> {
+        let path = self
+            .authority
+            .as_ref()
+            .ok_or_else(|| anyhow!("authority keypair is required for this command"))?;
+
+        read_keypair(path)
+    }
+}
+
+/// Existing minter, addressed by its collection mint and name
+#[derive(Deserialize)]
+pub struct MinterRef {
+    #[serde(with = "pubkey")]
+    pub mint: Pubkey,
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+pub struct CreateMinterWNSConfig {
+    #[serde(flatten)]
+    pub signers: SignerConfig,
+    // Keypair of the new collection mint
+    pub mint: PathBuf,
+    #[serde(with = "pubkey")]
+    pub receipt_sender: Pubkey,
+    #[serde(with = "pubkey")]
+    pub payment_mint: Pubkey,
+    pub args: CreateMinterWNSArgs,
+}
+
+#[derive(Deserialize)]
+pub struct AddMinterAuthorityConfig {
+    #[serde(flatten)]
+    pub signers: SignerConfig,
+    pub minter: MinterRef,
+    pub args: AddMinterAuthorityArgs,
+}
+
+#[derive(Deserialize)]
+pub struct RemoveMinterAuthorityConfig {
+    #[serde(flatten)]
+    pub signers: SignerConfig,
+    pub minter: MinterRef,
+    pub args: RemoveMinterAuthorityArgs,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateMemberMetadataWNSConfig {
+    #[serde(flatten)]
+    pub signers: SignerConfig,
+    pub minter: MinterRef,
+    #[serde(with = "pubkey")]
+    pub member_mint: Pubkey,
+    pub args: UpdateMemberMetadataWNSArgs,
+}
+
+#[derive(Deserialize)]
+pub struct CreateActivityConfig {
+    #[serde(flatten)]
+    pub signers: SignerConfig,
+    pub minter: MinterRef,
+    #[serde(with = "pubkey")]
+    pub member_mint: Pubkey,
+    pub args: CreateActivityArgs,
+}
+
+#[derive(Deserialize)]
+pub struct AppendActivityEntryConfig {
+    #[serde(flatten)]
+    pub signers: SignerConfig,
+    pub minter: MinterRef,
+    #[serde(with = "pubkey")]
+    pub member_mint: Pubkey,
+    pub label: String,
+    pub args: AppendActivityEntryArgs,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp(name: &str, contents: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("tokengator-cli-{}-{name}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn loads_toml_config() {
+        let mint = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+        let blockhash = Hash::new_unique();
+        let path = write_temp(
+            "add-authority.toml",
+            &format!(
+                r#"
+recent_blockhash = "{blockhash}"
+fee_payer = "payer.json"
+authority = "authority.json"
+
+[minter]
+mint = "{mint}"
+name = "Badges"
+
+[args]
+new_authority = "{new_authority}"
+"#
+            ),
+        );
+
+        let config: AddMinterAuthorityConfig = load(&path).unwrap();
+
+        assert_eq!(config.signers.recent_blockhash().unwrap(), blockhash);
+        assert_eq!(config.signers.fee_payer, PathBuf::from("payer.json"));
+        assert_eq!(
+            config.signers.authority,
+            Some(PathBuf::from("authority.json"))
+        );
+        assert_eq!(config.minter.mint, mint);
+        assert_eq!(config.minter.name, "Badges");
+        assert_eq!(config.args.new_authority, new_authority);
+    }
+
+    #[test]
+    fn loads_json_config() {
+        let mint = Pubkey::new_unique();
+        let member_mint = Pubkey::new_unique();
+        let blockhash = Hash::new_unique();
+        let path = write_temp(
+            "append-entry.json",
+            &serde_json::json!({
+                "recent_blockhash": blockhash.to_string(),
+                "fee_payer": "payer.json",
+                "minter": { "mint": mint.to_string(), "name": "Badges" },
+                "member_mint": member_mint.to_string(),
+                "label": "hackathon",
+                "args": { "message": "Shipped the demo", "points": 5 }
+            })
+            .to_string(),
+        );
+
+        let config: AppendActivityEntryConfig = load(&path).unwrap();
+
+        assert_eq!(config.signers.recent_blockhash().unwrap(), blockhash);
+        assert_eq!(config.signers.authority, None);
+        assert_eq!(config.minter.mint, mint);
+        assert_eq!(config.member_mint, member_mint);
+        assert_eq!(config.label, "hackathon");
+        assert_eq!(config.args.message, "Shipped the demo");
+        assert_eq!(config.args.points, Some(5));
+        assert_eq!(config.args.timestamp, None);
+        assert_eq!(config.args.url, None);
+    }
+
+    #[test]
+    fn load_rejects_unknown_extension() {
+        let path = write_temp("config.yaml", "recent_blockhash: x");
+
+        let err = load::<AddMinterAuthorityConfig>(&path).err().unwrap();
+
+        assert!(err.to_string().contains("must be a .toml or .json file"));
+    }
+
+    #[test]
+    fn load_rejects_invalid_pubkey() {
+        let path = write_temp(
+            "invalid-pubkey.json",
+            &serde_json::json!({
+                "recent_blockhash": Hash::new_unique().to_string(),
+                "fee_payer": "payer.json",
+                "minter": { "mint": "not-a-pubkey", "name": "Badges" },
+                "args": { "authority_to_remove": Pubkey::new_unique().to_string() }
+            })
+            .to_string(),
+        );
+
+        assert!(load::<RemoveMinterAuthorityConfig>(&path).is_err());
+    }
+
+    #[test]
+    fn signer_config_requires_authority() {
+        let signers = SignerConfig {
+            recent_blockhash: Hash::new_unique().to_string(),
+            fee_payer: PathBuf::from("payer.json"),
+            authority: None,
+        };
+
+        let err = signers.authority().err().unwrap();
+
+        assert!(err.to_string().contains("authority keypair is required"));
+    }
+}
@@ -0,0 +1,88 @@
+//! Builds and signs tokengator-minter admin transactions without a network connection.
+//!
+//! Every command reads a TOML or JSON config holding the keypair paths, a recent
+//! blockhash and the instruction arguments, and prints the signed transaction as
+//! base64, ready to be broadcast from another machine. `inspect` decodes saved
+//! account data to JSON instead.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+mod args;
+mod commands;
+mod config;
+mod snapshot;
+mod transaction;
+
+#[derive(Parser)]
+#[command(name = "tokengator", version, about)]
+struct Cli {
+    /// Print the decoded transaction as JSON instead of the base64 encoding
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a minter backed by a new WNS group
+    CreateMinterWns { config: PathBuf },
+    /// Add an authority to a minter
+    AddMinterAuthority { config: PathBuf },
+    /// Remove an authority from a minter
+    RemoveMinterAuthority { config: PathBuf },
+    /// Update a metadata field of a WNS member
+    UpdateMemberMetadataWns { config: PathBuf },
+    /// Create an activity for a member
+    CreateActivity { config: PathBuf },
+    /// Append an entry to a member activity
+    AppendActivityEntry { config: PathBuf },
+    /// Decode raw account data or an account snapshot to JSON
+    Inspect { file: PathBuf },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let built = match cli.command {
+        Command::CreateMinterWns { config } => commands::create_minter_wns(config::load(&config)?),
+        Command::AddMinterAuthority { config } => {
+            commands::add_minter_authority(config::load(&config)?)
+        }
+        Command::RemoveMinterAuthority { config } => {
+            commands::remove_minter_authority(config::load(&config)?)
+        }
+        Command::UpdateMemberMetadataWns { config } => {
+            commands::update_member_metadata_wns(config::load(&config)?)
+        }
+        Command::CreateActivity { config } => commands::create_activity(config::load(&config)?),
+        Command::AppendActivityEntry { config } => {
+            commands::append_activity_entry(config::load(&config)?)
+        }
+        Command::Inspect { file } => {
+            let inspected = snapshot::inspect_file(&file)?;
+            println!("{}", serde_json::to_string_pretty(&inspected)?);
+            return Ok(());
+        }
+    }?;
+
+    let signed = transaction::sign(built)?;
+    let encoded = transaction::encode(&signed)?;
+
+    if cli.dry_run {
+        // Decode the serialized bytes rather than `signed`, so the output shows exactly what would be sent
+        let decoded = transaction::decode(&encoded)?;
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&transaction::describe(&decoded)?)?
+        );
+    } else {
+        println!("{encoded}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! Account snapshots read by `inspect`.
+//!
+//! Accepts raw account data, the output of `solana account --output json`, or a
+//! `getAccountInfo` RPC response saved to a file.
+
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{Map, Value};
+use tokengator_client::inspect::inspect;
+
+// Finds the account object in the supported JSON layouts
+fn account_object(snapshot: &Value) -> Option<&Map<String, Value>> {
+    snapshot
+        .get("account")
+        .or_else(|| snapshot.pointer("/result/value"))
+        .unwrap_or(snapshot)
+        .as_object()
+}
+
+fn account_data(account: &Map<String, Value>) -> Result<Vec<u8>> {
+    match account.get("data") {
+        Some(Value::Array(data)) => match (data.first(), data.get(1)) {
+            (Some(Value::String(data)), Some(Value::String(encoding))) if encoding == "base64" => {
+                STANDARD.decode(data).context("decoding account data")
+            }
+            (_, Some(encoding)) => bail!("unsupported account data encoding {encoding}"),
+            _ => bail!("account data must be a [data, encoding] pair"),
+        },
+        _ => bail!("snapshot has no account data"),
+    }
+}
+
+pub fn inspect_file(path: &Path) -> Result<Value> {
+    let contents = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+
+    let Ok(snapshot) = serde_json::from_slice::<Value>(&contents) else {
+        return inspect(&contents).map_err(|err| anyhow!("{}: {err}", path.display()));
+    };
+
+    let account = account_object(&snapshot)
+        .ok_or_else(|| anyhow!("{} is not an account snapshot", path.display()))?;
+
+    let mut inspected =
+        inspect(&account_data(account)?).map_err(|err| anyhow!("{}: {err}", path.display()))?;
+
+    // Keep the snapshot context next to the decoded data
+    for key in ["pubkey", "owner", "lamports"] {
+        if let Some(value) = snapshot.get(key).or_else(|| account.get(key)) {
+            inspected[key] = value.clone();
+        }
+    }
+
+    Ok(inspected)
+}
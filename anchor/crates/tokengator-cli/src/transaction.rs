@@ -0,0 +1,194 @@
+//! Signing, base64 encoding and the `--dry-run` decoding of built transactions.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use tokengator_client::{tokengator_minter::instruction, TOKENGATOR_MINTER_PROGRAM_ID};
+
+use crate::args::*;
+use crate::commands::Built;
+
+pub fn sign(built: Built) -> Result<Transaction> {
+    let mut transaction =
+        Transaction::new_with_payer(&built.instructions, Some(&built.fee_payer.pubkey()));
+
+    let mut signers: Vec<&Keypair> = vec![&built.fee_payer];
+    signers.extend(built.signers.iter());
+
+    transaction
+        .try_sign(&signers, built.recent_blockhash)
+        .context("signing transaction")?;
+
+    Ok(transaction)
+}
+
+pub fn encode(transaction: &Transaction) -> Result<String> {
+    Ok(STANDARD.encode(bincode::serialize(transaction)?))
+}
+
+pub fn decode(encoded: &str) -> Result<Transaction> {
+    let bytes = STANDARD.decode(encoded.trim()).context("decoding base64")?;
+    bincode::deserialize(&bytes).context("deserializing transaction")
+}
+
+macro_rules! instruction_decoders {
+    ($data:ident, $( $name:literal => $ix:ident($args:ty); )*) => {
+        $(
+            if $data.starts_with(&instruction::$ix::DISCRIMINATOR) {
+                let ix = instruction::$ix::try_from_slice(&$data[8..])?;
+                return Ok(Some(($name, serde_json::to_value(<$args>::from(ix.args))?)));
+            }
+        )*
+    };
+}
+
+/// Decodes the instructions this tool builds, `None` for anything else
+fn decode_minter_instruction(data: &[u8]) -> Result<Option<(&'static str, Value)>> {
+    instruction_decoders!(
+        data,
+        "create_minter_wns" => CreateMinterWns(CreateMinterWNSArgs);
+        "add_minter_authority" => AddMinterAuthority(AddMinterAuthorityArgs);
+        "remove_minter_authority" => RemoveMinterAuthority(RemoveMinterAuthorityArgs);
+        "update_member_metadata_wns" => UpdateMemberMetadataWns(UpdateMemberMetadataWNSArgs);
+        "create_activity" => CreateActivity(CreateActivityArgs);
+        "append_activity_entry" => AppendActivityEntry(AppendActivityEntryArgs);
+    );
+
+    Ok(None)
+}
+
+/// Human readable view of a signed transaction, printed by `--dry-run`
+pub fn describe(transaction: &Transaction) -> Result<Value> {
+    let message = &transaction.message;
+
+    let instructions = message
+        .instructions
+        .iter()
+        .map(|compiled| {
+            let program_id = message.account_keys[usize::from(compiled.program_id_index)];
+
+            let accounts: Vec<Value> = compiled
+                .accounts
+                .iter()
+                .map(|&index| {
+                    let index = usize::from(index);
+                    json!({
+                        "pubkey": message.account_keys[index].to_string(),
+                        "signer": message.is_signer(index),
+                        "writable": message.is_writable(index),
+                    })
+                })
+                .collect();
+
+            let decoded = if program_id == TOKENGATOR_MINTER_PROGRAM_ID {
+                decode_minter_instruction(&compiled.data)?
+            } else {
+                None
+            };
+
+            Ok(match decoded {
+                Some((name, args)) => json!({
+                    "program": program_id.to_string(),
+                    "instruction": name,
+                    "accounts": accounts,
+                    "args": args,
+                }),
+                None => json!({
+                    "program": program_id.to_string(),
+                    "accounts": accounts,
+                    "data": STANDARD.encode(&compiled.data),
+                }),
+            })
+        })
+        .collect::<Result<Vec<Value>>>()?;
+
+    Ok(json!({
+        "fee_payer": message.account_keys[0].to_string(),
+        "recent_blockhash": message.recent_blockhash.to_string(),
+        "signatures": transaction
+            .signatures
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>(),
+        "verified": transaction.verify().is_ok(),
+        "instructions": instructions,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{prelude::Pubkey, system_program};
+    use solana_sdk::hash::Hash;
+    use tokengator_client::{instructions::minter, tokengator_minter};
+
+    #[test]
+    fn dry_run_decodes_signed_transaction() {
+        let fee_payer = Keypair::new();
+        let authority = Keypair::new();
+        let minter_key = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+
+        let built = Built {
+            instructions: vec![minter::add_minter_authority(
+                tokengator_minter::accounts::AddMinterAuthority {
+                    minter: minter_key,
+                    authority: authority.pubkey(),
+                    fee_payer: fee_payer.pubkey(),
+                    protocol_config: Pubkey::new_unique(),
+                    system_program: system_program::ID,
+                },
+                tokengator_minter::AddMinterAuthorityArgs { new_authority },
+            )],
+            recent_blockhash: Hash::new_unique(),
+            fee_payer,
+            signers: vec![authority],
+        };
+
+        let transaction = decode(&encode(&sign(built).unwrap()).unwrap()).unwrap();
+        let described = describe(&transaction).unwrap();
+
+        assert_eq!(described["verified"], true);
+        assert_eq!(described["signatures"].as_array().unwrap().len(), 2);
+
+        let instruction = &described["instructions"][0];
+        assert_eq!(instruction["instruction"], "add_minter_authority");
+        assert_eq!(
+            instruction["args"]["new_authority"],
+            new_authority.to_string()
+        );
+        assert_eq!(instruction["accounts"][0]["pubkey"], minter_key.to_string());
+        assert_eq!(instruction["accounts"][0]["writable"], true);
+        assert_eq!(instruction["accounts"][1]["signer"], true);
+    }
+
+    #[test]
+    fn sign_rejects_missing_signer() {
+        let fee_payer = Keypair::new();
+
+        let built = Built {
+            instructions: vec![minter::add_minter_authority(
+                tokengator_minter::accounts::AddMinterAuthority {
+                    minter: Pubkey::new_unique(),
+                    authority: Pubkey::new_unique(),
+                    fee_payer: fee_payer.pubkey(),
+                    protocol_config: Pubkey::new_unique(),
+                    system_program: system_program::ID,
+                },
+                tokengator_minter::AddMinterAuthorityArgs {
+                    new_authority: Pubkey::new_unique(),
+                },
+            )],
+            recent_blockhash: Hash::new_unique(),
+            fee_payer,
+            signers: vec![],
+        };
+
+        assert!(sign(built).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+//! Account deserializers for raw account data, checking the Anchor discriminator.
+
+use anchor_lang::{AccountDeserialize, Result};
+
+pub use tokengator_minter::state::{
+    Activity, Coupon, Group, IdentityClaim, IdentityLink, Minter, ProtocolConfig, Receipt,
+    Subscription,
+};
+pub use wen_new_standard::{
+    ApproveAccount, GroupAuthorityTransfer, Manager, MasterEdition, TokenGroup, TokenGroupMember,
+};
+
+/// Decodes any Anchor account, failing when the discriminator does not match `T`
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    let mut data = data;
+    T::try_deserialize(&mut data)
+}
+
+macro_rules! account_decoders {
+    ($( $name:ident => $account:ty; )*) => {
+        $(
+            pub fn $name(data: &[u8]) -> Result<$account> {
+                decode::<$account>(data)
+            }
+        )*
+    };
+}
+
+account_decoders!(
+    decode_protocol_config => ProtocolConfig;
+    decode_minter => Minter;
+    decode_receipt => Receipt;
+    decode_activity => Activity;
+    decode_coupon => Coupon;
+    decode_subscription => Subscription;
+    decode_identity_link => IdentityLink;
+    decode_identity_claim => IdentityClaim;
+    decode_legacy_group => Group;
+    decode_wns_manager => Manager;
+    decode_wns_group => TokenGroup;
+    decode_wns_member => TokenGroupMember;
+    decode_wns_approve_account => ApproveAccount;
+    decode_wns_group_authority_transfer => GroupAuthorityTransfer;
+    decode_wns_master_edition => MasterEdition;
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{prelude::Pubkey, AccountSerialize};
+
+    fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = vec![];
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn decodes_serialized_account() {
+        let group = TokenGroup::new(&Pubkey::new_unique(), Pubkey::new_unique(), 100);
+
+        let decoded = decode_wns_group(&serialize(&group)).unwrap();
+
+        assert_eq!(decoded.mint, group.mint);
+        assert_eq!(decoded.update_authority, group.update_authority);
+        assert_eq!(decoded.max_size, 100);
+        assert_eq!(decoded.size, 0);
+    }
+
+    #[test]
+    fn rejects_other_account_types() {
+        let member = TokenGroupMember::new(&Pubkey::new_unique(), &Pubkey::new_unique(), 1);
+
+        assert!(decode_wns_group(&serialize(&member)).is_err());
+        assert!(decode_wns_member(&serialize(&member)).is_ok());
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let edition = MasterEdition {
+            group: Pubkey::new_unique(),
+            supply: 3,
+            max_supply: 10,
+        };
+        let data = serialize(&edition);
+
+        assert!(decode_wns_master_edition(&data[..data.len() - 1]).is_err());
+        assert_eq!(decode_wns_master_edition(&data).unwrap().supply, 3);
+    }
+
+    #[test]
+    fn decodes_minter_serialized_before_transferable() {
+        use tokengator_minter::state::{
+            MinterApplicationConfig, MinterConfig, MinterMetadataConfig, PaymentConfig,
+            TrailingBool,
+        };
+
+        let minter = Minter {
+            bump: 255,
+            community_id: Pubkey::new_unique(),
+            group: Pubkey::new_unique(),
+            name: "business-visa".to_owned(),
+            description: "Business visa holders".to_owned(),
+            image_url: "https://tokengator.app/visa.png".to_owned(),
+            fee_payer: Pubkey::new_unique(),
+            authorities: vec![Pubkey::new_unique()],
+            payment_config: PaymentConfig::default(),
+            minter_config: MinterConfig {
+                mint: Pubkey::new_unique(),
+                application_config: MinterApplicationConfig {
+                    identities: vec![],
+                    payment_config: PaymentConfig::default(),
+                },
+                metadata_config: MinterMetadataConfig {
+                    name: "Business Visa".to_owned(),
+                    symbol: "VISA".to_owned(),
+                    metadata: None,
+                    uri: "https://tokengator.app/visa.json".to_owned(),
+                },
+                interest_config: None,
+                transfer_fee_config: None,
+            },
+            paused: false,
+            payment_tiers: vec![],
+            transferable: TrailingBool(true),
+        };
+        let data = serialize(&minter);
+
+        // Accounts written before the flag was appended end right before it
+        assert!(!decode_minter(&data[..data.len() - 1])
+            .unwrap()
+            .is_transferable());
+        assert!(decode_minter(&data).unwrap().is_transferable());
+    }
+}
@@ -0,0 +1,481 @@
+//! JSON views of raw account data, detecting the account type on its own.
+//!
+//! Anchor accounts are recognised by their discriminator, anything else is read as a
+//! Token-2022 mint or token account together with its extensions.
+
+use anchor_lang::{
+    prelude::{ProgramError, Pubkey},
+    solana_program::program_pack::Pack,
+    Discriminator, Result,
+};
+use serde_json::{json, Map, Value};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_2022::{
+    extension::{
+        group_member_pointer::GroupMemberPointer,
+        group_pointer::GroupPointer,
+        interest_bearing_mint::InterestBearingConfig,
+        metadata_pointer::MetadataPointer,
+        mint_close_authority::MintCloseAuthority,
+        permanent_delegate::PermanentDelegate,
+        transfer_fee::{TransferFee, TransferFeeAmount, TransferFeeConfig},
+        transfer_hook::TransferHook,
+        AccountType, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    state::{Account as TokenAccount, Mint},
+};
+use spl_token_group_interface::state::{
+    TokenGroup as NativeTokenGroup, TokenGroupMember as NativeTokenGroupMember,
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+use tokengator_minter::state::{
+    IdentityProvider, MinterApplicationConfig, MinterConfig, PaymentConfig, PaymentTier,
+    ReceiptType,
+};
+
+use crate::accounts::*;
+
+/// Decodes any supported account into `{ "type": ..., "data": ... }`
+pub fn inspect(data: &[u8]) -> Result<Value> {
+    let (account_type, value) = if data.starts_with(&Minter::DISCRIMINATOR) {
+        ("Minter", minter(&decode_minter(data)?))
+    } else if data.starts_with(&Receipt::DISCRIMINATOR) {
+        ("Receipt", receipt(&decode_receipt(data)?))
+    } else if data.starts_with(&Activity::DISCRIMINATOR) {
+        ("Activity", activity(&decode_activity(data)?))
+    } else if data.starts_with(&Group::DISCRIMINATOR) {
+        ("Group", legacy_group(&decode_legacy_group(data)?))
+    } else if data.starts_with(&TokenGroup::DISCRIMINATOR) {
+        ("TokenGroup", wns_group(&decode_wns_group(data)?))
+    } else if data.starts_with(&TokenGroupMember::DISCRIMINATOR) {
+        ("TokenGroupMember", wns_member(&decode_wns_member(data)?))
+    } else {
+        match token_account_type(data) {
+            Some(AccountType::Mint) => ("Mint", mint(data)?),
+            Some(AccountType::Account) => ("TokenAccount", token_account(data)?),
+            _ => return Err(ProgramError::InvalidAccountData.into()),
+        }
+    };
+
+    Ok(json!({ "type": account_type, "data": value }))
+}
+
+// Plain mints and token accounts are told apart by length, extended ones by the account type byte
+fn token_account_type(data: &[u8]) -> Option<AccountType> {
+    match data.len() {
+        Mint::LEN => Some(AccountType::Mint),
+        TokenAccount::LEN => Some(AccountType::Account),
+        len if len > TokenAccount::LEN => AccountType::try_from(data[TokenAccount::LEN]).ok(),
+        _ => None,
+    }
+}
+
+fn optional_pubkey(pubkey: OptionalNonZeroPubkey) -> Value {
+    json!(Option::<Pubkey>::from(pubkey).map(|pubkey| pubkey.to_string()))
+}
+
+fn pubkeys(pubkeys: &[Pubkey]) -> Value {
+    json!(pubkeys.iter().map(Pubkey::to_string).collect::<Vec<_>>())
+}
+
+/* tokengator_minter */
+
+fn identity_provider(provider: &IdentityProvider) -> Value {
+    match provider {
+        IdentityProvider::EmailDomain(value) | IdentityProvider::Oidc(value) => {
+            json!({ provider.get_provider(): value })
+        }
+        _ => json!(provider.get_provider()),
+    }
+}
+
+fn payment_config(config: &PaymentConfig) -> Value {
+    json!({
+        "amount": config.amount,
+        "price": config.price,
+        "mint": config.mint.to_string(),
+        "days": config.days,
+        "expires_at": config.expires_at,
+    })
+}
+
+fn application_config(config: &MinterApplicationConfig) -> Value {
+    json!({
+        "identities": config.identities.iter().map(identity_provider).collect::<Vec<_>>(),
+        "payment_config": payment_config(&config.payment_config),
+    })
+}
+
+fn payment_tier(tier: &PaymentTier) -> Value {
+    json!({
+        "label": tier.label,
+        "price": tier.price,
+        "mint": tier.mint.to_string(),
+        "days": tier.days,
+    })
+}
+
+fn minter_config(config: &MinterConfig) -> Value {
+    let metadata_config = &config.metadata_config;
+
+    json!({
+        "mint": config.mint.to_string(),
+        "application_config": application_config(&config.application_config),
+        "metadata_config": {
+            "name": metadata_config.name,
+            "symbol": metadata_config.symbol,
+            "metadata": metadata_config.metadata,
+            "uri": metadata_config.uri,
+        },
+        "interest_config": config.interest_config.as_ref().map(|interest_config| json!({
+            "rate": interest_config.rate,
+        })),
+        "transfer_fee_config": config.transfer_fee_config.as_ref().map(|transfer_fee_config| json!({
+            "transfer_fee_basis_points": transfer_fee_config.transfer_fee_basis_points,
+            "max_fee_rate": transfer_fee_config.max_fee_rate,
+        })),
+    })
+}
+
+fn minter(minter: &Minter) -> Value {
+    json!({
+        "bump": minter.bump,
+        "community_id": minter.community_id.to_string(),
+        "group": minter.group.to_string(),
+        "name": minter.name,
+        "description": minter.description,
+        "image_url": minter.image_url,
+        "fee_payer": minter.fee_payer.to_string(),
+        "authorities": pubkeys(&minter.authorities),
+        "payment_config": payment_config(&minter.payment_config),
+        "minter_config": minter_config(&minter.minter_config),
+        "paused": minter.paused,
+        "payment_tiers": minter.payment_tiers.iter().map(payment_tier).collect::<Vec<_>>(),
+        "transferable": minter.is_transferable(),
+    })
+}
+
+fn receipt(receipt: &Receipt) -> Value {
+    json!({
+        "bump": receipt.bump,
+        "payment_type": match receipt.payment_type {
+            ReceiptType::User => "User",
+            ReceiptType::Community => "Community",
+        },
+        "created_at": receipt.created_at,
+        "payment_amount": receipt.payment_amount,
+        "sender": receipt.sender.to_string(),
+        "receiver": receipt.receiver.to_string(),
+        "sender_token_account": receipt.sender_token_account.to_string(),
+        "receiver_token_account": receipt.receiver_token_account.to_string(),
+        "payment_mint": receipt.payment_mint.to_string(),
+        "payment_tier": receipt.payment_tier,
+        "coupon": receipt.coupon.map(|coupon| coupon.to_string()),
+        "protocol_fee": receipt.protocol_fee,
+    })
+}
+
+fn activity(activity: &Activity) -> Value {
+    json!({
+        "bump": activity.bump,
+        "label": activity.label,
+        "start_date": activity.start_date,
+        "end_date": activity.end_date,
+        "fee_payer": activity.fee_payer.to_string(),
+        "minter": activity.minter.to_string(),
+        "member": activity.member.to_string(),
+        "mint": activity.mint.to_string(),
+        "entries": activity
+            .entries
+            .iter()
+            .map(|entry| json!({
+                "timestamp": entry.timestamp,
+                "message": entry.message,
+                "url": entry.url,
+                "points": entry.points,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn legacy_group(group: &Group) -> Value {
+    json!({
+        "update_authority": group.update_authority.to_string(),
+        "mint": group.mint.to_string(),
+        "size": group.size,
+        "max_size": group.max_size,
+    })
+}
+
+/* wen_new_standard */
+
+fn wns_group(group: &TokenGroup) -> Value {
+    json!({
+        "update_authority": group.update_authority.to_string(),
+        "mint": group.mint.to_string(),
+        "size": group.size,
+        "max_size": group.max_size,
+        "member_count": group.member_count,
+    })
+}
+
+fn wns_member(member: &TokenGroupMember) -> Value {
+    json!({
+        "mint": member.mint.to_string(),
+        "group": member.group.to_string(),
+        "member_number": member.member_number,
+    })
+}
+
+/* spl_token_2022 */
+
+fn transfer_fee(fee: &TransferFee) -> Value {
+    json!({
+        "epoch": u64::from(fee.epoch),
+        "maximum_fee": u64::from(fee.maximum_fee),
+        "transfer_fee_basis_points": u16::from(fee.transfer_fee_basis_points),
+    })
+}
+
+fn token_metadata(metadata: &TokenMetadata) -> Value {
+    json!({
+        "update_authority": optional_pubkey(metadata.update_authority),
+        "mint": metadata.mint.to_string(),
+        "name": metadata.name,
+        "symbol": metadata.symbol,
+        "uri": metadata.uri,
+        "additional_metadata": metadata.additional_metadata,
+    })
+}
+
+fn mint_extension<S: BaseStateWithExtensions<Mint>>(
+    state: &S,
+    extension_type: ExtensionType,
+) -> Result<Value> {
+    Ok(match extension_type {
+        ExtensionType::MintCloseAuthority => {
+            let extension = state.get_extension::<MintCloseAuthority>()?;
+            json!({ "close_authority": optional_pubkey(extension.close_authority) })
+        }
+        ExtensionType::MetadataPointer => {
+            let extension = state.get_extension::<MetadataPointer>()?;
+            json!({
+                "authority": optional_pubkey(extension.authority),
+                "metadata_address": optional_pubkey(extension.metadata_address),
+            })
+        }
+        ExtensionType::GroupPointer => {
+            let extension = state.get_extension::<GroupPointer>()?;
+            json!({
+                "authority": optional_pubkey(extension.authority),
+                "group_address": optional_pubkey(extension.group_address),
+            })
+        }
+        ExtensionType::GroupMemberPointer => {
+            let extension = state.get_extension::<GroupMemberPointer>()?;
+            json!({
+                "authority": optional_pubkey(extension.authority),
+                "member_address": optional_pubkey(extension.member_address),
+            })
+        }
+        ExtensionType::TransferFeeConfig => {
+            let extension = state.get_extension::<TransferFeeConfig>()?;
+            json!({
+                "transfer_fee_config_authority": optional_pubkey(extension.transfer_fee_config_authority),
+                "withdraw_withheld_authority": optional_pubkey(extension.withdraw_withheld_authority),
+                "withheld_amount": u64::from(extension.withheld_amount),
+                "older_transfer_fee": transfer_fee(&extension.older_transfer_fee),
+                "newer_transfer_fee": transfer_fee(&extension.newer_transfer_fee),
+            })
+        }
+        ExtensionType::InterestBearingConfig => {
+            let extension = state.get_extension::<InterestBearingConfig>()?;
+            json!({
+                "rate_authority": optional_pubkey(extension.rate_authority),
+                "initialization_timestamp": i64::from(extension.initialization_timestamp),
+                "pre_update_average_rate": i16::from(extension.pre_update_average_rate),
+                "last_update_timestamp": i64::from(extension.last_update_timestamp),
+                "current_rate": i16::from(extension.current_rate),
+            })
+        }
+        ExtensionType::PermanentDelegate => {
+            let extension = state.get_extension::<PermanentDelegate>()?;
+            json!({ "delegate": optional_pubkey(extension.delegate) })
+        }
+        ExtensionType::TransferHook => {
+            let extension = state.get_extension::<TransferHook>()?;
+            json!({
+                "authority": optional_pubkey(extension.authority),
+                "program_id": optional_pubkey(extension.program_id),
+            })
+        }
+        ExtensionType::TokenMetadata => {
+            token_metadata(&state.get_variable_len_extension::<TokenMetadata>()?)
+        }
+        ExtensionType::TokenGroup => {
+            let extension = state.get_extension::<NativeTokenGroup>()?;
+            json!({
+                "update_authority": optional_pubkey(extension.update_authority),
+                "mint": extension.mint.to_string(),
+                "size": u32::from(extension.size),
+                "max_size": u32::from(extension.max_size),
+            })
+        }
+        ExtensionType::TokenGroupMember => {
+            let extension = state.get_extension::<NativeTokenGroupMember>()?;
+            json!({
+                "mint": extension.mint.to_string(),
+                "group": extension.group.to_string(),
+                "member_number": u32::from(extension.member_number),
+            })
+        }
+        // Extensions without any configuration, or not used by the programs
+        _ => json!({}),
+    })
+}
+
+fn mint(data: &[u8]) -> Result<Value> {
+    let state = StateWithExtensions::<Mint>::unpack(data)?;
+
+    let mut extensions = Map::new();
+    for extension_type in state.get_extension_types()? {
+        extensions.insert(
+            format!("{extension_type:?}"),
+            mint_extension(&state, extension_type)?,
+        );
+    }
+
+    Ok(json!({
+        "mint_authority": Option::<Pubkey>::from(state.base.mint_authority).map(|key| key.to_string()),
+        "supply": state.base.supply,
+        "decimals": state.base.decimals,
+        "is_initialized": state.base.is_initialized,
+        "freeze_authority": Option::<Pubkey>::from(state.base.freeze_authority).map(|key| key.to_string()),
+        "extensions": extensions,
+    }))
+}
+
+fn token_account(data: &[u8]) -> Result<Value> {
+    let state = StateWithExtensions::<TokenAccount>::unpack(data)?;
+
+    let mut extensions = Map::new();
+    for extension_type in state.get_extension_types()? {
+        let value = match extension_type {
+            ExtensionType::TransferFeeAmount => {
+                let extension = state.get_extension::<TransferFeeAmount>()?;
+                json!({ "withheld_amount": u64::from(extension.withheld_amount) })
+            }
+            _ => json!({}),
+        };
+        extensions.insert(format!("{extension_type:?}"), value);
+    }
+
+    Ok(json!({
+        "mint": state.base.mint.to_string(),
+        "owner": state.base.owner.to_string(),
+        "amount": state.base.amount,
+        "delegate": Option::<Pubkey>::from(state.base.delegate).map(|key| key.to_string()),
+        "state": format!("{:?}", state.base.state),
+        "is_native": Option::<u64>::from(state.base.is_native),
+        "delegated_amount": state.base.delegated_amount,
+        "close_authority": Option::<Pubkey>::from(state.base.close_authority).map(|key| key.to_string()),
+        "extensions": extensions,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::AccountSerialize;
+    use spl_token_2022::extension::{BaseStateWithExtensionsMut, StateWithExtensionsMut};
+
+    #[test]
+    fn inspects_anchor_account() {
+        let mint = Pubkey::new_unique();
+        let member = TokenGroupMember::new(&mint, &Pubkey::new_unique(), 7);
+
+        let mut data = vec![];
+        member.try_serialize(&mut data).unwrap();
+
+        let value = inspect(&data).unwrap();
+
+        assert_eq!(value["type"], "TokenGroupMember");
+        assert_eq!(value["data"]["mint"], mint.to_string());
+        assert_eq!(value["data"]["member_number"], 7);
+    }
+
+    #[test]
+    fn inspects_plain_mint() {
+        let authority = Pubkey::new_unique();
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(
+            Mint {
+                mint_authority: Some(authority).into(),
+                supply: 1,
+                decimals: 0,
+                is_initialized: true,
+                freeze_authority: None.into(),
+            },
+            &mut data,
+        )
+        .unwrap();
+
+        let value = inspect(&data).unwrap();
+
+        assert_eq!(value["type"], "Mint");
+        assert_eq!(value["data"]["mint_authority"], authority.to_string());
+        assert_eq!(value["data"]["freeze_authority"], Value::Null);
+        assert_eq!(value["data"]["extensions"], json!({}));
+    }
+
+    #[test]
+    fn inspects_mint_metadata() {
+        let mint = Pubkey::new_unique();
+        let metadata = TokenMetadata {
+            update_authority: Some(Pubkey::new_unique()).try_into().unwrap(),
+            mint,
+            name: "Business Visa".to_owned(),
+            symbol: "VISA".to_owned(),
+            uri: "https://tokengator.com".to_owned(),
+            additional_metadata: vec![("community".to_owned(), "tokengator".to_owned())],
+        };
+
+        let base_len =
+            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::MetadataPointer])
+                .unwrap();
+        let mut data = vec![0; base_len + metadata.tlv_size_of().unwrap()];
+
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        let pointer = state.init_extension::<MetadataPointer>(true).unwrap();
+        pointer.metadata_address = Some(mint).try_into().unwrap();
+        state.base = Mint {
+            mint_authority: Some(Pubkey::new_unique()).into(),
+            supply: 0,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: None.into(),
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        state.init_variable_len_extension(&metadata, false).unwrap();
+
+        let value = inspect(&data).unwrap();
+        let extensions = &value["data"]["extensions"];
+
+        assert_eq!(value["type"], "Mint");
+        assert_eq!(
+            extensions["MetadataPointer"]["metadata_address"],
+            mint.to_string()
+        );
+        assert_eq!(extensions["TokenMetadata"]["name"], "Business Visa");
+        assert_eq!(
+            extensions["TokenMetadata"]["additional_metadata"],
+            json!([["community", "tokengator"]])
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_data() {
+        assert!(inspect(&[0; 40]).is_err());
+    }
+}
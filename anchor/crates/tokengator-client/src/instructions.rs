@@ -0,0 +1,165 @@
+//! Typed instruction builders, one per program instruction.
+//!
+//! Each builder takes the program's generated accounts struct and the instruction
+//! arguments, so account lists and argument layouts always match the program.
+
+use anchor_lang::{
+    prelude::Pubkey, solana_program::instruction::Instruction, InstructionData, ToAccountMetas,
+};
+
+/// Assembles an instruction from any accounts struct and instruction data
+pub fn build_instruction(
+    program_id: &Pubkey,
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+macro_rules! instruction_builders {
+    ($program:ident, $( $name:ident($accounts:ident, $ix:ident $(, $arg:ident: $ty:ty)*); )*) => {
+        $(
+            pub fn $name(
+                accounts: ::$program::accounts::$accounts,
+                $($arg: $ty,)*
+            ) -> Instruction {
+                $crate::instructions::build_instruction(
+                    &::$program::ID,
+                    accounts,
+                    ::$program::instruction::$ix { $($arg,)* },
+                )
+            }
+        )*
+    };
+}
+
+pub mod minter {
+    use super::*;
+    use ::tokengator_minter::*;
+
+    instruction_builders!(
+        tokengator_minter,
+        initialize_protocol_config(InitializeProtocolConfig, InitializeProtocolConfig, args: InitializeProtocolConfigArgs);
+        update_protocol_config(UpdateProtocolConfig, UpdateProtocolConfig, args: UpdateProtocolConfigArgs);
+        set_protocol_paused(SetProtocolPaused, SetProtocolPaused, args: SetPausedArgs);
+        set_minter_paused(SetMinterPaused, SetMinterPaused, args: SetPausedArgs);
+        prepare_for_payment(PrepareForPayment, PrepareForPayment, args: PrepareForPaymentArgs);
+        create_minter(CreateMinter, CreateMinter, args: CreateMinterArgs);
+        create_minter_wns(CreateMinterWNS, CreateMinterWns, args: CreateMinterWNSArgs);
+        add_minter_authority(AddMinterAuthority, AddMinterAuthority, args: AddMinterAuthorityArgs);
+        remove_minter_authority(RemoveMinterAuthority, RemoveMinterAuthority, args: RemoveMinterAuthorityArgs);
+        mint_preset(MintPreset, MintPreset);
+        mint_minter_wns(MintMinterWNS, MintMinterWns, args: MintMinterWNSArgs);
+        update_member_metdata(UpdateMemberMetadata, UpdateMemberMetdata, args: UpdateMemberMetadataArgs);
+        update_member_metadata_wns(UpdateMemberMetadataWNS, UpdateMemberMetadataWns, args: UpdateMemberMetadataWNSArgs);
+        sync_member_holder_wns(SyncMemberHolderWNS, SyncMemberHolderWns);
+        remove_minter(RemoveMinter, RemoveMinter);
+        transfer_group_authority_wns(TransferGroupAuthorityWNS, TransferGroupAuthorityWns, args: TransferGroupAuthorityWNSArgs);
+        cancel_group_authority_transfer_wns(CancelGroupAuthorityTransferWNS, CancelGroupAuthorityTransferWns);
+        remove_minter_wns(RemoveMinterWNS, RemoveMinterWns);
+        migrate_group(MigrateGroup, MigrateGroup);
+        create_coupon(CreateCoupon, CreateCoupon, args: CreateCouponArgs);
+        remove_coupon(RemoveCoupon, RemoveCoupon);
+        harvest_transfer_fees(HarvestTransferFees, HarvestTransferFees);
+        withdraw_transfer_fees(WithdrawTransferFees, WithdrawTransferFees);
+        update_transfer_fee(UpdateTransferFee, UpdateTransferFee, args: UpdateTransferFeeArgs);
+        update_interest_rate(UpdateInterestRate, UpdateInterestRate, args: UpdateInterestRateArgs);
+        link_identity(LinkIdentity, LinkIdentity, args: LinkIdentityArgs);
+        unlink_identity(UnlinkIdentity, UnlinkIdentity);
+        create_subscription(CreateSubscription, CreateSubscription, args: CreateSubscriptionArgs);
+        collect_subscription(CollectSubscription, CollectSubscription);
+        create_activity(CreateActivity, CreateActivity, args: CreateActivityArgs);
+        append_activity_entry(AppendActivityEntry, AppendActivityEntry, args: AppendActivityEntryArgs);
+    );
+}
+
+pub mod wns {
+    use super::*;
+    use ::wen_new_standard::*;
+
+    instruction_builders!(
+        wen_new_standard,
+        init_manager_account(InitManagerAccount, InitManagerAccount);
+        create_group_account(CreateGroupAccount, CreateGroupAccount, args: CreateGroupAccountArgs);
+        update_group_account(UpdateGroupAccount, UpdateGroupAccount, args: UpdateGroupAccountArgs);
+        transfer_group_authority(TransferGroupAuthority, TransferGroupAuthority, args: TransferGroupAuthorityArgs);
+        accept_group_authority(AcceptGroupAuthority, AcceptGroupAuthority);
+        cancel_group_authority_transfer(CancelGroupAuthorityTransfer, CancelGroupAuthorityTransfer);
+        migrate_group_account(MigrateGroupAccount, MigrateGroupAccount);
+        close_group_account(CloseGroupAccount, CloseGroupAccount);
+        create_mint_account(CreateMintAccount, CreateMintAccount, args: CreateMintAccountArgs);
+        add_mint_to_group(AddGroup, AddMintToGroup);
+        remove_mint_from_group(RemoveGroup, RemoveMintFromGroup);
+        add_metadata(AddMetadata, AddMetadata, args: Vec<AddMetadataArgs>);
+        remove_metadata(RemoveMetadata, RemoveMetadata, args: Vec<RemoveMetadataArgs>);
+        add_royalties(AddRoyalties, AddRoyalties, args: UpdateRoyaltiesArgs);
+        modify_royalties(ModifyRoyalties, ModifyRoyalties, args: UpdateRoyaltiesArgs);
+        freeze_mint_account(FreezeDelegatedAccount, FreezeMintAccount);
+        thaw_mint_account(ThawDelegatedAccount, ThawMintAccount);
+        finalize_mint(FinalizeMintAccount, FinalizeMint);
+        burn_mint_account(BurnMintAccount, BurnMintAccount);
+        burn_non_member_mint_account(BurnNonMemberMintAccount, BurnNonMemberMintAccount);
+        create_master_edition(CreateMasterEdition, CreateMasterEdition);
+        print_edition(PrintEdition, PrintEdition);
+        distribute_royalties(DistributeRoyalties, DistributeRoyalties, args: DistributeRoyaltiesArgs);
+        initialize_extra_meta_list(InitializeExtraMetaList, InitializeExtraMetaList);
+        approve_transfer(ApproveTransfer, ApproveTransfer, args: ApproveTransferArgs);
+        execute(ExecuteTransferHook, Execute, amount: u64);
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::{prelude::Pubkey, Discriminator};
+
+    #[test]
+    fn builds_tokengator_minter_instruction() {
+        let protocol_config = Pubkey::new_unique();
+        let accounts = tokengator_minter::accounts::SetProtocolPaused {
+            protocol_config,
+            admin: Pubkey::new_unique(),
+        };
+
+        let ix = super::minter::set_protocol_paused(
+            accounts,
+            tokengator_minter::SetPausedArgs { paused: true },
+        );
+
+        assert_eq!(ix.program_id, tokengator_minter::ID);
+        assert_eq!(
+            &ix.data[..8],
+            tokengator_minter::instruction::SetProtocolPaused::DISCRIMINATOR
+        );
+        assert_eq!(ix.data[8..], [1]);
+        assert_eq!(ix.accounts.len(), 2);
+        assert_eq!(ix.accounts[0].pubkey, protocol_config);
+        assert!(ix.accounts[1].is_signer);
+    }
+
+    #[test]
+    fn builds_wns_instruction() {
+        let mint = Pubkey::new_unique();
+        let accounts = wen_new_standard::accounts::FinalizeMintAccount {
+            authority: Pubkey::new_unique(),
+            mint,
+            member: Pubkey::new_unique(),
+            manager: Pubkey::new_unique(),
+            token_program: Pubkey::new_unique(),
+        };
+
+        let ix = super::wns::finalize_mint(accounts);
+
+        assert_eq!(ix.program_id, wen_new_standard::ID);
+        assert_eq!(
+            ix.data,
+            wen_new_standard::instruction::FinalizeMint::DISCRIMINATOR
+        );
+        assert!(ix.accounts[0].is_signer);
+        assert!(ix.accounts[1].is_writable);
+        assert_eq!(ix.accounts[1].pubkey, mint);
+    }
+}
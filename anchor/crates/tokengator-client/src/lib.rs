@@ -0,0 +1,17 @@
+//! Client helpers for the `tokengator_minter` and `wen_new_standard` programs.
+//!
+//! Everything here is pure: PDAs are derived locally, instructions are assembled
+//! from the program account and argument types, and accounts are decoded from raw
+//! account data, so nothing needs a network connection. `inspect` turns raw account
+//! data of any supported type into JSON for debugging.
+
+pub mod accounts;
+pub mod inspect;
+pub mod instructions;
+pub mod pda;
+
+pub use tokengator_minter;
+pub use wen_new_standard;
+
+pub use tokengator_minter::ID as TOKENGATOR_MINTER_PROGRAM_ID;
+pub use wen_new_standard::ID as WNS_PROGRAM_ID;
@@ -0,0 +1,214 @@
+//! PDA helpers, all returning the address together with its bump.
+
+use anchor_lang::prelude::Pubkey;
+use tokengator_minter::{constants::*, state::IdentityProvider};
+use wen_new_standard::{
+    APPROVE_ACCOUNT_SEED, GROUP_ACCOUNT_SEED, GROUP_AUTHORITY_TRANSFER_SEED, MANAGER_SEED,
+    MASTER_EDITION_SEED, MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED,
+};
+
+use crate::{TOKENGATOR_MINTER_PROGRAM_ID, WNS_PROGRAM_ID};
+
+/* tokengator_minter */
+
+pub fn protocol_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PREFIX, PROTOCOL_CONFIG], &TOKENGATOR_MINTER_PROGRAM_ID)
+}
+
+pub fn minter(mint: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PREFIX, MINTER, mint.as_ref(), name.as_bytes()],
+        &TOKENGATOR_MINTER_PROGRAM_ID,
+    )
+}
+
+pub fn receipt(sender: &Pubkey, receiver: &Pubkey, payment_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX,
+            RECEIPT,
+            sender.as_ref(),
+            receiver.as_ref(),
+            payment_mint.as_ref(),
+        ],
+        &TOKENGATOR_MINTER_PROGRAM_ID,
+    )
+}
+
+pub fn activity(mint: &Pubkey, label: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PREFIX, ACTIVITY, mint.as_ref(), label.as_bytes()],
+        &TOKENGATOR_MINTER_PROGRAM_ID,
+    )
+}
+
+pub fn coupon(minter: &Pubkey, code_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PREFIX, COUPON, minter.as_ref(), code_hash.as_ref()],
+        &TOKENGATOR_MINTER_PROGRAM_ID,
+    )
+}
+
+pub fn subscription(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PREFIX, SUBSCRIPTION, mint.as_ref()],
+        &TOKENGATOR_MINTER_PROGRAM_ID,
+    )
+}
+
+pub fn identity_link(
+    minter: &Pubkey,
+    wallet: &Pubkey,
+    provider: &IdentityProvider,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX,
+            IDENTITY_LINK,
+            minter.as_ref(),
+            wallet.as_ref(),
+            provider.seed(),
+            provider.value_seed(),
+        ],
+        &TOKENGATOR_MINTER_PROGRAM_ID,
+    )
+}
+
+pub fn identity_claim(
+    minter: &Pubkey,
+    provider: &IdentityProvider,
+    id_hash: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX,
+            IDENTITY_CLAIM,
+            minter.as_ref(),
+            provider.seed(),
+            provider.value_seed(),
+            id_hash.as_ref(),
+        ],
+        &TOKENGATOR_MINTER_PROGRAM_ID,
+    )
+}
+
+/// Legacy custom group account, only used to migrate minters onto the group extension
+pub fn legacy_group(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PREFIX, GROUP, mint.as_ref()],
+        &TOKENGATOR_MINTER_PROGRAM_ID,
+    )
+}
+
+pub fn community_id(community: &str) -> Pubkey {
+    tokengator_minter::utils::fetch_community_id(community)
+}
+
+/* wen_new_standard */
+
+pub fn wns_manager() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MANAGER_SEED], &WNS_PROGRAM_ID)
+}
+
+pub fn wns_group(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GROUP_ACCOUNT_SEED, mint.as_ref()], &WNS_PROGRAM_ID)
+}
+
+pub fn wns_member(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MEMBER_ACCOUNT_SEED, mint.as_ref()], &WNS_PROGRAM_ID)
+}
+
+pub fn wns_extra_meta_list(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[META_LIST_ACCOUNT_SEED, mint.as_ref()], &WNS_PROGRAM_ID)
+}
+
+pub fn wns_approve_account(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[APPROVE_ACCOUNT_SEED, mint.as_ref()], &WNS_PROGRAM_ID)
+}
+
+pub fn wns_group_authority_transfer(group: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[GROUP_AUTHORITY_TRANSFER_SEED, group.as_ref()],
+        &WNS_PROGRAM_ID,
+    )
+}
+
+pub fn wns_master_edition(group: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MASTER_EDITION_SEED, group.as_ref()], &WNS_PROGRAM_ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minter_matches_program_seeds() {
+        let mint = Pubkey::new_unique();
+        let (address, bump) = minter(&mint, "Business Visa");
+
+        let expected = Pubkey::create_program_address(
+            &[PREFIX, MINTER, mint.as_ref(), b"Business Visa", &[bump]],
+            &TOKENGATOR_MINTER_PROGRAM_ID,
+        )
+        .unwrap();
+
+        assert_eq!(address, expected);
+    }
+
+    #[test]
+    fn receipt_depends_on_every_party() {
+        let sender = Pubkey::new_unique();
+        let receiver = Pubkey::new_unique();
+        let payment_mint = Pubkey::new_unique();
+
+        let (address, _) = receipt(&sender, &receiver, &payment_mint);
+
+        assert_ne!(address, receipt(&receiver, &sender, &payment_mint).0);
+        assert_ne!(
+            address,
+            receipt(&sender, &receiver, &Pubkey::new_unique()).0
+        );
+    }
+
+    #[test]
+    fn community_id_matches_program() {
+        let (expected, _) =
+            Pubkey::find_program_address(&[PREFIX, b"tokengator"], &TOKENGATOR_MINTER_PROGRAM_ID);
+
+        assert_eq!(community_id("tokengator"), expected);
+    }
+
+    #[test]
+    fn identity_link_separates_provider_values() {
+        let minter = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+
+        let (first, _) = identity_link(
+            &minter,
+            &wallet,
+            &IdentityProvider::EmailDomain("tokengator.com".to_owned()),
+        );
+        let (second, _) = identity_link(
+            &minter,
+            &wallet,
+            &IdentityProvider::EmailDomain("example.com".to_owned()),
+        );
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn wns_pdas_live_under_wns_program() {
+        let mint = Pubkey::new_unique();
+        let (group, bump) = wns_group(&mint);
+
+        let expected = Pubkey::create_program_address(
+            &[GROUP_ACCOUNT_SEED, mint.as_ref(), &[bump]],
+            &WNS_PROGRAM_ID,
+        )
+        .unwrap();
+
+        assert_eq!(group, expected);
+        assert_ne!(group, legacy_group(&mint).0);
+    }
+}
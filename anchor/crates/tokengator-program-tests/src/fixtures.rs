@@ -0,0 +1,526 @@
+//! Default arguments and multi-instruction flows shared by the tests.
+
+use anchor_lang::{
+    prelude::*, solana_program::sysvar::instructions::ID as INSTRUCTIONS_SYSVAR_ID, system_program,
+};
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id, ID as ASSOCIATED_TOKEN_PROGRAM_ID,
+};
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::{Keypair, Signer},
+    sysvar::rent::ID as RENT_SYSVAR_ID,
+};
+use tokengator_client::tokengator_minter::{
+    accounts,
+    args::{MinterApplicationConfigArgs, PaymentConfigArgs},
+    state::*,
+    CreateCouponArgs, CreateMinterArgs, CreateMinterWNSArgs, MintMinterWNSArgs,
+    PrepareForPaymentArgs, SetPausedArgs,
+};
+use tokengator_client::{instructions::minter, pda, WNS_PROGRAM_ID};
+
+use crate::{identity_attestation_instruction, TestContext, COMMUNITY_FEE};
+
+pub const COMMUNITY: &str = "tokengator";
+pub const TIER_PRICE: u64 = 100_000;
+pub const TIER_DAYS: u16 = 30;
+
+/// Minter created through `create_minter_wns`
+pub struct WnsMinter {
+    pub minter: Pubkey,
+    pub mint: Pubkey,
+    pub group: Pubkey,
+    pub authority: Keypair,
+    pub name: String,
+}
+
+/// Minter created through `create_minter`, its mint is also the group
+pub struct CustomMinter {
+    pub minter: Pubkey,
+    pub mint: Pubkey,
+    pub authority: Keypair,
+    pub name: String,
+}
+
+/// Member minted through `mint_minter_wns`
+pub struct Member {
+    pub mint: Pubkey,
+    pub member: Pubkey,
+    pub holder: Keypair,
+    pub token_account: Pubkey,
+}
+
+pub fn metadata_config(name: &str) -> MinterMetadataConfig {
+    MinterMetadataConfig {
+        name: name.to_owned(),
+        symbol: "TKG".to_owned(),
+        metadata: Some(vec![["preset".to_owned(), "business-visa".to_owned()]]),
+        uri: "https://tokengator.app/metadata.json".to_owned(),
+    }
+}
+
+/// Transfers the badge of `member` to `destination`, with the accounts of the WNS transfer hook
+pub fn transfer_member_instruction(member: &Member, destination: &Pubkey) -> Instruction {
+    let mut transfer = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::ID,
+        &member.token_account,
+        &member.mint,
+        destination,
+        &member.holder.pubkey(),
+        &[],
+        1,
+        0,
+    )
+    .unwrap();
+
+    // The transfer hook resolves the WNS approve account through the extra metas
+    transfer.accounts.extend([
+        AccountMeta::new(pda::wns_approve_account(&member.mint).0, false),
+        AccountMeta::new_readonly(WNS_PROGRAM_ID, false),
+        AccountMeta::new_readonly(pda::wns_extra_meta_list(&member.mint).0, false),
+    ]);
+
+    transfer
+}
+
+impl TestContext {
+    pub fn application_config(&self) -> MinterApplicationConfigArgs {
+        MinterApplicationConfigArgs {
+            identities: vec![],
+            payment_tiers: vec![PaymentTier {
+                label: Some("Monthly".to_owned()),
+                price: TIER_PRICE,
+                mint: self.payment_mint,
+                days: Some(TIER_DAYS),
+            }],
+        }
+    }
+
+    pub fn create_minter_wns_args(&self, name: &str) -> CreateMinterWNSArgs {
+        CreateMinterWNSArgs {
+            community: COMMUNITY.to_owned(),
+            name: name.to_owned(),
+            description: "Members of the tokengator community".to_owned(),
+            image_url: "https://tokengator.app/badge.png".to_owned(),
+            payment_config: PaymentConfigArgs {
+                amount: 100,
+                price: COMMUNITY_FEE,
+                mint: self.payment_mint,
+                days: 30,
+            },
+            application_config: self.application_config(),
+            metadata_config: metadata_config(name),
+            interest_config: None,
+            transfer_fee_config: None,
+            transferable: false,
+            website: None,
+            royalty_basis_points: None,
+        }
+    }
+
+    pub fn create_minter_args(&self, name: &str) -> CreateMinterArgs {
+        CreateMinterArgs {
+            community: COMMUNITY.to_owned(),
+            name: name.to_owned(),
+            description: "Members of the tokengator community".to_owned(),
+            image_url: "https://tokengator.app/badge.png".to_owned(),
+            payment_config: PaymentConfig {
+                amount: 100,
+                price: COMMUNITY_FEE,
+                mint: self.payment_mint,
+                days: 30,
+                expires_at: 0,
+            },
+            application_config: self.application_config(),
+            metadata_config: metadata_config(name),
+            interest_config: None,
+            transfer_fee_config: None,
+            transferable: true,
+        }
+    }
+
+    pub fn prepare_for_payment_accounts(
+        &self,
+        sender: &Pubkey,
+        receiver: &Pubkey,
+        coupon: Option<Pubkey>,
+    ) -> accounts::PrepareForPayment {
+        accounts::PrepareForPayment {
+            receipt: pda::receipt(sender, receiver, &self.payment_mint).0,
+            fee_payer: self.payer(),
+            sender: *sender,
+            sender_token_account: get_associated_token_address_with_program_id(
+                sender,
+                &self.payment_mint,
+                &spl_token_2022::ID,
+            ),
+            receiver: *receiver,
+            receiver_token_account: get_associated_token_address_with_program_id(
+                receiver,
+                &self.payment_mint,
+                &spl_token_2022::ID,
+            ),
+            mint: self.payment_mint,
+            protocol_config: pda::protocol_config().0,
+            treasury: self.treasury.pubkey(),
+            treasury_token_account: get_associated_token_address_with_program_id(
+                &self.treasury.pubkey(),
+                &self.payment_mint,
+                &spl_token_2022::ID,
+            ),
+            coupon,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    /// Pays `amount` from `sender` to `receiver`, returning the receipt
+    pub async fn prepare_for_payment(
+        &mut self,
+        sender: &Keypair,
+        receiver: &Pubkey,
+        args: PrepareForPaymentArgs,
+    ) -> Pubkey {
+        let accounts = self.prepare_for_payment_accounts(&sender.pubkey(), receiver, None);
+        let receipt = accounts.receipt;
+
+        self.process(&[minter::prepare_for_payment(accounts, args)], &[sender])
+            .await
+            .unwrap();
+
+        receipt
+    }
+
+    /// Funded authority holding a community receipt for a new minter
+    pub async fn community_authority(&mut self) -> Keypair {
+        let authority = self.funded_keypair().await;
+        self.fund_payment_tokens(&authority.pubkey(), COMMUNITY_FEE)
+            .await;
+
+        self.prepare_for_payment(
+            &authority,
+            &authority.pubkey(),
+            PrepareForPaymentArgs {
+                payment_amount: COMMUNITY_FEE,
+                payment_type: ReceiptType::Community,
+                payment_tier: None,
+                coupon_code: None,
+            },
+        )
+        .await;
+
+        authority
+    }
+
+    pub fn create_minter_wns_accounts(
+        &self,
+        authority: &Pubkey,
+        mint: &Pubkey,
+        name: &str,
+    ) -> accounts::CreateMinterWNS {
+        let minter = pda::minter(mint, name).0;
+
+        accounts::CreateMinterWNS {
+            group: pda::wns_group(mint).0,
+            manager: pda::wns_manager().0,
+            receipt: pda::receipt(authority, authority, &self.payment_mint).0,
+            minter,
+            minter_token_account: get_associated_token_address_with_program_id(
+                &minter,
+                mint,
+                &spl_token_2022::ID,
+            ),
+            mint: *mint,
+            fee_payer: self.payer(),
+            authority: *authority,
+            authority_token_account: get_associated_token_address_with_program_id(
+                authority,
+                &self.payment_mint,
+                &spl_token_2022::ID,
+            ),
+            protocol_config: pda::protocol_config().0,
+            payment_mint: self.payment_mint,
+            rent: RENT_SYSVAR_ID,
+            wns_program: WNS_PROGRAM_ID,
+            token_program: spl_token_2022::ID,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub async fn create_minter_wns(&mut self, args: CreateMinterWNSArgs) -> WnsMinter {
+        let authority = self.community_authority().await;
+        let mint = Keypair::new();
+        let name = args.name.clone();
+        let accounts = self.create_minter_wns_accounts(&authority.pubkey(), &mint.pubkey(), &name);
+        let minter = accounts.minter;
+
+        self.process(
+            &[minter::create_minter_wns(accounts, args)],
+            &[&authority, &mint],
+        )
+        .await
+        .unwrap();
+
+        WnsMinter {
+            minter,
+            mint: mint.pubkey(),
+            group: pda::wns_group(&mint.pubkey()).0,
+            authority,
+            name,
+        }
+    }
+
+    /// User payment for the first tier of `minter`, sent by `applicant`
+    pub async fn pay_tier(&mut self, authority: &Pubkey, applicant: &Keypair, price: u64) {
+        self.fund_payment_tokens(&applicant.pubkey(), price).await;
+        self.prepare_for_payment(
+            applicant,
+            authority,
+            PrepareForPaymentArgs {
+                payment_amount: price,
+                payment_type: ReceiptType::User,
+                payment_tier: Some(0),
+                coupon_code: None,
+            },
+        )
+        .await;
+    }
+
+    pub fn mint_minter_wns_accounts(
+        &self,
+        minter: &WnsMinter,
+        receiver: &Pubkey,
+        mint: &Pubkey,
+    ) -> accounts::MintMinterWNS {
+        accounts::MintMinterWNS {
+            manager: pda::wns_manager().0,
+            group: minter.group,
+            member: pda::wns_member(mint).0,
+            extra_metas_account: pda::wns_extra_meta_list(mint).0,
+            master_edition: pda::wns_master_edition(&minter.group).0,
+            receipt: pda::receipt(receiver, &minter.authority.pubkey(), &self.payment_mint).0,
+            minter: minter.minter,
+            coupon: None,
+            protocol_config: pda::protocol_config().0,
+            mint: *mint,
+            receiver_token_account: get_associated_token_address_with_program_id(
+                receiver,
+                mint,
+                &spl_token_2022::ID,
+            ),
+            fee_payer: self.payer(),
+            authority: minter.authority.pubkey(),
+            receiver: *receiver,
+            token_program: spl_token_2022::ID,
+            wns_program: WNS_PROGRAM_ID,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            system_program: system_program::ID,
+            rent: RENT_SYSVAR_ID,
+            instructions_sysvar: INSTRUCTIONS_SYSVAR_ID,
+        }
+    }
+
+    pub fn mint_minter_wns_args(&self) -> MintMinterWNSArgs {
+        MintMinterWNSArgs {
+            name: "Member".to_owned(),
+            symbol: "TKG".to_owned(),
+            uri: "https://tokengator.app/member.json".to_owned(),
+            metadata: None,
+        }
+    }
+
+    /// Builds the mint instruction, preceded by an attestation when one is given and
+    /// followed by the identity links of the attested providers
+    pub fn mint_minter_wns_instructions(
+        &self,
+        accounts: accounts::MintMinterWNS,
+        attestation: Option<&IdentityAttestation>,
+    ) -> Vec<Instruction> {
+        let mut instructions = vec![];
+        let mut mint = minter::mint_minter_wns(accounts, self.mint_minter_wns_args());
+
+        if let Some(attestation) = attestation {
+            instructions.push(identity_attestation_instruction(
+                &self.identity_verifier,
+                attestation,
+            ));
+
+            mint.accounts
+                .extend(attestation.identities.iter().map(|provider| {
+                    AccountMeta::new_readonly(
+                        pda::identity_link(&attestation.minter, &attestation.wallet, provider).0,
+                        false,
+                    )
+                }));
+        }
+
+        instructions.push(mint);
+
+        instructions
+    }
+
+    /// Pays the first tier and mints a member badge to a new applicant
+    pub async fn mint_member_wns(&mut self, minter: &WnsMinter) -> Member {
+        let holder = self.funded_keypair().await;
+        self.pay_tier(&minter.authority.pubkey(), &holder, TIER_PRICE)
+            .await;
+
+        let mint = Keypair::new();
+        let accounts = self.mint_minter_wns_accounts(minter, &holder.pubkey(), &mint.pubkey());
+        let token_account = accounts.receiver_token_account;
+        let instructions = self.mint_minter_wns_instructions(accounts, None);
+        let authority = minter.authority.insecure_clone();
+
+        self.process(&instructions, &[&authority, &mint])
+            .await
+            .unwrap();
+
+        Member {
+            mint: mint.pubkey(),
+            member: pda::wns_member(&mint.pubkey()).0,
+            holder,
+            token_account,
+        }
+    }
+
+    /// Moves a member badge to the associated token account of `to`
+    pub async fn transfer_member(&mut self, member: &Member, to: &Pubkey) -> Pubkey {
+        let (destination, result) = self.try_transfer_member(member, to).await;
+        result.unwrap();
+
+        destination
+    }
+
+    pub async fn try_transfer_member(
+        &mut self,
+        member: &Member,
+        to: &Pubkey,
+    ) -> (Pubkey, std::result::Result<(), BanksClientError>) {
+        let destination = self.create_token_account(to, &member.mint).await;
+        let transfer = transfer_member_instruction(member, &destination);
+
+        let holder = member.holder.insecure_clone();
+        let result = self.process(&[transfer], &[&holder]).await;
+
+        (destination, result)
+    }
+
+    pub fn create_minter_accounts(
+        &self,
+        authority: &Pubkey,
+        mint: &Pubkey,
+        name: &str,
+    ) -> accounts::CreateMinter {
+        let minter = pda::minter(mint, name).0;
+
+        accounts::CreateMinter {
+            minter,
+            minter_token_account: get_associated_token_address_with_program_id(
+                &minter,
+                mint,
+                &spl_token_2022::ID,
+            ),
+            mint: *mint,
+            fee_payer: self.payer(),
+            authority: *authority,
+            protocol_config: pda::protocol_config().0,
+            token_program: spl_token_2022::ID,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    pub async fn create_minter(&mut self, args: CreateMinterArgs) -> CustomMinter {
+        let authority = self.funded_keypair().await;
+        let mint = Keypair::new();
+        let name = args.name.clone();
+        let accounts = self.create_minter_accounts(&authority.pubkey(), &mint.pubkey(), &name);
+        let minter = accounts.minter;
+
+        self.process(
+            &[minter::create_minter(accounts, args)],
+            &[&authority, &mint],
+        )
+        .await
+        .unwrap();
+
+        CustomMinter {
+            minter,
+            mint: mint.pubkey(),
+            authority,
+            name,
+        }
+    }
+
+    pub fn mint_preset_accounts(
+        &self,
+        minter: &CustomMinter,
+        member_mint: &Pubkey,
+    ) -> accounts::MintPreset {
+        accounts::MintPreset {
+            minter: minter.minter,
+            mint: minter.mint,
+            member_mint: *member_mint,
+            authority_token_account: get_associated_token_address_with_program_id(
+                &minter.authority.pubkey(),
+                member_mint,
+                &spl_token_2022::ID,
+            ),
+            fee_payer: self.payer(),
+            authority: minter.authority.pubkey(),
+            protocol_config: pda::protocol_config().0,
+            token_program: spl_token_2022::ID,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            system_program: system_program::ID,
+        }
+    }
+
+    /// Creates a coupon for `minter` through its authority
+    pub async fn create_coupon(&mut self, minter: &WnsMinter, args: CreateCouponArgs) -> Pubkey {
+        let coupon = pda::coupon(&minter.minter, &args.code_hash).0;
+        let accounts = accounts::CreateCoupon {
+            coupon,
+            minter: minter.minter,
+            authority: minter.authority.pubkey(),
+            fee_payer: self.payer(),
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        };
+        let authority = minter.authority.insecure_clone();
+
+        self.process(&[minter::create_coupon(accounts, args)], &[&authority])
+            .await
+            .unwrap();
+
+        coupon
+    }
+
+    pub fn set_minter_paused_instruction(
+        &self,
+        minter: &Pubkey,
+        authority: &Pubkey,
+        paused: bool,
+    ) -> Instruction {
+        minter::set_minter_paused(
+            accounts::SetMinterPaused {
+                minter: *minter,
+                authority: *authority,
+            },
+            SetPausedArgs { paused },
+        )
+    }
+
+    pub fn set_protocol_paused_instruction(&self, admin: &Pubkey, paused: bool) -> Instruction {
+        minter::set_protocol_paused(
+            accounts::SetProtocolPaused {
+                protocol_config: pda::protocol_config().0,
+                admin: *admin,
+            },
+            SetPausedArgs { paused },
+        )
+    }
+}
@@ -0,0 +1,595 @@
+//! Hermetic `solana-program-test` harness for `tokengator_minter` and `wen_new_standard`.
+//!
+//! Both programs run natively inside the test bank together with Token-2022, so the
+//! suite needs neither a validator nor accounts cloned from mainnet. Pointing
+//! `SBF_OUT_DIR` at the `anchor build` output runs the same tests against the
+//! compiled programs instead, deployed as upgradeable programs.
+
+use anchor_lang::{
+    prelude::*, solana_program::bpf_loader_upgradeable, system_program, AccountDeserialize,
+    AccountSerialize,
+};
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id, spl_associated_token_account,
+};
+use solana_program_test::{
+    find_file, processor, read_file, BanksClientError, ProgramTest, ProgramTestContext,
+};
+use solana_sdk::{
+    account::{Account, AccountSharedData, WritableAccount},
+    bpf_loader_upgradeable::UpgradeableLoaderState,
+    ed25519_program,
+    entrypoint::ProgramResult,
+    instruction::{Instruction, InstructionError},
+    program_pack::Pack,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::{Account as TokenAccount, Mint},
+};
+use spl_token_metadata_interface::state::TokenMetadata;
+use tokengator_client::{
+    instructions::wns, pda, tokengator_minter, wen_new_standard, TOKENGATOR_MINTER_PROGRAM_ID,
+    WNS_PROGRAM_ID,
+};
+use tokengator_minter::{errors::TokenGatorMinterError, state::*};
+
+mod fixtures;
+mod wns_fixtures;
+
+pub use fixtures::*;
+pub use wns_fixtures::*;
+
+pub const PAYMENT_MINT_DECIMALS: u8 = 6;
+pub const COMMUNITY_FEE: u64 = 1_000_000;
+pub const MINT_FEE_BASIS_POINTS: u16 = 500;
+
+// Anchor entrypoints tie the accounts slice to the account lifetimes, which the
+// program-test processor signature does not, so the slice is leaked for the call
+fn tokengator_minter_entry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    tokengator_minter::entry(program_id, accounts, data)
+}
+
+fn wen_new_standard_entry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    wen_new_standard::entry(program_id, accounts, data)
+}
+
+// Compiled program from `SBF_OUT_DIR`, only looked up when the variable is set
+fn compiled_program(program_name: &str) -> Option<Vec<u8>> {
+    std::env::var_os("SBF_OUT_DIR")
+        .or_else(|| std::env::var_os("BPF_OUT_DIR"))
+        .and_then(|_| find_file(&format!("{program_name}.so")))
+        .map(read_file)
+}
+
+fn add_upgradeable_program(
+    program_test: &mut ProgramTest,
+    program_id: Pubkey,
+    elf: &[u8],
+    upgrade_authority: Pubkey,
+) {
+    let rent = Rent::default();
+    let (programdata_address, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID);
+
+    let mut programdata = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(upgrade_authority),
+    })
+    .unwrap();
+    programdata.resize(UpgradeableLoaderState::size_of_programdata_metadata(), 0);
+    programdata.extend_from_slice(elf);
+
+    let program = bincode::serialize(&UpgradeableLoaderState::Program {
+        programdata_address,
+    })
+    .unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: rent.minimum_balance(programdata.len()),
+            data: programdata,
+            owner: bpf_loader_upgradeable::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test.add_account(
+        program_id,
+        Account {
+            lamports: rent.minimum_balance(program.len()),
+            data: program,
+            owner: bpf_loader_upgradeable::ID,
+            executable: true,
+            rent_epoch: 0,
+        },
+    );
+}
+
+pub struct TestContext {
+    pub context: ProgramTestContext,
+    // Protocol admin, also the upgrade authority of the compiled programs
+    pub admin: Keypair,
+    pub treasury: Keypair,
+    pub identity_verifier: Keypair,
+    pub payment_mint: Pubkey,
+    pub payment_mint_authority: Keypair,
+    upgradeable: bool,
+}
+
+impl TestContext {
+    /// Programs, the WNS manager and a payment mint, without a protocol config
+    pub async fn new() -> Self {
+        let admin = Keypair::new();
+
+        let mut program_test = ProgramTest::default();
+        program_test.prefer_bpf(false);
+
+        // Token-2022 with the group extensions, replacing the bundled build
+        program_test.add_program(
+            "spl_token_2022",
+            spl_token_2022::ID,
+            processor!(spl_token_2022::processor::Processor::process),
+        );
+
+        let compiled = (
+            compiled_program("tokengator_minter"),
+            compiled_program("wen_new_standard"),
+        );
+        let upgradeable = matches!(compiled, (Some(_), Some(_)));
+
+        match compiled {
+            (Some(minter_elf), Some(wns_elf)) => {
+                add_upgradeable_program(
+                    &mut program_test,
+                    TOKENGATOR_MINTER_PROGRAM_ID,
+                    &minter_elf,
+                    admin.pubkey(),
+                );
+                add_upgradeable_program(
+                    &mut program_test,
+                    WNS_PROGRAM_ID,
+                    &wns_elf,
+                    admin.pubkey(),
+                );
+            }
+            _ => {
+                program_test.add_program(
+                    "tokengator_minter",
+                    TOKENGATOR_MINTER_PROGRAM_ID,
+                    processor!(tokengator_minter_entry),
+                );
+                program_test.add_program(
+                    "wen_new_standard",
+                    WNS_PROGRAM_ID,
+                    processor!(wen_new_standard_entry),
+                );
+            }
+        }
+
+        let context = program_test.start_with_context().await;
+
+        let mut test_context = Self {
+            context,
+            admin,
+            treasury: Keypair::new(),
+            identity_verifier: Keypair::new(),
+            payment_mint: Pubkey::default(),
+            payment_mint_authority: Keypair::new(),
+            upgradeable,
+        };
+
+        test_context
+            .process(
+                &[wns::init_manager_account(
+                    wen_new_standard::accounts::InitManagerAccount {
+                        payer: test_context.payer(),
+                        manager: pda::wns_manager().0,
+                        system_program: system_program::ID,
+                    },
+                )],
+                &[],
+            )
+            .await
+            .unwrap();
+
+        test_context
+            .airdrop(&test_context.admin.pubkey(), 10_000_000_000)
+            .await;
+
+        let payment_mint_authority = test_context.payment_mint_authority.pubkey();
+        test_context.payment_mint = test_context
+            .create_mint(&payment_mint_authority, PAYMENT_MINT_DECIMALS)
+            .await;
+
+        test_context
+    }
+
+    /// Everything from [`TestContext::new`] plus a protocol config accepting the payment mint
+    pub async fn with_protocol_config() -> Self {
+        let mut test_context = Self::new().await;
+
+        let protocol_config = ProtocolConfig {
+            bump: pda::protocol_config().1,
+            admin: test_context.admin.pubkey(),
+            treasury: test_context.treasury.pubkey(),
+            community_fee: COMMUNITY_FEE,
+            mint_fee_basis_points: MINT_FEE_BASIS_POINTS,
+            allowed_payment_mints: vec![test_context.payment_mint],
+            paused: false,
+            identity_verifier: test_context.identity_verifier.pubkey(),
+        };
+        let space = ProtocolConfig::size(&protocol_config.allowed_payment_mints);
+
+        test_context.set_program_account(&pda::protocol_config().0, &protocol_config, space);
+        test_context
+    }
+
+    /// A protocol config and a "Business Visa" minter created through `create_minter_wns`
+    pub async fn with_minter_wns() -> (Self, WnsMinter) {
+        let mut test_context = Self::with_protocol_config().await;
+        let args = test_context.create_minter_wns_args("Business Visa");
+        let minter = test_context.create_minter_wns(args).await;
+
+        (test_context, minter)
+    }
+
+    /// Everything from [`TestContext::with_minter_wns`] plus a member minted from it
+    pub async fn with_member_wns() -> (Self, WnsMinter, Member) {
+        let (mut test_context, minter) = Self::with_minter_wns().await;
+        let member = test_context.mint_member_wns(&minter).await;
+
+        (test_context, minter, member)
+    }
+
+    /// Whether the programs were loaded from `SBF_OUT_DIR` as upgradeable programs
+    pub fn is_upgradeable(&self) -> bool {
+        self.upgradeable
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
+    /// Sends the instructions paid by the context payer, which always signs
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> std::result::Result<(), BanksClientError> {
+        let recent_blockhash = self
+            .context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .unwrap();
+
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            recent_blockhash,
+        );
+
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    pub async fn airdrop(&mut self, to: &Pubkey, lamports: u64) {
+        let payer = self.payer();
+        self.process(&[system_instruction::transfer(&payer, to, lamports)], &[])
+            .await
+            .unwrap();
+    }
+
+    /// Funded keypair for signers that also pay rent, like minter authorities
+    pub async fn funded_keypair(&mut self) -> Keypair {
+        let keypair = Keypair::new();
+        self.airdrop(&keypair.pubkey(), 1_000_000_000).await;
+        keypair
+    }
+
+    pub async fn create_mint(&mut self, mint_authority: &Pubkey, decimals: u8) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.payer();
+        let lamports = Rent::default().minimum_balance(Mint::LEN);
+
+        self.process(
+            &[
+                system_instruction::create_account(
+                    &payer,
+                    &mint.pubkey(),
+                    lamports,
+                    u64::try_from(Mint::LEN).unwrap(),
+                    &spl_token_2022::ID,
+                ),
+                spl_token_2022::instruction::initialize_mint2(
+                    &spl_token_2022::ID,
+                    &mint.pubkey(),
+                    mint_authority,
+                    None,
+                    decimals,
+                )
+                .unwrap(),
+            ],
+            &[&mint],
+        )
+        .await
+        .unwrap();
+
+        mint.pubkey()
+    }
+
+    /// Creates the associated token account of `owner` when missing
+    pub async fn create_token_account(&mut self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let payer = self.payer();
+
+        self.process(
+            &[
+                spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                    &payer,
+                    owner,
+                    mint,
+                    &spl_token_2022::ID,
+                ),
+            ],
+            &[],
+        )
+        .await
+        .unwrap();
+
+        get_associated_token_address_with_program_id(owner, mint, &spl_token_2022::ID)
+    }
+
+    /// Mints payment tokens to the associated token account of `owner`
+    pub async fn fund_payment_tokens(&mut self, owner: &Pubkey, amount: u64) -> Pubkey {
+        let payment_mint = self.payment_mint;
+        let token_account = self.create_token_account(owner, &payment_mint).await;
+        let payment_mint_authority = self.payment_mint_authority.insecure_clone();
+
+        self.process(
+            &[spl_token_2022::instruction::mint_to(
+                &spl_token_2022::ID,
+                &payment_mint,
+                &token_account,
+                &payment_mint_authority.pubkey(),
+                &[],
+                amount,
+            )
+            .unwrap()],
+            &[&payment_mint_authority],
+        )
+        .await
+        .unwrap();
+
+        token_account
+    }
+
+    pub async fn get_account(&mut self, address: &Pubkey) -> Option<Account> {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+    }
+
+    pub async fn account_exists(&mut self, address: &Pubkey) -> bool {
+        self.get_account(address).await.is_some()
+    }
+
+    /// Decodes an anchor account, checking its discriminator
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self
+            .get_account(address)
+            .await
+            .unwrap_or_else(|| panic!("account {address} does not exist"));
+
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn mint(&mut self, address: &Pubkey) -> Mint {
+        let account = self.get_account(address).await.unwrap();
+        StateWithExtensions::<Mint>::unpack(&account.data)
+            .unwrap()
+            .base
+    }
+
+    pub async fn token_account(&mut self, address: &Pubkey) -> TokenAccount {
+        let account = self.get_account(address).await.unwrap();
+        StateWithExtensions::<TokenAccount>::unpack(&account.data)
+            .unwrap()
+            .base
+    }
+
+    pub async fn token_metadata(&mut self, mint: &Pubkey) -> TokenMetadata {
+        let account = self.get_account(mint).await.unwrap();
+        StateWithExtensions::<Mint>::unpack(&account.data)
+            .unwrap()
+            .get_variable_len_extension::<TokenMetadata>()
+            .unwrap()
+    }
+
+    pub async fn metadata_field(&mut self, mint: &Pubkey, field: &str) -> Option<String> {
+        self.token_metadata(mint)
+            .await
+            .additional_metadata
+            .into_iter()
+            .find(|(key, _)| key == field)
+            .map(|(_, value)| value)
+    }
+
+    /// Writes an anchor account owned by `tokengator_minter` straight into the bank
+    pub fn set_program_account<T: AccountSerialize>(
+        &mut self,
+        address: &Pubkey,
+        account: &T,
+        space: usize,
+    ) {
+        let mut data = Vec::with_capacity(space);
+        account.try_serialize(&mut data).unwrap();
+        data.resize(space, 0);
+
+        self.set_account_data(address, data, TOKENGATOR_MINTER_PROGRAM_ID);
+    }
+
+    /// Edits the raw data of an existing account in place
+    pub async fn modify_account_data(&mut self, address: &Pubkey, modify: impl FnOnce(&mut [u8])) {
+        let account = self.get_account(address).await.unwrap();
+        let mut account = AccountSharedData::from(account);
+        modify(account.data_as_mut_slice());
+
+        self.context.set_account(address, &account);
+    }
+
+    pub fn set_account_data(&mut self, address: &Pubkey, data: Vec<u8>, owner: Pubkey) {
+        let mut account = AccountSharedData::new(
+            Rent::default().minimum_balance(data.len()),
+            data.len(),
+            &owner,
+        );
+        account.set_data_from_slice(&data);
+
+        self.context.set_account(address, &account);
+    }
+
+    pub async fn unix_timestamp(&mut self) -> i64 {
+        self.context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+            .unix_timestamp
+    }
+
+    /// Moves the clock forward without producing new slots
+    pub async fn warp_forward(&mut self, seconds: i64) {
+        let mut clock = self
+            .context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap();
+        clock.unix_timestamp += seconds;
+
+        self.context.set_sysvar(&clock);
+    }
+}
+
+/// Ed25519 program instruction carrying an attestation signed by `verifier`
+pub fn identity_attestation_instruction(
+    verifier: &Keypair,
+    attestation: &IdentityAttestation,
+) -> Instruction {
+    const PUBLIC_KEY_OFFSET: u16 = 16;
+    const SIGNATURE_OFFSET: u16 = PUBLIC_KEY_OFFSET + 32;
+    const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+    // Signature, public key and message all live in this instruction
+    const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+    let message = attestation.try_to_vec().unwrap();
+    let signature = verifier.sign_message(&message);
+
+    let mut data = vec![1, 0];
+    for offset in [
+        SIGNATURE_OFFSET,
+        CURRENT_INSTRUCTION,
+        PUBLIC_KEY_OFFSET,
+        CURRENT_INSTRUCTION,
+        MESSAGE_OFFSET,
+        u16::try_from(message.len()).unwrap(),
+        CURRENT_INSTRUCTION,
+    ] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(verifier.pubkey().as_ref());
+    data.extend_from_slice(signature.as_ref());
+    data.extend_from_slice(&message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+fn custom_error_code(result: std::result::Result<(), BanksClientError>) -> u32 {
+    match result.map_err(|error| error.unwrap()) {
+        Err(TransactionError::InstructionError(_, InstructionError::Custom(code))) => code,
+        other => panic!("expected a custom program error, got {other:?}"),
+    }
+}
+
+pub fn assert_minter_error(
+    result: std::result::Result<(), BanksClientError>,
+    error: TokenGatorMinterError,
+) {
+    let expected = error.to_string();
+    let code = custom_error_code(result);
+
+    assert_eq!(
+        code,
+        u32::from(error),
+        "expected {expected}, got error code {code}"
+    );
+}
+
+pub fn assert_anchor_error(
+    result: std::result::Result<(), BanksClientError>,
+    error: anchor_lang::error::ErrorCode,
+) {
+    let expected = error.to_string();
+    let code = custom_error_code(result);
+
+    assert_eq!(
+        code,
+        u32::from(error),
+        "expected {expected}, got error code {code}"
+    );
+}
+
+/// Asserts a `MetadataErrors` or `MintErrors` raised by `wen_new_standard`
+pub fn assert_wns_error<E: Into<u32> + std::fmt::Display>(
+    result: std::result::Result<(), BanksClientError>,
+    error: E,
+) {
+    let expected = error.to_string();
+    let code = custom_error_code(result);
+
+    assert_eq!(
+        code,
+        error.into(),
+        "expected {expected}, got error code {code}"
+    );
+}
+
+pub fn assert_token_error(
+    result: std::result::Result<(), BanksClientError>,
+    error: spl_token_2022::error::TokenError,
+) {
+    let expected = error.to_string();
+    let code = custom_error_code(result);
+
+    assert_eq!(
+        code, error as u32,
+        "expected {expected}, got error code {code}"
+    );
+}
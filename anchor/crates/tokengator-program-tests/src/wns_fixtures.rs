@@ -0,0 +1,339 @@
+//! Groups and mints driven through `wen_new_standard` directly, with a keypair as the group authority.
+
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id, ID as ASSOCIATED_TOKEN_PROGRAM_ID,
+};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+    sysvar::rent::ID as RENT_SYSVAR_ID,
+};
+use tokengator_client::{
+    instructions::wns,
+    pda,
+    wen_new_standard::{
+        accounts, CreateGroupAccountArgs, CreateMintAccountArgs, CreatorWithShare, TokenGroup,
+        TokenGroupV1, UpdateGroupAccountArgs, UpdateRoyaltiesArgs,
+    },
+    WNS_PROGRAM_ID,
+};
+
+use crate::{Member, TestContext};
+
+pub const WNS_GROUP_MAX_SIZE: u32 = 10;
+
+/// Royalties of `royalty_basis_points` split between `creators` by share
+pub fn wns_royalties_args(
+    royalty_basis_points: u16,
+    creators: &[(Pubkey, u8)],
+) -> UpdateRoyaltiesArgs {
+    UpdateRoyaltiesArgs {
+        royalty_basis_points,
+        creators: creators
+            .iter()
+            .map(|&(address, share)| CreatorWithShare { address, share })
+            .collect(),
+    }
+}
+
+/// Group created through `create_group_account`
+pub struct WnsGroup {
+    pub group: Pubkey,
+    pub mint: Pubkey,
+    pub authority: Keypair,
+    pub token_account: Pubkey,
+}
+
+impl TestContext {
+    pub fn create_wns_group_args(&self) -> CreateGroupAccountArgs {
+        CreateGroupAccountArgs {
+            name: "Collection".to_owned(),
+            symbol: "COL".to_owned(),
+            uri: "https://tokengator.app/collection.json".to_owned(),
+            max_size: WNS_GROUP_MAX_SIZE,
+            additional_metadata: vec![],
+        }
+    }
+
+    pub fn create_wns_group_accounts(
+        &self,
+        authority: &Pubkey,
+        mint: &Pubkey,
+    ) -> accounts::CreateGroupAccount {
+        accounts::CreateGroupAccount {
+            payer: self.payer(),
+            authority: *authority,
+            receiver: *authority,
+            group: pda::wns_group(mint).0,
+            mint: *mint,
+            mint_token_account: get_associated_token_address_with_program_id(
+                authority,
+                mint,
+                &spl_token_2022::ID,
+            ),
+            manager: pda::wns_manager().0,
+            system_program: system_program::ID,
+            rent: RENT_SYSVAR_ID,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            token_program: spl_token_2022::ID,
+        }
+    }
+
+    pub async fn create_wns_group(&mut self, args: CreateGroupAccountArgs) -> WnsGroup {
+        let authority = self.funded_keypair().await;
+        let mint = Keypair::new();
+        let accounts = self.create_wns_group_accounts(&authority.pubkey(), &mint.pubkey());
+        let token_account = accounts.mint_token_account;
+
+        self.process(
+            &[wns::create_group_account(accounts, args)],
+            &[&authority, &mint],
+        )
+        .await
+        .unwrap();
+
+        WnsGroup {
+            group: pda::wns_group(&mint.pubkey()).0,
+            mint: mint.pubkey(),
+            authority,
+            token_account,
+        }
+    }
+
+    pub fn create_wns_mint_args(&self) -> CreateMintAccountArgs {
+        CreateMintAccountArgs {
+            name: "Item".to_owned(),
+            symbol: "ITM".to_owned(),
+            uri: "https://tokengator.app/item.json".to_owned(),
+            permanent_delegate: None,
+            non_transferable: false,
+            finalize: false,
+        }
+    }
+
+    pub fn create_wns_mint_accounts(
+        &self,
+        authority: &Pubkey,
+        receiver: &Pubkey,
+        mint: &Pubkey,
+    ) -> accounts::CreateMintAccount {
+        accounts::CreateMintAccount {
+            payer: self.payer(),
+            authority: *authority,
+            receiver: *receiver,
+            mint: *mint,
+            mint_token_account: get_associated_token_address_with_program_id(
+                receiver,
+                mint,
+                &spl_token_2022::ID,
+            ),
+            extra_metas_account: pda::wns_extra_meta_list(mint).0,
+            manager: pda::wns_manager().0,
+            system_program: system_program::ID,
+            rent: RENT_SYSVAR_ID,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            token_program: spl_token_2022::ID,
+        }
+    }
+
+    /// Creates a mint whose metadata `authority` updates, held by a new keypair
+    pub async fn create_wns_mint(
+        &mut self,
+        authority: &Keypair,
+        args: CreateMintAccountArgs,
+    ) -> Member {
+        let holder = self.funded_keypair().await;
+        let mint = Keypair::new();
+        let accounts =
+            self.create_wns_mint_accounts(&authority.pubkey(), &holder.pubkey(), &mint.pubkey());
+        let token_account = accounts.mint_token_account;
+        let authority = authority.insecure_clone();
+
+        self.process(
+            &[wns::create_mint_account(accounts, args)],
+            &[&authority, &mint],
+        )
+        .await
+        .unwrap();
+
+        Member {
+            mint: mint.pubkey(),
+            member: pda::wns_member(&mint.pubkey()).0,
+            holder,
+            token_account,
+        }
+    }
+
+    pub fn add_wns_member_instruction(&self, group: &WnsGroup, mint: &Pubkey) -> Instruction {
+        wns::add_mint_to_group(accounts::AddGroup {
+            payer: self.payer(),
+            authority: group.authority.pubkey(),
+            group: group.group,
+            master_edition: pda::wns_master_edition(&group.group).0,
+            member: pda::wns_member(mint).0,
+            mint: *mint,
+            manager: pda::wns_manager().0,
+            system_program: system_program::ID,
+            token_program: spl_token_2022::ID,
+        })
+    }
+
+    pub fn remove_wns_member_instruction(&self, group: &WnsGroup, mint: &Pubkey) -> Instruction {
+        wns::remove_mint_from_group(accounts::RemoveGroup {
+            payer: self.payer(),
+            authority: group.authority.pubkey(),
+            group: group.group,
+            member: pda::wns_member(mint).0,
+            mint: *mint,
+            manager: pda::wns_manager().0,
+            system_program: system_program::ID,
+            token_program: spl_token_2022::ID,
+        })
+    }
+
+    /// Creates a mint under the group authority and adds it to the group
+    pub async fn create_wns_member(&mut self, group: &WnsGroup) -> Member {
+        let args = self.create_wns_mint_args();
+        let member = self.create_wns_mint(&group.authority, args).await;
+
+        let instruction = self.add_wns_member_instruction(group, &member.mint);
+        let authority = group.authority.insecure_clone();
+        self.process(&[instruction], &[&authority]).await.unwrap();
+
+        member
+    }
+
+    pub fn migrate_wns_group_instruction(&self, group: &Pubkey, mint: &Pubkey) -> Instruction {
+        wns::migrate_group_account(accounts::MigrateGroupAccount {
+            payer: self.payer(),
+            group: *group,
+            mint: *mint,
+            system_program: system_program::ID,
+        })
+    }
+
+    /// Rewrites `group` in the layout it had before the member count
+    pub async fn downgrade_wns_group(&mut self, group: &Pubkey) {
+        let account = self.get_account(group).await.unwrap();
+        let data = account.data[..8 + TokenGroupV1::LEN].to_vec();
+        assert_eq!(data[..8], TokenGroup::DISCRIMINATOR);
+
+        self.set_account_data(group, data, WNS_PROGRAM_ID);
+    }
+
+    pub fn update_wns_group_instruction(
+        &self,
+        group: &WnsGroup,
+        args: UpdateGroupAccountArgs,
+    ) -> Instruction {
+        wns::update_group_account(
+            accounts::UpdateGroupAccount {
+                payer: self.payer(),
+                authority: group.authority.pubkey(),
+                group: group.group,
+                mint: group.mint,
+                system_program: system_program::ID,
+                token_program: spl_token_2022::ID,
+            },
+            args,
+        )
+    }
+
+    pub fn create_master_edition_instruction(&self, group: &WnsGroup) -> Instruction {
+        wns::create_master_edition(accounts::CreateMasterEdition {
+            payer: self.payer(),
+            authority: group.authority.pubkey(),
+            group: group.group,
+            master_edition: pda::wns_master_edition(&group.group).0,
+            system_program: system_program::ID,
+        })
+    }
+
+    pub fn print_edition_instruction(
+        &self,
+        group: &WnsGroup,
+        receiver: &Pubkey,
+        mint: &Pubkey,
+    ) -> Instruction {
+        wns::print_edition(accounts::PrintEdition {
+            payer: self.payer(),
+            authority: group.authority.pubkey(),
+            receiver: *receiver,
+            group: group.group,
+            master_edition: pda::wns_master_edition(&group.group).0,
+            group_mint: group.mint,
+            mint: *mint,
+            mint_token_account: get_associated_token_address_with_program_id(
+                receiver,
+                mint,
+                &spl_token_2022::ID,
+            ),
+            member: pda::wns_member(mint).0,
+            extra_metas_account: pda::wns_extra_meta_list(mint).0,
+            manager: pda::wns_manager().0,
+            system_program: system_program::ID,
+            associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+            token_program: spl_token_2022::ID,
+        })
+    }
+
+    /// Prints the next edition of a master edition group to a new keypair
+    pub async fn print_wns_edition(&mut self, group: &WnsGroup) -> Member {
+        let holder = self.funded_keypair().await;
+        let mint = Keypair::new();
+        let instruction = self.print_edition_instruction(group, &holder.pubkey(), &mint.pubkey());
+        let authority = group.authority.insecure_clone();
+
+        self.process(&[instruction], &[&authority, &mint])
+            .await
+            .unwrap();
+
+        Member {
+            mint: mint.pubkey(),
+            member: pda::wns_member(&mint.pubkey()).0,
+            token_account: get_associated_token_address_with_program_id(
+                &holder.pubkey(),
+                &mint.pubkey(),
+                &spl_token_2022::ID,
+            ),
+            holder,
+        }
+    }
+
+    pub fn add_wns_royalties_instruction(
+        &self,
+        authority: &Pubkey,
+        mint: &Pubkey,
+        args: UpdateRoyaltiesArgs,
+    ) -> Instruction {
+        wns::add_royalties(
+            accounts::AddRoyalties {
+                payer: self.payer(),
+                authority: *authority,
+                mint: *mint,
+                system_program: system_program::ID,
+                token_program: spl_token_2022::ID,
+            },
+            args,
+        )
+    }
+
+    pub fn modify_wns_royalties_instruction(
+        &self,
+        authority: &Pubkey,
+        mint: &Pubkey,
+        args: UpdateRoyaltiesArgs,
+    ) -> Instruction {
+        wns::modify_royalties(
+            accounts::ModifyRoyalties {
+                payer: self.payer(),
+                authority: *authority,
+                mint: *mint,
+                system_program: system_program::ID,
+                token_program: spl_token_2022::ID,
+            },
+            args,
+        )
+    }
+}
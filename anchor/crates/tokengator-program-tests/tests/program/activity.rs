@@ -0,0 +1,115 @@
+use anchor_lang::{prelude::Pubkey, system_program};
+use tokengator_client::{
+    instructions::minter,
+    pda,
+    tokengator_minter::{
+        accounts, errors::TokenGatorMinterError, state::Activity, AppendActivityEntryArgs,
+        CreateActivityArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+const LABEL: &str = "Hackathon";
+
+async fn create_member_activity(
+    ctx: &mut TestContext,
+    minter: &WnsMinter,
+    member: &Member,
+) -> Pubkey {
+    let activity = pda::activity(&member.mint, LABEL).0;
+    let instruction = minter::create_activity(
+        accounts::CreateActivity {
+            activity,
+            minter: minter.minter,
+            group: minter.group,
+            member: member.member,
+            mint: member.mint,
+            fee_payer: ctx.payer(),
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        },
+        CreateActivityArgs {
+            label: LABEL.to_owned(),
+            start_date: None,
+            end_date: None,
+        },
+    );
+
+    ctx.process(&[instruction], &[]).await.unwrap();
+
+    activity
+}
+
+fn append_activity_entry_instruction(
+    ctx: &TestContext,
+    activity: &Pubkey,
+    minter: &Pubkey,
+    message: &str,
+) -> solana_sdk::instruction::Instruction {
+    minter::append_activity_entry(
+        accounts::AppendActivityEntry {
+            activity: *activity,
+            minter: *minter,
+            fee_payer: ctx.payer(),
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        },
+        AppendActivityEntryArgs {
+            timestamp: None,
+            message: message.to_owned(),
+            url: Some("https://tokengator.app/hackathon".to_owned()),
+            points: Some(10),
+        },
+    )
+}
+
+#[tokio::test]
+async fn create_activity() {
+    let (mut ctx, minter, member) = TestContext::with_member_wns().await;
+    let now = ctx.unix_timestamp().await;
+
+    let activity = create_member_activity(&mut ctx, &minter, &member).await;
+
+    let state: Activity = ctx.account(&activity).await;
+    assert_eq!(state.label, LABEL);
+    assert_eq!(state.minter, minter.minter);
+    assert_eq!(state.member, member.member);
+    assert_eq!(state.mint, member.mint);
+    assert_eq!(state.start_date, now);
+    assert_eq!(state.end_date, now + 60 * 60 * 24 * 30);
+    assert!(state.entries.is_empty());
+}
+
+#[tokio::test]
+async fn append_activity_entry() {
+    let (mut ctx, minter, member) = TestContext::with_member_wns().await;
+    let activity = create_member_activity(&mut ctx, &minter, &member).await;
+
+    for message in ["Joined the kickoff", "Shipped a demo"] {
+        let instruction =
+            append_activity_entry_instruction(&ctx, &activity, &minter.minter, message);
+        ctx.process(&[instruction], &[]).await.unwrap();
+    }
+
+    let state: Activity = ctx.account(&activity).await;
+    let messages: Vec<_> = state
+        .entries
+        .iter()
+        .map(|entry| entry.message.as_str())
+        .collect();
+    assert_eq!(messages, ["Joined the kickoff", "Shipped a demo"]);
+    assert_eq!(state.entries[1].points, 10);
+}
+
+#[tokio::test]
+async fn append_activity_entry_rejects_other_minter() {
+    let (mut ctx, minter, member) = TestContext::with_member_wns().await;
+    let activity = create_member_activity(&mut ctx, &minter, &member).await;
+    let args = ctx.create_minter_wns_args("Other Visa");
+    let other = ctx.create_minter_wns(args).await;
+
+    let instruction = append_activity_entry_instruction(&ctx, &activity, &other.minter, "Spoofed");
+    let result = ctx.process(&[instruction], &[]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidMinter);
+}
@@ -0,0 +1,173 @@
+use anchor_lang::{
+    prelude::{AccountInfo, Pubkey},
+    system_program,
+};
+use solana_sdk::{instruction::Instruction, signature::Signer};
+use tokengator_client::{
+    instructions::minter,
+    pda,
+    tokengator_minter::{
+        self, accounts, errors::TokenGatorMinterError, state::Minter, AddMinterAuthorityArgs,
+        RemoveMinterAuthorityArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+fn add_minter_authority_instruction(
+    ctx: &TestContext,
+    minter: &WnsMinter,
+    new_authority: Pubkey,
+) -> Instruction {
+    minter::add_minter_authority(
+        accounts::AddMinterAuthority {
+            minter: minter.minter,
+            authority: minter.authority.pubkey(),
+            fee_payer: ctx.payer(),
+            protocol_config: pda::protocol_config().0,
+            system_program: system_program::ID,
+        },
+        AddMinterAuthorityArgs { new_authority },
+    )
+}
+
+fn remove_minter_authority_instruction(
+    ctx: &TestContext,
+    minter: &WnsMinter,
+    authority_to_remove: Pubkey,
+) -> Instruction {
+    minter::remove_minter_authority(
+        accounts::RemoveMinterAuthority {
+            minter: minter.minter,
+            fee_payer: ctx.payer(),
+            authority: minter.authority.pubkey(),
+            protocol_config: pda::protocol_config().0,
+        },
+        RemoveMinterAuthorityArgs {
+            authority_to_remove,
+        },
+    )
+}
+
+#[tokio::test]
+async fn add_minter_authority() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let new_authority = ctx.funded_keypair().await;
+
+    let instruction = add_minter_authority_instruction(&ctx, &minter, new_authority.pubkey());
+    ctx.process(&[instruction], &[&minter.authority])
+        .await
+        .unwrap();
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert!(state.authorities.contains(&minter.authority.pubkey()));
+    assert!(state.authorities.contains(&new_authority.pubkey()));
+
+    // The new authority can act on the minter straight away
+    let pause = ctx.set_minter_paused_instruction(&minter.minter, &new_authority.pubkey(), true);
+    ctx.process(&[pause], &[&new_authority]).await.unwrap();
+}
+
+#[tokio::test]
+async fn add_minter_authority_rejects_existing_authority() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+
+    let instruction = add_minter_authority_instruction(&ctx, &minter, minter.authority.pubkey());
+    let result = ctx.process(&[instruction], &[&minter.authority]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::AuthorityAlreadyExists);
+}
+
+#[tokio::test]
+async fn add_minter_authority_rejects_paused_minter() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let pause = ctx.set_minter_paused_instruction(&minter.minter, &minter.authority.pubkey(), true);
+    ctx.process(&[pause], &[&minter.authority]).await.unwrap();
+
+    let instruction = add_minter_authority_instruction(&ctx, &minter, Pubkey::new_unique());
+    let result = ctx.process(&[instruction], &[&minter.authority]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::MinterPaused);
+}
+
+#[tokio::test]
+async fn remove_minter_authority() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let new_authority = Pubkey::new_unique();
+
+    let add = add_minter_authority_instruction(&ctx, &minter, new_authority);
+    ctx.process(&[add], &[&minter.authority]).await.unwrap();
+
+    let remove = remove_minter_authority_instruction(&ctx, &minter, new_authority);
+    ctx.process(&[remove], &[&minter.authority]).await.unwrap();
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert_eq!(state.authorities, vec![minter.authority.pubkey()]);
+}
+
+#[tokio::test]
+async fn remove_minter_authority_keeps_last_authority() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+
+    let remove = remove_minter_authority_instruction(&ctx, &minter, minter.authority.pubkey());
+    let result = ctx.process(&[remove], &[&minter.authority]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::CannotRemoveSoloAuthority);
+}
+
+#[tokio::test]
+async fn remove_minter_authority_rejects_unknown_authority() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+
+    let add = add_minter_authority_instruction(&ctx, &minter, Pubkey::new_unique());
+    ctx.process(&[add], &[&minter.authority]).await.unwrap();
+
+    let remove = remove_minter_authority_instruction(&ctx, &minter, Pubkey::new_unique());
+    let result = ctx.process(&[remove], &[&minter.authority]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::AuthorityNonExistant);
+}
+
+#[tokio::test]
+async fn remove_minter_authority_rejects_paused_protocol() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let new_authority = Pubkey::new_unique();
+    let add = add_minter_authority_instruction(&ctx, &minter, new_authority);
+    ctx.process(&[add], &[&minter.authority]).await.unwrap();
+
+    let admin = ctx.admin.insecure_clone();
+    let pause = ctx.set_protocol_paused_instruction(&admin.pubkey(), true);
+    ctx.process(&[pause], &[&admin]).await.unwrap();
+
+    let remove = remove_minter_authority_instruction(&ctx, &minter, new_authority);
+    let result = ctx.process(&[remove], &[&minter.authority]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::ProgramPaused);
+}
+
+// Every instruction hands `realloc_account` an account anchor already owner checked,
+// so the guard is called directly with an account of another program
+#[test]
+fn realloc_account_rejects_foreign_owner() {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = vec![0; 8];
+    let account = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    let result =
+        tokengator_minter::utils::realloc_account(account.clone(), 16, account.clone(), account);
+
+    assert_eq!(
+        result.unwrap_err(),
+        TokenGatorMinterError::InvalidAccountOwner.into()
+    );
+}
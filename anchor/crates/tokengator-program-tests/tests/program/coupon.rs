@@ -0,0 +1,290 @@
+use anchor_lang::{prelude::Pubkey, solana_program::hash::hash};
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::{Keypair, Signer};
+use tokengator_client::{
+    instructions::minter,
+    pda,
+    tokengator_minter::{
+        accounts,
+        errors::TokenGatorMinterError,
+        state::{Coupon, CouponDiscount, ReceiptType},
+        CreateCouponArgs, PrepareForPaymentArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+const CODE: &str = "EARLYBIRD";
+
+fn coupon_args(discount: CouponDiscount) -> CreateCouponArgs {
+    CreateCouponArgs {
+        code_hash: hash(CODE.as_bytes()).to_bytes(),
+        discount,
+        max_redemptions: 10,
+        expires_at: None,
+        wallet: None,
+    }
+}
+
+/// Pays the first tier of `minter` with `coupon`, sending `amount` and revealing `code`
+async fn prepare_with_coupon(
+    ctx: &mut TestContext,
+    minter: &WnsMinter,
+    applicant: &Keypair,
+    coupon: Pubkey,
+    code: Option<&str>,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    ctx.fund_payment_tokens(&applicant.pubkey(), amount).await;
+
+    let accounts = ctx.prepare_for_payment_accounts(
+        &applicant.pubkey(),
+        &minter.authority.pubkey(),
+        Some(coupon),
+    );
+    let args = PrepareForPaymentArgs {
+        payment_amount: amount,
+        payment_type: ReceiptType::User,
+        payment_tier: Some(0),
+        coupon_code: code.map(str::to_owned),
+    };
+
+    ctx.process(&[minter::prepare_for_payment(accounts, args)], &[applicant])
+        .await
+}
+
+/// Mints the badge paid for by `applicant` with the `coupon` redeemed on its receipt
+async fn mint_with_coupon(
+    ctx: &mut TestContext,
+    minter: &WnsMinter,
+    applicant: &Keypair,
+    coupon: Pubkey,
+) -> Result<(), BanksClientError> {
+    let mint = Keypair::new();
+    let mut accounts = ctx.mint_minter_wns_accounts(minter, &applicant.pubkey(), &mint.pubkey());
+    accounts.coupon = Some(coupon);
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+
+    ctx.process(&instructions, &[&minter.authority, &mint])
+        .await
+}
+
+#[tokio::test]
+async fn create_coupon() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+
+    let coupon = ctx
+        .create_coupon(&minter, coupon_args(CouponDiscount::Percentage(2_500)))
+        .await;
+
+    let state: Coupon = ctx.account(&coupon).await;
+    assert_eq!(state.minter, minter.minter);
+    assert_eq!(state.code_hash, hash(CODE.as_bytes()).to_bytes());
+    assert_eq!(state.max_redemptions, 10);
+    assert_eq!(state.redemptions, 0);
+    assert_eq!(state.fee_payer, ctx.payer());
+}
+
+#[tokio::test]
+async fn remove_coupon() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let coupon = ctx
+        .create_coupon(&minter, coupon_args(CouponDiscount::Fixed(1_000)))
+        .await;
+
+    let instruction = minter::remove_coupon(accounts::RemoveCoupon {
+        coupon,
+        minter: minter.minter,
+        authority: minter.authority.pubkey(),
+        fee_payer: ctx.payer(),
+        protocol_config: pda::protocol_config().0,
+    });
+    ctx.process(&[instruction], &[&minter.authority])
+        .await
+        .unwrap();
+
+    assert!(!ctx.account_exists(&coupon).await);
+}
+
+#[tokio::test]
+async fn prepare_for_payment_redeems_coupon() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let coupon = ctx
+        .create_coupon(&minter, coupon_args(CouponDiscount::Percentage(5_000)))
+        .await;
+    let applicant = ctx.funded_keypair().await;
+
+    prepare_with_coupon(
+        &mut ctx,
+        &minter,
+        &applicant,
+        coupon,
+        Some(CODE),
+        TIER_PRICE / 2,
+    )
+    .await
+    .unwrap();
+
+    let state: Coupon = ctx.account(&coupon).await;
+    assert_eq!(state.redemptions, 1);
+
+    mint_with_coupon(&mut ctx, &minter, &applicant, coupon)
+        .await
+        .unwrap();
+
+    let state: Coupon = ctx.account(&coupon).await;
+    assert_eq!(state.redemptions, 1);
+}
+
+#[tokio::test]
+async fn wallet_coupon_is_redeemed_without_code() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let applicant = ctx.funded_keypair().await;
+    let coupon = ctx
+        .create_coupon(
+            &minter,
+            CreateCouponArgs {
+                wallet: Some(applicant.pubkey()),
+                ..coupon_args(CouponDiscount::Fixed(TIER_PRICE / 4))
+            },
+        )
+        .await;
+
+    let price = TIER_PRICE - TIER_PRICE / 4;
+    prepare_with_coupon(&mut ctx, &minter, &applicant, coupon, None, price)
+        .await
+        .unwrap();
+    mint_with_coupon(&mut ctx, &minter, &applicant, coupon)
+        .await
+        .unwrap();
+
+    let state: Coupon = ctx.account(&coupon).await;
+    assert_eq!(state.redemptions, 1);
+}
+
+#[tokio::test]
+async fn prepare_for_payment_rejects_wrong_coupon_code() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let coupon = ctx
+        .create_coupon(&minter, coupon_args(CouponDiscount::Percentage(5_000)))
+        .await;
+    let applicant = ctx.funded_keypair().await;
+
+    let result = prepare_with_coupon(
+        &mut ctx,
+        &minter,
+        &applicant,
+        coupon,
+        Some("LATEBIRD"),
+        TIER_PRICE / 2,
+    )
+    .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidCoupon);
+}
+
+#[tokio::test]
+async fn prepare_for_payment_rejects_expired_coupon() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let now = ctx.unix_timestamp().await;
+    let coupon = ctx
+        .create_coupon(
+            &minter,
+            CreateCouponArgs {
+                expires_at: Some(now - 1),
+                ..coupon_args(CouponDiscount::Percentage(5_000))
+            },
+        )
+        .await;
+    let applicant = ctx.funded_keypair().await;
+
+    let result = prepare_with_coupon(
+        &mut ctx,
+        &minter,
+        &applicant,
+        coupon,
+        Some(CODE),
+        TIER_PRICE / 2,
+    )
+    .await;
+
+    assert_minter_error(result, TokenGatorMinterError::CouponExpired);
+}
+
+#[tokio::test]
+async fn prepare_for_payment_rejects_exhausted_coupon() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let coupon = ctx
+        .create_coupon(
+            &minter,
+            CreateCouponArgs {
+                max_redemptions: 1,
+                ..coupon_args(CouponDiscount::Percentage(5_000))
+            },
+        )
+        .await;
+
+    let first = ctx.funded_keypair().await;
+    prepare_with_coupon(
+        &mut ctx,
+        &minter,
+        &first,
+        coupon,
+        Some(CODE),
+        TIER_PRICE / 2,
+    )
+    .await
+    .unwrap();
+
+    // The first receipt already holds the only use, before its badge is minted
+    let second = ctx.funded_keypair().await;
+    let result = prepare_with_coupon(
+        &mut ctx,
+        &minter,
+        &second,
+        coupon,
+        Some(CODE),
+        TIER_PRICE / 2,
+    )
+    .await;
+
+    assert_minter_error(result, TokenGatorMinterError::CouponRedemptionsExhausted);
+
+    mint_with_coupon(&mut ctx, &minter, &first, coupon)
+        .await
+        .unwrap();
+
+    let state: Coupon = ctx.account(&coupon).await;
+    assert_eq!(state.redemptions, 1);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_honors_coupon_expired_after_prepare() {
+    let (mut ctx, minter) = TestContext::with_minter_wns().await;
+    let now = ctx.unix_timestamp().await;
+    let coupon = ctx
+        .create_coupon(
+            &minter,
+            CreateCouponArgs {
+                expires_at: Some(now + 60),
+                ..coupon_args(CouponDiscount::Percentage(5_000))
+            },
+        )
+        .await;
+    let applicant = ctx.funded_keypair().await;
+
+    prepare_with_coupon(
+        &mut ctx,
+        &minter,
+        &applicant,
+        coupon,
+        Some(CODE),
+        TIER_PRICE / 2,
+    )
+    .await
+    .unwrap();
+    ctx.warp_forward(120).await;
+
+    mint_with_coupon(&mut ctx, &minter, &applicant, coupon)
+        .await
+        .unwrap();
+}
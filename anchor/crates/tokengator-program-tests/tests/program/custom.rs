@@ -0,0 +1,380 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id, ID as ASSOCIATED_TOKEN_PROGRAM_ID,
+};
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+    system_instruction,
+};
+use spl_token_2022::{
+    extension::{group_pointer, ExtensionType, StateWithExtensionsMut},
+    state::Mint,
+};
+use tokengator_client::{
+    instructions::minter,
+    pda,
+    tokengator_minter::{
+        accounts,
+        errors::TokenGatorMinterError,
+        state::{
+            Group, Minter, MinterApplicationConfig, MinterConfig, PaymentConfig, TrailingBool,
+        },
+        CreateMinterArgs, UpdateMemberMetadataArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+/// Runs `create_minter` for a new authority, after `modify` tampered with the accounts
+async fn try_create_minter(
+    ctx: &mut TestContext,
+    args: CreateMinterArgs,
+    modify: impl FnOnce(&mut accounts::CreateMinter),
+) -> std::result::Result<(), BanksClientError> {
+    let authority = ctx.funded_keypair().await;
+    let mint = Keypair::new();
+    let mut accounts = ctx.create_minter_accounts(&authority.pubkey(), &mint.pubkey(), &args.name);
+    modify(&mut accounts);
+
+    ctx.process(
+        &[minter::create_minter(accounts, args)],
+        &[&authority, &mint],
+    )
+    .await
+}
+
+fn remove_minter_instruction(ctx: &TestContext, minter: &CustomMinter) -> Instruction {
+    minter::remove_minter(accounts::RemoveMinter {
+        minter: minter.minter,
+        mint: minter.mint,
+        fee_payer: ctx.payer(),
+        authority: minter.authority.pubkey(),
+        protocol_config: pda::protocol_config().0,
+        token_program: spl_token_2022::ID,
+        associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: system_program::ID,
+    })
+}
+
+/// Minter created before the group extension, still pointing at its custom group account
+async fn create_legacy_minter(ctx: &mut TestContext, name: &str) -> CustomMinter {
+    let authority = ctx.funded_keypair().await;
+    let mint = Keypair::new();
+    let (minter, bump) = pda::minter(&mint.pubkey(), name);
+    let legacy_group = pda::legacy_group(&mint.pubkey()).0;
+
+    let payer = ctx.payer();
+    let space =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::GroupPointer]).unwrap();
+
+    ctx.process(
+        &[
+            system_instruction::create_account(
+                &payer,
+                &mint.pubkey(),
+                Rent::default().minimum_balance(space),
+                u64::try_from(space).unwrap(),
+                &spl_token_2022::ID,
+            ),
+            group_pointer::instruction::initialize(
+                &spl_token_2022::ID,
+                &mint.pubkey(),
+                Some(minter),
+                Some(legacy_group),
+            )
+            .unwrap(),
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::ID,
+                &mint.pubkey(),
+                &minter,
+                Some(&minter),
+                0,
+            )
+            .unwrap(),
+        ],
+        &[&mint],
+    )
+    .await
+    .unwrap();
+
+    let args = ctx.create_minter_args(name);
+    let authorities = vec![authority.pubkey()];
+    let state = Minter {
+        bump,
+        community_id: pda::community_id(COMMUNITY),
+        group: legacy_group,
+        name: name.to_owned(),
+        description: args.description,
+        image_url: args.image_url,
+        fee_payer: payer,
+        authorities: authorities.clone(),
+        payment_config: args.payment_config,
+        minter_config: MinterConfig {
+            mint: mint.pubkey(),
+            application_config: MinterApplicationConfig {
+                identities: vec![],
+                payment_config: PaymentConfig::default(),
+            },
+            metadata_config: args.metadata_config.clone(),
+            interest_config: None,
+            transfer_fee_config: None,
+        },
+        paused: false,
+        payment_tiers: args.application_config.payment_tiers.clone(),
+        transferable: TrailingBool(true),
+    };
+    let space = Minter::size(
+        &authorities,
+        &[],
+        &args.application_config.payment_tiers,
+        &args.metadata_config,
+    );
+    ctx.set_program_account(&minter, &state, space);
+
+    let group = Group {
+        update_authority: minter,
+        mint: mint.pubkey(),
+        size: 0,
+        max_size: 50,
+    };
+    ctx.set_program_account(&legacy_group, &group, Group::size());
+
+    CustomMinter {
+        minter,
+        mint: mint.pubkey(),
+        authority,
+        name: name.to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn create_minter() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_args("Business Visa");
+    let minter = ctx.create_minter(args).await;
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert_eq!(state.name, "Business Visa");
+    assert_eq!(state.group, minter.mint);
+    assert_eq!(state.community_id, pda::community_id(COMMUNITY));
+    assert_eq!(state.authorities, vec![minter.authority.pubkey()]);
+    assert_eq!(state.minter_config.mint, minter.mint);
+
+    // The minter holds the only collection token and keeps the mint authorities
+    let mint = ctx.mint(&minter.mint).await;
+    assert_eq!(mint.supply, 1);
+    assert_eq!(mint.decimals, 0);
+    assert_eq!(mint.mint_authority, Some(minter.minter).into());
+    assert_eq!(mint.freeze_authority, Some(minter.minter).into());
+
+    let minter_token_account = get_associated_token_address_with_program_id(
+        &minter.minter,
+        &minter.mint,
+        &spl_token_2022::ID,
+    );
+    assert_eq!(ctx.token_account(&minter_token_account).await.amount, 1);
+    assert_eq!(
+        ctx.metadata_field(&minter.mint, "preset").await.as_deref(),
+        Some("business-visa")
+    );
+}
+
+#[tokio::test]
+async fn create_minter_rejects_authority_as_fee_payer() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_args("Business Visa");
+    let payer = ctx.payer();
+    let mint = Keypair::new();
+
+    let accounts = ctx.create_minter_accounts(&payer, &mint.pubkey(), &args.name);
+    let result = ctx
+        .process(&[minter::create_minter(accounts, args)], &[&mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidFeePayer);
+}
+
+#[tokio::test]
+async fn create_minter_rejects_other_minter_token_account() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_args("Business Visa");
+
+    let result = try_create_minter(&mut ctx, args, |accounts| {
+        accounts.minter_token_account = get_associated_token_address_with_program_id(
+            &accounts.authority,
+            &accounts.mint,
+            &spl_token_2022::ID,
+        );
+    })
+    .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidMinterTokenAccount);
+}
+
+#[tokio::test]
+async fn create_minter_rejects_missing_payment_tiers() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_args("Business Visa");
+    args.application_config.payment_tiers = vec![];
+
+    let result = try_create_minter(&mut ctx, args, |_| {}).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidPaymentTier);
+}
+
+#[tokio::test]
+async fn mint_preset() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_args("Business Visa");
+    let minter = ctx.create_minter(args).await;
+    let member_mint = Keypair::new();
+
+    let accounts = ctx.mint_preset_accounts(&minter, &member_mint.pubkey());
+    let authority_token_account = accounts.authority_token_account;
+    ctx.process(
+        &[minter::mint_preset(accounts)],
+        &[&minter.authority, &member_mint],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(ctx.token_account(&authority_token_account).await.amount, 1);
+    let metadata = ctx.token_metadata(&member_mint.pubkey()).await;
+    assert_eq!(metadata.name, "Business Visa");
+    assert_eq!(
+        metadata.update_authority,
+        Some(minter.minter).try_into().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn mint_preset_rejects_other_token_account() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_args("Business Visa");
+    let minter = ctx.create_minter(args).await;
+    let member_mint = Keypair::new();
+
+    let mut accounts = ctx.mint_preset_accounts(&minter, &member_mint.pubkey());
+    accounts.authority_token_account = get_associated_token_address_with_program_id(
+        &ctx.payer(),
+        &member_mint.pubkey(),
+        &spl_token_2022::ID,
+    );
+    let result = ctx
+        .process(
+            &[minter::mint_preset(accounts)],
+            &[&minter.authority, &member_mint],
+        )
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidTokenAccount);
+}
+
+#[tokio::test]
+async fn mint_preset_requires_migrated_group() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let minter = create_legacy_minter(&mut ctx, "Business Visa").await;
+    let member_mint = Keypair::new();
+
+    let accounts = ctx.mint_preset_accounts(&minter, &member_mint.pubkey());
+    let result = ctx
+        .process(
+            &[minter::mint_preset(accounts)],
+            &[&minter.authority, &member_mint],
+        )
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::GroupNotMigrated);
+}
+
+#[tokio::test]
+async fn migrate_group() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let minter = create_legacy_minter(&mut ctx, "Business Visa").await;
+    let legacy_group = pda::legacy_group(&minter.mint).0;
+
+    let migrate = minter::migrate_group(accounts::MigrateGroup {
+        group: legacy_group,
+        minter: minter.minter,
+        mint: minter.mint,
+        fee_payer: ctx.payer(),
+        authority: minter.authority.pubkey(),
+        protocol_config: pda::protocol_config().0,
+        token_program: spl_token_2022::ID,
+        system_program: system_program::ID,
+    });
+    ctx.process(&[migrate], &[&minter.authority]).await.unwrap();
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert_eq!(state.group, minter.mint);
+    assert!(!ctx.account_exists(&legacy_group).await);
+
+    // Members can be minted into the group extension right away
+    let member_mint = Keypair::new();
+    let accounts = ctx.mint_preset_accounts(&minter, &member_mint.pubkey());
+    ctx.process(
+        &[minter::mint_preset(accounts)],
+        &[&minter.authority, &member_mint],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn update_member_metdata() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_args("Business Visa");
+    let minter = ctx.create_minter(args).await;
+
+    let instruction = minter::update_member_metdata(
+        accounts::UpdateMemberMetadata {
+            minter: minter.minter,
+        },
+        UpdateMemberMetadataArgs {
+            label: "Monthly".to_owned(),
+            start_date: None,
+            end_date: None,
+        },
+    );
+
+    ctx.process(&[instruction], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn remove_minter() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_args("Business Visa");
+    let minter = ctx.create_minter(args).await;
+
+    // Only minters whose collection token was burned can be removed
+    ctx.modify_account_data(&minter.mint, |data| {
+        let mut mint = StateWithExtensionsMut::<Mint>::unpack(data).unwrap();
+        mint.base.supply = 0;
+        mint.pack_base();
+    })
+    .await;
+
+    let instruction = remove_minter_instruction(&ctx, &minter);
+    ctx.process(&[instruction], &[&minter.authority])
+        .await
+        .unwrap();
+
+    assert!(!ctx.account_exists(&minter.minter).await);
+    assert!(!ctx.account_exists(&minter.mint).await);
+}
+
+#[tokio::test]
+async fn remove_minter_rejects_non_zero_supply() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_args("Business Visa");
+    let minter = ctx.create_minter(args).await;
+
+    let instruction = remove_minter_instruction(&ctx, &minter);
+    let result = ctx.process(&[instruction], &[&minter.authority]).await;
+
+    assert_minter_error(
+        result,
+        TokenGatorMinterError::CannotRemoveNonZeroSupplyMinter,
+    );
+}
@@ -0,0 +1,224 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use solana_sdk::{instruction::AccountMeta, signature::Signer};
+use spl_token_2022::{
+    extension::{
+        interest_bearing_mint::InterestBearingConfig,
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        BaseStateWithExtensions, BaseStateWithExtensionsMut, StateWithExtensions,
+        StateWithExtensionsMut,
+    },
+    state::{Account as TokenAccount, Mint},
+};
+use tokengator_client::{
+    instructions::minter,
+    pda,
+    tokengator_minter::{
+        accounts,
+        errors::TokenGatorMinterError,
+        state::{Minter, MinterInterestConfig, MinterTransferFeeConfig},
+        UpdateInterestRateArgs, UpdateTransferFeeArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+const WITHHELD_AMOUNT: u64 = 7;
+
+async fn fee_minter(ctx: &mut TestContext) -> CustomMinter {
+    let mut args = ctx.create_minter_args("Business Visa");
+    args.transfer_fee_config = Some(MinterTransferFeeConfig {
+        transfer_fee_basis_points: 100,
+        max_fee_rate: 10,
+    });
+    args.interest_config = Some(MinterInterestConfig { rate: 50 });
+
+    ctx.create_minter(args).await
+}
+
+fn minter_token_account(minter: &CustomMinter) -> Pubkey {
+    get_associated_token_address_with_program_id(&minter.minter, &minter.mint, &spl_token_2022::ID)
+}
+
+fn update_transfer_fee_instruction(
+    minter: &CustomMinter,
+    mint: &Pubkey,
+    transfer_fee_basis_points: u16,
+) -> solana_sdk::instruction::Instruction {
+    minter::update_transfer_fee(
+        accounts::UpdateTransferFee {
+            minter: minter.minter,
+            mint: *mint,
+            authority: minter.authority.pubkey(),
+            protocol_config: pda::protocol_config().0,
+            token_program: spl_token_2022::ID,
+        },
+        UpdateTransferFeeArgs {
+            transfer_fee_basis_points,
+            max_fee_rate: 20,
+        },
+    )
+}
+
+fn update_interest_rate_instruction(
+    minter: &CustomMinter,
+    rate: i16,
+) -> solana_sdk::instruction::Instruction {
+    minter::update_interest_rate(
+        accounts::UpdateInterestRate {
+            minter: minter.minter,
+            mint: minter.mint,
+            authority: minter.authority.pubkey(),
+            protocol_config: pda::protocol_config().0,
+            token_program: spl_token_2022::ID,
+        },
+        UpdateInterestRateArgs { rate },
+    )
+}
+
+async fn transfer_fee_config(ctx: &mut TestContext, mint: &Pubkey) -> TransferFeeConfig {
+    let account = ctx.get_account(mint).await.unwrap();
+    *StateWithExtensions::<Mint>::unpack(&account.data)
+        .unwrap()
+        .get_extension::<TransferFeeConfig>()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn harvest_and_withdraw_transfer_fees() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let minter = fee_minter(&mut ctx).await;
+    let source = minter_token_account(&minter);
+
+    // Stands in for the fees withheld by transfers between members
+    ctx.modify_account_data(&source, |data| {
+        let mut account = StateWithExtensionsMut::<TokenAccount>::unpack(data).unwrap();
+        let fee_amount = account.get_extension_mut::<TransferFeeAmount>().unwrap();
+        fee_amount.withheld_amount = WITHHELD_AMOUNT.into();
+    })
+    .await;
+
+    let mut harvest = minter::harvest_transfer_fees(accounts::HarvestTransferFees {
+        minter: minter.minter,
+        mint: minter.mint,
+        protocol_config: pda::protocol_config().0,
+        token_program: spl_token_2022::ID,
+    });
+    harvest.accounts.push(AccountMeta::new(source, false));
+    ctx.process(&[harvest], &[]).await.unwrap();
+
+    let config = transfer_fee_config(&mut ctx, &minter.mint).await;
+    assert_eq!(u64::from(config.withheld_amount), WITHHELD_AMOUNT);
+
+    let authority = minter.authority.pubkey();
+    let destination = ctx.create_token_account(&authority, &minter.mint).await;
+    let withdraw = minter::withdraw_transfer_fees(accounts::WithdrawTransferFees {
+        minter: minter.minter,
+        mint: minter.mint,
+        destination,
+        authority,
+        protocol_config: pda::protocol_config().0,
+        token_program: spl_token_2022::ID,
+    });
+    ctx.process(&[withdraw], &[&minter.authority])
+        .await
+        .unwrap();
+
+    let config = transfer_fee_config(&mut ctx, &minter.mint).await;
+    assert_eq!(u64::from(config.withheld_amount), 0);
+    assert_eq!(
+        ctx.token_account(&destination).await.amount,
+        WITHHELD_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn harvest_transfer_fees_requires_token_accounts() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let minter = fee_minter(&mut ctx).await;
+
+    let harvest = minter::harvest_transfer_fees(accounts::HarvestTransferFees {
+        minter: minter.minter,
+        mint: minter.mint,
+        protocol_config: pda::protocol_config().0,
+        token_program: spl_token_2022::ID,
+    });
+    let result = ctx.process(&[harvest], &[]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidTokenAccount);
+}
+
+#[tokio::test]
+async fn update_transfer_fee() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let minter = fee_minter(&mut ctx).await;
+
+    let instruction = update_transfer_fee_instruction(&minter, &minter.mint, 250);
+    ctx.process(&[instruction], &[&minter.authority])
+        .await
+        .unwrap();
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    let fee_config = state.minter_config.transfer_fee_config.unwrap();
+    assert_eq!(fee_config.transfer_fee_basis_points, 250);
+    assert_eq!(fee_config.max_fee_rate, 20);
+
+    let config = transfer_fee_config(&mut ctx, &minter.mint).await;
+    assert_eq!(
+        u16::from(config.newer_transfer_fee.transfer_fee_basis_points),
+        250
+    );
+}
+
+#[tokio::test]
+async fn update_transfer_fee_rejects_other_mint() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let minter = fee_minter(&mut ctx).await;
+    let other = fee_minter(&mut ctx).await;
+
+    let instruction = update_transfer_fee_instruction(&minter, &other.mint, 250);
+    let result = ctx.process(&[instruction], &[&minter.authority]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidMint);
+}
+
+#[tokio::test]
+async fn update_transfer_fee_rejects_fee_above_max() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let minter = fee_minter(&mut ctx).await;
+
+    let instruction = update_transfer_fee_instruction(&minter, &minter.mint, 10_001);
+    let result = ctx.process(&[instruction], &[&minter.authority]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidTransferFee);
+}
+
+#[tokio::test]
+async fn update_interest_rate() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let minter = fee_minter(&mut ctx).await;
+
+    let instruction = update_interest_rate_instruction(&minter, 125);
+    ctx.process(&[instruction], &[&minter.authority])
+        .await
+        .unwrap();
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert_eq!(state.minter_config.interest_config.unwrap().rate, 125);
+
+    let account = ctx.get_account(&minter.mint).await.unwrap();
+    let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    let config = mint.get_extension::<InterestBearingConfig>().unwrap();
+    assert_eq!(i16::from(config.current_rate), 125);
+}
+
+#[tokio::test]
+async fn update_interest_rate_requires_interest_config() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_args("Business Visa");
+    let minter = ctx.create_minter(args).await;
+
+    let instruction = update_interest_rate_instruction(&minter, 125);
+    let result = ctx.process(&[instruction], &[&minter.authority]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::MintExtensionNotEnabled);
+}
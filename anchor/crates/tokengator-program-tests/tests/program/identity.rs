@@ -0,0 +1,337 @@
+use anchor_lang::{prelude::Pubkey, solana_program::hash::hash, system_program};
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use tokengator_client::{
+    instructions::minter,
+    pda,
+    tokengator_minter::{
+        accounts,
+        errors::TokenGatorMinterError,
+        state::{IdentityAttestation, IdentityClaim, IdentityLink, IdentityProvider},
+        LinkIdentityArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+fn id_hash() -> [u8; 32] {
+    hash(b"salt:discord-user-42").to_bytes()
+}
+
+fn link_identity_instruction(
+    ctx: &TestContext,
+    minter: &WnsMinter,
+    wallet: &Pubkey,
+    verifier: &Pubkey,
+    provider: IdentityProvider,
+) -> Instruction {
+    minter::link_identity(
+        accounts::LinkIdentity {
+            identity_link: pda::identity_link(&minter.minter, wallet, &provider).0,
+            identity_claim: pda::identity_claim(&minter.minter, &provider, &id_hash()).0,
+            minter: minter.minter,
+            protocol_config: pda::protocol_config().0,
+            wallet: *wallet,
+            verifier: *verifier,
+            fee_payer: ctx.payer(),
+            system_program: system_program::ID,
+        },
+        LinkIdentityArgs {
+            provider,
+            id_hash: id_hash(),
+        },
+    )
+}
+
+/// Links the Discord account behind `id_hash` to `wallet`
+async fn link_discord(
+    ctx: &mut TestContext,
+    minter: &WnsMinter,
+    wallet: &Keypair,
+) -> Result<(), BanksClientError> {
+    let verifier = ctx.identity_verifier.insecure_clone();
+    let instruction = link_identity_instruction(
+        ctx,
+        minter,
+        &wallet.pubkey(),
+        &verifier.pubkey(),
+        IdentityProvider::Discord,
+    );
+
+    ctx.process(&[instruction], &[wallet, &verifier]).await
+}
+
+/// Minter requiring a Discord identity, with an applicant who linked it and paid the first tier
+async fn gated_minter() -> (TestContext, WnsMinter, Keypair) {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.application_config.identities = vec![IdentityProvider::Discord];
+    let minter = ctx.create_minter_wns(args).await;
+
+    let applicant = ctx.funded_keypair().await;
+    link_discord(&mut ctx, &minter, &applicant).await.unwrap();
+    ctx.pay_tier(&minter.authority.pubkey(), &applicant, TIER_PRICE)
+        .await;
+
+    (ctx, minter, applicant)
+}
+
+async fn attestation(
+    ctx: &mut TestContext,
+    minter: &WnsMinter,
+    wallet: &Pubkey,
+    identities: Vec<IdentityProvider>,
+) -> IdentityAttestation {
+    IdentityAttestation {
+        minter: minter.minter,
+        wallet: *wallet,
+        identities,
+        expires_at: ctx.unix_timestamp().await + 60,
+    }
+}
+
+#[tokio::test]
+async fn link_identity() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let wallet = Keypair::new();
+    let verifier = ctx.identity_verifier.insecure_clone();
+
+    let instruction = link_identity_instruction(
+        &ctx,
+        &minter,
+        &wallet.pubkey(),
+        &verifier.pubkey(),
+        IdentityProvider::Discord,
+    );
+    ctx.process(&[instruction], &[&wallet, &verifier])
+        .await
+        .unwrap();
+
+    let identity_link =
+        pda::identity_link(&minter.minter, &wallet.pubkey(), &IdentityProvider::Discord).0;
+    let link: IdentityLink = ctx.account(&identity_link).await;
+    assert_eq!(link.minter, minter.minter);
+    assert_eq!(link.wallet, wallet.pubkey());
+    assert!(matches!(link.provider, IdentityProvider::Discord));
+    assert_eq!(link.id_hash, id_hash());
+    assert_eq!(link.verifier, verifier.pubkey());
+
+    let identity_claim =
+        pda::identity_claim(&minter.minter, &IdentityProvider::Discord, &id_hash()).0;
+    let claim: IdentityClaim = ctx.account(&identity_claim).await;
+    assert_eq!(claim.identity_link, identity_link);
+}
+
+#[tokio::test]
+async fn link_identity_rejects_other_verifier() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let wallet = Keypair::new();
+    let verifier = Keypair::new();
+
+    let instruction = link_identity_instruction(
+        &ctx,
+        &minter,
+        &wallet.pubkey(),
+        &verifier.pubkey(),
+        IdentityProvider::Discord,
+    );
+    let result = ctx.process(&[instruction], &[&wallet, &verifier]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidIdentityLink);
+}
+
+#[tokio::test]
+async fn link_identity_rejects_empty_provider_value() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let wallet = Keypair::new();
+    let verifier = ctx.identity_verifier.insecure_clone();
+
+    let instruction = link_identity_instruction(
+        &ctx,
+        &minter,
+        &wallet.pubkey(),
+        &verifier.pubkey(),
+        IdentityProvider::EmailDomain(String::new()),
+    );
+    let result = ctx.process(&[instruction], &[&wallet, &verifier]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidIdentityProvider);
+}
+
+#[tokio::test]
+async fn unlink_identity() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let wallet = Keypair::new();
+    let verifier = ctx.identity_verifier.insecure_clone();
+
+    let link = link_identity_instruction(
+        &ctx,
+        &minter,
+        &wallet.pubkey(),
+        &verifier.pubkey(),
+        IdentityProvider::Discord,
+    );
+    ctx.process(&[link], &[&wallet, &verifier]).await.unwrap();
+
+    let identity_link =
+        pda::identity_link(&minter.minter, &wallet.pubkey(), &IdentityProvider::Discord).0;
+    let identity_claim =
+        pda::identity_claim(&minter.minter, &IdentityProvider::Discord, &id_hash()).0;
+    let unlink = minter::unlink_identity(accounts::UnlinkIdentity {
+        identity_link,
+        identity_claim,
+        protocol_config: pda::protocol_config().0,
+        authority: wallet.pubkey(),
+        fee_payer: ctx.payer(),
+    });
+    ctx.process(&[unlink], &[&wallet]).await.unwrap();
+
+    assert!(!ctx.account_exists(&identity_link).await);
+    assert!(!ctx.account_exists(&identity_claim).await);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_with_identity_attestation() {
+    let (mut ctx, minter, applicant) = gated_minter().await;
+    let attestation = attestation(
+        &mut ctx,
+        &minter,
+        &applicant.pubkey(),
+        vec![IdentityProvider::Discord, IdentityProvider::GitHub],
+    )
+    .await;
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let token_account = accounts.receiver_token_account;
+    let instructions = ctx.mint_minter_wns_instructions(accounts, Some(&attestation));
+    ctx.process(&instructions, &[&minter.authority, &mint])
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.token_account(&token_account).await.amount, 1);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_requires_identity_attestation() {
+    let (mut ctx, minter, applicant) = gated_minter().await;
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidIdentityAttestation);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_rejects_attestation_from_other_signer() {
+    let (mut ctx, minter, applicant) = gated_minter().await;
+    let attestation = attestation(
+        &mut ctx,
+        &minter,
+        &applicant.pubkey(),
+        vec![IdentityProvider::Discord],
+    )
+    .await;
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let instructions = [
+        identity_attestation_instruction(&Keypair::new(), &attestation),
+        minter::mint_minter_wns(accounts, ctx.mint_minter_wns_args()),
+    ];
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidIdentityAttestation);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_rejects_expired_attestation() {
+    let (mut ctx, minter, applicant) = gated_minter().await;
+    let mut attestation = attestation(
+        &mut ctx,
+        &minter,
+        &applicant.pubkey(),
+        vec![IdentityProvider::Discord],
+    )
+    .await;
+    attestation.expires_at = ctx.unix_timestamp().await;
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let instructions = ctx.mint_minter_wns_instructions(accounts, Some(&attestation));
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::IdentityAttestationExpired);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_rejects_attestation_missing_identity() {
+    let (mut ctx, minter, applicant) = gated_minter().await;
+    let attestation = attestation(
+        &mut ctx,
+        &minter,
+        &applicant.pubkey(),
+        vec![IdentityProvider::GitHub],
+    )
+    .await;
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let instructions = ctx.mint_minter_wns_instructions(accounts, Some(&attestation));
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::MissingIdentity);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_rejects_identity_linked_to_other_wallet() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.application_config.identities = vec![IdentityProvider::Discord];
+    let minter = ctx.create_minter_wns(args).await;
+
+    // The Discord account already backs another wallet, so the applicant cannot link it
+    let member = ctx.funded_keypair().await;
+    link_discord(&mut ctx, &minter, &member).await.unwrap();
+    let applicant = ctx.funded_keypair().await;
+    assert!(link_discord(&mut ctx, &minter, &applicant).await.is_err());
+    ctx.pay_tier(&minter.authority.pubkey(), &applicant, TIER_PRICE)
+        .await;
+
+    // An attestation for the same identity does not stand in for the link
+    let attestation = attestation(
+        &mut ctx,
+        &minter,
+        &applicant.pubkey(),
+        vec![IdentityProvider::Discord],
+    )
+    .await;
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let instructions = ctx.mint_minter_wns_instructions(accounts, Some(&attestation));
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::MissingIdentity);
+}
@@ -0,0 +1,21 @@
+//! Every `tokengator_minter` instruction, run against the programs loaded by the harness.
+//!
+//! Each `TokenGatorMinterError` variant is asserted next to the instruction raising it,
+//! apart from `InvalidAccountOwner`: accounts are only reallocated after anchor checked
+//! their owner, so `realloc_account` is called directly instead.
+
+mod activity;
+mod authority;
+mod coupon;
+mod custom;
+mod fees;
+mod identity;
+mod minter_wns;
+mod pause;
+mod payment;
+mod protocol;
+mod subscription;
+mod wns_edition;
+mod wns_group;
+mod wns_hook;
+mod wns_mint;
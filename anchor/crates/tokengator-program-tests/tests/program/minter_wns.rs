@@ -0,0 +1,905 @@
+use anchor_lang::{
+    prelude::Pubkey, solana_program::program_option::COption, system_program, AnchorSerialize,
+    Discriminator,
+};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use solana_program_test::BanksClientError;
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use spl_token_2022::error::TokenError;
+use tokengator_client::{
+    instructions::{minter, wns},
+    pda,
+    tokengator_minter::{
+        accounts,
+        errors::TokenGatorMinterError,
+        state::{Minter, PaymentConfig, ProtocolConfig, Receipt},
+        CreateMinterWNSArgs, TransferGroupAuthorityWNSArgs, UpdateMemberMetadataWNSArgs,
+    },
+    wen_new_standard::{self, TokenGroup, TokenGroupMember},
+    TOKENGATOR_MINTER_PROGRAM_ID, WNS_PROGRAM_ID,
+};
+use tokengator_program_tests::*;
+
+/// Runs `create_minter_wns` for a new community authority, after `modify` tampered with the accounts
+async fn try_create_minter_wns(
+    ctx: &mut TestContext,
+    args: CreateMinterWNSArgs,
+    modify: impl FnOnce(&mut accounts::CreateMinterWNS),
+) -> Result<(), BanksClientError> {
+    let authority = ctx.community_authority().await;
+    let mint = Keypair::new();
+    let mut accounts =
+        ctx.create_minter_wns_accounts(&authority.pubkey(), &mint.pubkey(), &args.name);
+    modify(&mut accounts);
+
+    ctx.process(
+        &[minter::create_minter_wns(accounts, args)],
+        &[&authority, &mint],
+    )
+    .await
+}
+
+/// `state` in the layout minters were stored in before payment tiers, pausing and transferable
+fn legacy_minter_data(state: &Minter) -> Vec<u8> {
+    let mut data = Minter::DISCRIMINATOR.to_vec();
+    state.bump.serialize(&mut data).unwrap();
+    state.community_id.serialize(&mut data).unwrap();
+    state.group.serialize(&mut data).unwrap();
+    state.name.serialize(&mut data).unwrap();
+    state.description.serialize(&mut data).unwrap();
+    state.image_url.serialize(&mut data).unwrap();
+    state.fee_payer.serialize(&mut data).unwrap();
+    state.authorities.serialize(&mut data).unwrap();
+    state.payment_config.serialize(&mut data).unwrap();
+    state.minter_config.serialize(&mut data).unwrap();
+    // Legacy minters were sized with an unused 8 byte `payment_expires_at`, the least they end with
+    data.extend([0; 8]);
+    data
+}
+
+fn update_member_metadata_instruction(
+    ctx: &TestContext,
+    minter: &WnsMinter,
+    member: &Pubkey,
+    mint: &Pubkey,
+    field: &str,
+    new_value: &str,
+) -> Instruction {
+    minter::update_member_metadata_wns(
+        accounts::UpdateMemberMetadataWNS {
+            minter: minter.minter,
+            group: minter.group,
+            member: *member,
+            mint: *mint,
+            fee_payer: ctx.payer(),
+            protocol_config: pda::protocol_config().0,
+            wns_program: WNS_PROGRAM_ID,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        UpdateMemberMetadataWNSArgs {
+            field: field.to_owned(),
+            new_value: new_value.to_owned(),
+        },
+    )
+}
+
+fn sync_member_holder_instruction(
+    ctx: &TestContext,
+    minter: &WnsMinter,
+    member: &Member,
+    holder_token_account: &Pubkey,
+) -> Instruction {
+    minter::sync_member_holder_wns(accounts::SyncMemberHolderWNS {
+        minter: minter.minter,
+        group: minter.group,
+        member: member.member,
+        mint: member.mint,
+        holder_token_account: *holder_token_account,
+        payer: ctx.payer(),
+        protocol_config: pda::protocol_config().0,
+        wns_program: WNS_PROGRAM_ID,
+        token_program: spl_token_2022::ID,
+        system_program: system_program::ID,
+    })
+}
+
+fn transfer_group_authority_wns_instruction(
+    ctx: &TestContext,
+    minter: &WnsMinter,
+    new_authority: Pubkey,
+) -> Instruction {
+    minter::transfer_group_authority_wns(
+        accounts::TransferGroupAuthorityWNS {
+            group: minter.group,
+            group_authority_transfer: pda::wns_group_authority_transfer(&minter.group).0,
+            manager: pda::wns_manager().0,
+            minter: minter.minter,
+            mint: minter.mint,
+            fee_payer: ctx.payer(),
+            authority: minter.authority.pubkey(),
+            protocol_config: pda::protocol_config().0,
+            wns_program: WNS_PROGRAM_ID,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        TransferGroupAuthorityWNSArgs { new_authority },
+    )
+}
+
+fn cancel_group_authority_transfer_wns_instruction(
+    ctx: &TestContext,
+    minter: &WnsMinter,
+) -> Instruction {
+    minter::cancel_group_authority_transfer_wns(accounts::CancelGroupAuthorityTransferWNS {
+        group: minter.group,
+        group_authority_transfer: pda::wns_group_authority_transfer(&minter.group).0,
+        manager: pda::wns_manager().0,
+        minter: minter.minter,
+        mint: minter.mint,
+        fee_payer: ctx.payer(),
+        authority: minter.authority.pubkey(),
+        protocol_config: pda::protocol_config().0,
+        wns_program: WNS_PROGRAM_ID,
+        token_program: spl_token_2022::ID,
+    })
+}
+
+fn remove_minter_wns_instruction(ctx: &TestContext, minter: &WnsMinter) -> Instruction {
+    minter::remove_minter_wns(accounts::RemoveMinterWNS {
+        group: minter.group,
+        manager: pda::wns_manager().0,
+        minter: minter.minter,
+        minter_token_account: get_associated_token_address_with_program_id(
+            &minter.minter,
+            &minter.mint,
+            &spl_token_2022::ID,
+        ),
+        mint: minter.mint,
+        fee_payer: ctx.payer(),
+        authority: minter.authority.pubkey(),
+        protocol_config: pda::protocol_config().0,
+        wns_program: WNS_PROGRAM_ID,
+        token_program: spl_token_2022::ID,
+        system_program: system_program::ID,
+    })
+}
+
+#[tokio::test]
+async fn create_minter_wns() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert_eq!(state.name, "Business Visa");
+    assert_eq!(state.group, minter.group);
+    assert_eq!(state.community_id, pda::community_id(COMMUNITY));
+    assert_eq!(state.fee_payer, ctx.payer());
+    assert_eq!(state.authorities, vec![minter.authority.pubkey()]);
+    assert_eq!(state.minter_config.mint, minter.mint);
+
+    let group: TokenGroup = ctx.account(&minter.group).await;
+    assert_eq!(group.update_authority, minter.minter);
+    assert_eq!(group.mint, minter.mint);
+    assert_eq!(group.size, 0);
+
+    // The minter holds the collection token and the group carries the minter details
+    let minter_token_account = get_associated_token_address_with_program_id(
+        &minter.minter,
+        &minter.mint,
+        &spl_token_2022::ID,
+    );
+    assert_eq!(ctx.token_account(&minter_token_account).await.amount, 1);
+    assert_eq!(
+        ctx.metadata_field(&minter.mint, "community").await,
+        Some(pda::community_id(COMMUNITY).to_string())
+    );
+    assert_eq!(
+        ctx.metadata_field(&minter.mint, "preset").await.as_deref(),
+        Some("business-visa")
+    );
+
+    // The community fee went to the treasury once and the receipt was spent
+    let treasury_token_account = get_associated_token_address_with_program_id(
+        &ctx.treasury.pubkey(),
+        &ctx.payment_mint,
+        &spl_token_2022::ID,
+    );
+    assert_eq!(
+        ctx.token_account(&treasury_token_account).await.amount,
+        COMMUNITY_FEE
+    );
+    let authority_token_account = get_associated_token_address_with_program_id(
+        &minter.authority.pubkey(),
+        &ctx.payment_mint,
+        &spl_token_2022::ID,
+    );
+    assert_eq!(ctx.token_account(&authority_token_account).await.amount, 0);
+    let receipt = pda::receipt(
+        &minter.authority.pubkey(),
+        &minter.authority.pubkey(),
+        &ctx.payment_mint,
+    )
+    .0;
+    assert!(!ctx.account_exists(&receipt).await);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_other_authority() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let authority = ctx.community_authority().await;
+    let receipt = pda::receipt(&authority.pubkey(), &authority.pubkey(), &ctx.payment_mint).0;
+
+    let result = try_create_minter_wns(&mut ctx, args, |accounts| accounts.receipt = receipt).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn create_minter_wns_accepts_price_other_than_community_fee() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.payment_config.price = COMMUNITY_FEE + 1;
+    let minter = ctx.create_minter_wns(args).await;
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert_eq!(state.payment_config.price, COMMUNITY_FEE + 1);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_receipt_not_paid_to_treasury() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let authority = ctx.community_authority().await;
+
+    // Community receipts prepared before the fee went to the treasury
+    let receipt = pda::receipt(&authority.pubkey(), &authority.pubkey(), &ctx.payment_mint).0;
+    let mut state: Receipt = ctx.account(&receipt).await;
+    state.protocol_fee = 0;
+    let space = ctx.get_account(&receipt).await.unwrap().data.len();
+    ctx.set_program_account(&receipt, &state, space);
+
+    let mint = Keypair::new();
+    let accounts = ctx.create_minter_wns_accounts(&authority.pubkey(), &mint.pubkey(), &args.name);
+    let result = ctx
+        .process(
+            &[minter::create_minter_wns(accounts, args)],
+            &[&authority, &mint],
+        )
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidProtocolFee);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_missing_payment_tiers() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.application_config.payment_tiers = vec![];
+
+    let result = try_create_minter_wns(&mut ctx, args, |_| {}).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidPaymentTier);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_free_payment_tier() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.application_config.payment_tiers[0].price = 0;
+
+    let result = try_create_minter_wns(&mut ctx, args, |_| {}).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidPaymentTier);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_other_group() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+
+    let result = try_create_minter_wns(&mut ctx, args, |accounts| {
+        accounts.group = pda::wns_group(&Pubkey::new_unique()).0;
+    })
+    .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidWNSGroup);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_other_manager() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+
+    let result = try_create_minter_wns(&mut ctx, args, |accounts| {
+        accounts.manager = Pubkey::new_unique();
+    })
+    .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidWNSManager);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_short_name() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("ab");
+
+    let result = try_create_minter_wns(&mut ctx, args, |_| {}).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidMinterName);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_short_description() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.description = "Members".to_owned();
+
+    let result = try_create_minter_wns(&mut ctx, args, |_| {}).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidMinterDescription);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_invalid_image_url() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.image_url = "not a url".to_owned();
+
+    let result = try_create_minter_wns(&mut ctx, args, |_| {}).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidMinterImageURL);
+}
+
+#[tokio::test]
+async fn create_minter_wns_writes_website_and_royalty_defaults() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.website = Some("https://tokengator.app".to_owned());
+    args.royalty_basis_points = Some(500);
+    let minter = ctx.create_minter_wns(args).await;
+
+    assert_eq!(
+        ctx.metadata_field(&minter.mint, "website").await.as_deref(),
+        Some("https://tokengator.app")
+    );
+    assert_eq!(
+        ctx.metadata_field(&minter.mint, "default_royalty_basis_points")
+            .await
+            .as_deref(),
+        Some("500")
+    );
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_invalid_website() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.website = Some("not a url".to_owned());
+
+    let result = try_create_minter_wns(&mut ctx, args, |_| {}).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidWebsite);
+}
+
+#[tokio::test]
+async fn create_minter_wns_rejects_royalty_above_max_basis_points() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.royalty_basis_points = Some(10_001);
+
+    let result = try_create_minter_wns(&mut ctx, args, |_| {}).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidRoyaltyBasisPoints);
+}
+
+#[tokio::test]
+async fn mint_minter_wns() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let member = ctx.mint_member_wns(&minter).await;
+
+    assert_eq!(ctx.token_account(&member.token_account).await.amount, 1);
+    // Nothing can mint a second badge from the same mint
+    assert_eq!(ctx.mint(&member.mint).await.mint_authority, COption::None);
+
+    let group: TokenGroup = ctx.account(&minter.group).await;
+    assert_eq!(group.size, 1);
+    let state: TokenGroupMember = ctx.account(&member.member).await;
+    assert_eq!(state.group, minter.group);
+    assert_eq!(state.mint, member.mint);
+
+    // The transfer hook can resolve its extra accounts right away
+    let extra_metas = ctx
+        .get_account(&pda::wns_extra_meta_list(&member.mint).0)
+        .await
+        .unwrap();
+    assert_eq!(extra_metas.owner, WNS_PROGRAM_ID);
+
+    let issued_at: i64 = ctx
+        .metadata_field(&member.mint, "issued_at")
+        .await
+        .unwrap()
+        .parse()
+        .unwrap();
+    let expires_at: i64 = ctx
+        .metadata_field(&member.mint, "expires_at")
+        .await
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(expires_at - issued_at, 60 * 60 * 24 * i64::from(TIER_DAYS));
+    assert_eq!(
+        ctx.metadata_field(&member.mint, "holder").await,
+        Some(member.holder.pubkey().to_string())
+    );
+    assert_eq!(
+        ctx.metadata_field(&member.mint, "tier").await.as_deref(),
+        Some("Monthly")
+    );
+
+    // The authority was paid for the tier minus the protocol fee
+    let authority_token_account = get_associated_token_address_with_program_id(
+        &minter.authority.pubkey(),
+        &ctx.payment_mint,
+        &spl_token_2022::ID,
+    );
+    let fee = TIER_PRICE * u64::from(MINT_FEE_BASIS_POINTS) / 10_000;
+    assert_eq!(
+        ctx.token_account(&authority_token_account).await.amount,
+        TIER_PRICE - fee
+    );
+}
+
+#[tokio::test]
+async fn mint_minter_wns_sells_legacy_payment_config() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    // Minters created before payment tiers only carry the application payment config
+    let mut state: Minter = ctx.account(&minter.minter).await;
+    state.payment_tiers = vec![];
+    state.minter_config.application_config.payment_config = PaymentConfig {
+        amount: 100,
+        price: TIER_PRICE,
+        mint: ctx.payment_mint,
+        days: 7,
+        expires_at: 0,
+    };
+    let space = ctx.get_account(&minter.minter).await.unwrap().data.len();
+    ctx.set_program_account(&minter.minter, &state, space);
+
+    let member = ctx.mint_member_wns(&minter).await;
+
+    let issued_at: i64 = ctx
+        .metadata_field(&member.mint, "issued_at")
+        .await
+        .unwrap()
+        .parse()
+        .unwrap();
+    let expires_at: i64 = ctx
+        .metadata_field(&member.mint, "expires_at")
+        .await
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(expires_at - issued_at, 60 * 60 * 24 * 7);
+    assert_eq!(ctx.metadata_field(&member.mint, "tier").await, None);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_mints_free_legacy_payment_config() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    // Free minters created before payment tiers fall back to a price 0 tier
+    let mut state: Minter = ctx.account(&minter.minter).await;
+    state.payment_tiers = vec![];
+    state.minter_config.application_config.payment_config = PaymentConfig {
+        amount: 0,
+        price: 0,
+        mint: ctx.payment_mint,
+        days: 0,
+        expires_at: 0,
+    };
+    let space = ctx.get_account(&minter.minter).await.unwrap().data.len();
+    ctx.set_program_account(&minter.minter, &state, space);
+
+    let holder = ctx.funded_keypair().await;
+    ctx.pay_tier(&minter.authority.pubkey(), &holder, 0).await;
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &holder.pubkey(), &mint.pubkey());
+    let token_account = accounts.receiver_token_account;
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+    ctx.process(&instructions, &[&minter.authority, &mint])
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.token_account(&token_account).await.amount, 1);
+    assert_eq!(ctx.metadata_field(&mint.pubkey(), "expires_at").await, None);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_mints_into_migrated_legacy_group() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    ctx.mint_member_wns(&minter).await;
+
+    // The minter PDA is the group authority and never signs the migration
+    ctx.downgrade_wns_group(&minter.group).await;
+    let instruction = ctx.migrate_wns_group_instruction(&minter.group, &minter.mint);
+    ctx.process(&[instruction], &[]).await.unwrap();
+
+    let member = ctx.mint_member_wns(&minter).await;
+
+    let group: TokenGroup = ctx.account(&minter.group).await;
+    assert_eq!(group.size, 2);
+    assert_eq!(group.member_count, 2);
+    let state: TokenGroupMember = ctx.account(&member.member).await;
+    assert_eq!(state.member_number, 2);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_mints_from_legacy_minter() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    // Rewrite the minter and its group byte for byte as they were stored before payment tiers
+    let mut state: Minter = ctx.account(&minter.minter).await;
+    state.minter_config.application_config.payment_config = PaymentConfig {
+        amount: 100,
+        price: TIER_PRICE,
+        mint: ctx.payment_mint,
+        days: 7,
+        expires_at: 0,
+    };
+    ctx.set_account_data(
+        &minter.minter,
+        legacy_minter_data(&state),
+        TOKENGATOR_MINTER_PROGRAM_ID,
+    );
+    ctx.downgrade_wns_group(&minter.group).await;
+    let instruction = ctx.migrate_wns_group_instruction(&minter.group, &minter.mint);
+    ctx.process(&[instruction], &[]).await.unwrap();
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert!(!state.paused);
+    assert!(state.payment_tiers.is_empty());
+    assert!(!state.is_transferable());
+
+    let member = ctx.mint_member_wns(&minter).await;
+
+    assert_eq!(ctx.token_account(&member.token_account).await.amount, 1);
+    let (_, result) = ctx
+        .try_transfer_member(&member, &Pubkey::new_unique())
+        .await;
+    assert_token_error(result, TokenError::NonTransferable);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_rejects_other_receiver() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let applicant = ctx.funded_keypair().await;
+    ctx.pay_tier(&minter.authority.pubkey(), &applicant, TIER_PRICE)
+        .await;
+
+    let mint = Keypair::new();
+    let mut accounts = ctx.mint_minter_wns_accounts(&minter, &Pubkey::new_unique(), &mint.pubkey());
+    accounts.receipt = pda::receipt(
+        &applicant.pubkey(),
+        &minter.authority.pubkey(),
+        &ctx.payment_mint,
+    )
+    .0;
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidReceiver);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_rejects_underpaid_receipt() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let applicant = ctx.funded_keypair().await;
+    ctx.pay_tier(&minter.authority.pubkey(), &applicant, TIER_PRICE / 2)
+        .await;
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidReceipt);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_keeps_receipts_paid_under_previous_mint_fee() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let applicant = ctx.funded_keypair().await;
+    ctx.pay_tier(&minter.authority.pubkey(), &applicant, TIER_PRICE)
+        .await;
+
+    let protocol_config = pda::protocol_config().0;
+    let mut state: ProtocolConfig = ctx.account(&protocol_config).await;
+    state.mint_fee_basis_points = MINT_FEE_BASIS_POINTS * 2;
+    let space = ctx.get_account(&protocol_config).await.unwrap().data.len();
+    ctx.set_program_account(&protocol_config, &state, space);
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let token_account = accounts.receiver_token_account;
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+    ctx.process(&instructions, &[&minter.authority, &mint])
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.token_account(&token_account).await.amount, 1);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_rejects_other_receiver_token_account() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let applicant = ctx.funded_keypair().await;
+    ctx.pay_tier(&minter.authority.pubkey(), &applicant, TIER_PRICE)
+        .await;
+
+    let mint = Keypair::new();
+    let mut accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    accounts.receiver_token_account = get_associated_token_address_with_program_id(
+        &minter.authority.pubkey(),
+        &mint.pubkey(),
+        &spl_token_2022::ID,
+    );
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidAuthorityTokenAccount);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_rejects_other_token_program() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let applicant = ctx.funded_keypair().await;
+    ctx.pay_tier(&minter.authority.pubkey(), &applicant, TIER_PRICE)
+        .await;
+
+    let mint = Keypair::new();
+    let mut accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    accounts.token_program = anchor_spl::token::ID;
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidTokenProgram);
+}
+
+#[tokio::test]
+async fn update_member_metadata_wns() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let member = ctx.mint_member_wns(&minter).await;
+
+    let instruction = update_member_metadata_instruction(
+        &ctx,
+        &minter,
+        &member.member,
+        &member.mint,
+        "tier",
+        "Yearly",
+    );
+    ctx.process(&[instruction], &[]).await.unwrap();
+
+    assert_eq!(
+        ctx.metadata_field(&member.mint, "tier").await.as_deref(),
+        Some("Yearly")
+    );
+}
+
+#[tokio::test]
+async fn update_member_metadata_wns_rejects_member_of_other_mint() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let member = ctx.mint_member_wns(&minter).await;
+    let other = ctx.mint_member_wns(&minter).await;
+
+    let instruction = update_member_metadata_instruction(
+        &ctx,
+        &minter,
+        &member.member,
+        &other.mint,
+        "tier",
+        "Yearly",
+    );
+    let result = ctx.process(&[instruction], &[]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidWNSMember);
+}
+
+#[tokio::test]
+async fn sync_member_holder_wns() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.transferable = true;
+    let minter = ctx.create_minter_wns(args).await;
+    let member = ctx.mint_member_wns(&minter).await;
+
+    let buyer = Pubkey::new_unique();
+    let destination = ctx.transfer_member(&member, &buyer).await;
+
+    let instruction = sync_member_holder_instruction(&ctx, &minter, &member, &destination);
+    ctx.process(&[instruction], &[]).await.unwrap();
+
+    assert_eq!(
+        ctx.metadata_field(&member.mint, "holder").await,
+        Some(buyer.to_string())
+    );
+}
+
+#[tokio::test]
+async fn sync_member_holder_wns_rejects_soulbound_minter() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let member = ctx.mint_member_wns(&minter).await;
+
+    let instruction = sync_member_holder_instruction(&ctx, &minter, &member, &member.token_account);
+    let result = ctx.process(&[instruction], &[]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::MinterNotTransferable);
+}
+
+#[tokio::test]
+async fn mint_minter_wns_soulbound_member_is_not_transferable() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let member = ctx.mint_member_wns(&minter).await;
+
+    let (_, result) = ctx
+        .try_transfer_member(&member, &Pubkey::new_unique())
+        .await;
+
+    assert_token_error(result, TokenError::NonTransferable);
+    assert_eq!(ctx.token_account(&member.token_account).await.amount, 1);
+}
+
+#[tokio::test]
+async fn transfer_group_authority_wns() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let new_authority = Keypair::new();
+    let group_authority_transfer = pda::wns_group_authority_transfer(&minter.group).0;
+
+    let transfer = transfer_group_authority_wns_instruction(&ctx, &minter, new_authority.pubkey());
+    ctx.process(&[transfer], &[&minter.authority])
+        .await
+        .unwrap();
+
+    // The group only changes hands once the new authority accepts it
+    let group: TokenGroup = ctx.account(&minter.group).await;
+    assert_eq!(group.update_authority, minter.minter);
+
+    let accept = wns::accept_group_authority(wen_new_standard::accounts::AcceptGroupAuthority {
+        payer: ctx.payer(),
+        new_authority: new_authority.pubkey(),
+        group: minter.group,
+        group_authority_transfer,
+        mint: minter.mint,
+        manager: pda::wns_manager().0,
+        token_program: spl_token_2022::ID,
+    });
+    ctx.process(&[accept], &[&new_authority]).await.unwrap();
+
+    let group: TokenGroup = ctx.account(&minter.group).await;
+    assert_eq!(group.update_authority, new_authority.pubkey());
+    assert!(!ctx.account_exists(&group_authority_transfer).await);
+}
+
+#[tokio::test]
+async fn cancel_group_authority_transfer_wns() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let group_authority_transfer = pda::wns_group_authority_transfer(&minter.group).0;
+
+    let transfer = transfer_group_authority_wns_instruction(&ctx, &minter, Pubkey::new_unique());
+    ctx.process(&[transfer], &[&minter.authority])
+        .await
+        .unwrap();
+
+    // The manager holds the metadata authority while the transfer is pending
+    let metadata = ctx.token_metadata(&minter.mint).await;
+    assert_eq!(
+        Option::<Pubkey>::from(metadata.update_authority),
+        Some(pda::wns_manager().0)
+    );
+
+    let cancel = cancel_group_authority_transfer_wns_instruction(&ctx, &minter);
+    ctx.process(&[cancel], &[&minter.authority]).await.unwrap();
+
+    assert!(!ctx.account_exists(&group_authority_transfer).await);
+    let metadata = ctx.token_metadata(&minter.mint).await;
+    assert_eq!(
+        Option::<Pubkey>::from(metadata.update_authority),
+        Some(minter.minter)
+    );
+    let group: TokenGroup = ctx.account(&minter.group).await;
+    assert_eq!(group.update_authority, minter.minter);
+}
+
+#[tokio::test]
+async fn cancel_group_authority_transfer_wns_rejects_missing_transfer() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let cancel = cancel_group_authority_transfer_wns_instruction(&ctx, &minter);
+    let result = ctx.process(&[cancel], &[&minter.authority]).await;
+
+    assert_anchor_error(result, anchor_lang::error::ErrorCode::AccountNotInitialized);
+}
+
+#[tokio::test]
+async fn remove_minter_wns() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let instruction = remove_minter_wns_instruction(&ctx, &minter);
+    ctx.process(&[instruction], &[&minter.authority])
+        .await
+        .unwrap();
+
+    assert!(!ctx.account_exists(&minter.minter).await);
+    assert!(!ctx.account_exists(&minter.group).await);
+}
+
+#[tokio::test]
+async fn remove_minter_wns_keeps_groups_with_members() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    ctx.mint_member_wns(&minter).await;
+
+    let instruction = remove_minter_wns_instruction(&ctx, &minter);
+    let result = ctx.process(&[instruction], &[&minter.authority]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::CannotRemoveNonEmptyGroup);
+}
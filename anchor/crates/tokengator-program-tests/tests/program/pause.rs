@@ -0,0 +1,105 @@
+use solana_sdk::signature::{Keypair, Signer};
+use tokengator_client::{
+    pda,
+    tokengator_minter::{
+        errors::TokenGatorMinterError,
+        state::{Minter, ProtocolConfig},
+    },
+};
+use tokengator_program_tests::*;
+
+#[tokio::test]
+async fn set_protocol_paused() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let admin = ctx.admin.insecure_clone();
+
+    let pause = ctx.set_protocol_paused_instruction(&admin.pubkey(), true);
+    ctx.process(&[pause], &[&admin]).await.unwrap();
+
+    let protocol_config: ProtocolConfig = ctx.account(&pda::protocol_config().0).await;
+    assert!(protocol_config.paused);
+
+    let resume = ctx.set_protocol_paused_instruction(&admin.pubkey(), false);
+    ctx.process(&[resume], &[&admin]).await.unwrap();
+
+    let protocol_config: ProtocolConfig = ctx.account(&pda::protocol_config().0).await;
+    assert!(!protocol_config.paused);
+}
+
+#[tokio::test]
+async fn set_protocol_paused_requires_admin() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let impostor = Keypair::new();
+
+    let pause = ctx.set_protocol_paused_instruction(&impostor.pubkey(), true);
+    let result = ctx.process(&[pause], &[&impostor]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::UnAuthorized);
+}
+
+#[tokio::test]
+async fn paused_protocol_halts_minting() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let applicant = ctx.funded_keypair().await;
+    ctx.pay_tier(&minter.authority.pubkey(), &applicant, TIER_PRICE)
+        .await;
+
+    let admin = ctx.admin.insecure_clone();
+    let pause = ctx.set_protocol_paused_instruction(&admin.pubkey(), true);
+    ctx.process(&[pause], &[&admin]).await.unwrap();
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::ProgramPaused);
+}
+
+#[tokio::test]
+async fn set_minter_paused() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let pause = ctx.set_minter_paused_instruction(&minter.minter, &minter.authority.pubkey(), true);
+    ctx.process(&[pause], &[&minter.authority]).await.unwrap();
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert!(state.paused);
+
+    let resume =
+        ctx.set_minter_paused_instruction(&minter.minter, &minter.authority.pubkey(), false);
+    ctx.process(&[resume], &[&minter.authority]).await.unwrap();
+
+    let state: Minter = ctx.account(&minter.minter).await;
+    assert!(!state.paused);
+}
+
+#[tokio::test]
+async fn paused_minter_halts_minting() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+
+    let applicant = ctx.funded_keypair().await;
+    ctx.pay_tier(&minter.authority.pubkey(), &applicant, TIER_PRICE)
+        .await;
+
+    let pause = ctx.set_minter_paused_instruction(&minter.minter, &minter.authority.pubkey(), true);
+    ctx.process(&[pause], &[&minter.authority]).await.unwrap();
+
+    let mint = Keypair::new();
+    let accounts = ctx.mint_minter_wns_accounts(&minter, &applicant.pubkey(), &mint.pubkey());
+    let instructions = ctx.mint_minter_wns_instructions(accounts, None);
+    let result = ctx
+        .process(&instructions, &[&minter.authority, &mint])
+        .await;
+
+    assert_minter_error(result, TokenGatorMinterError::MinterPaused);
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use solana_sdk::signature::Signer;
+use tokengator_client::{
+    instructions::minter,
+    pda,
+    tokengator_minter::{
+        errors::TokenGatorMinterError,
+        state::{Receipt, ReceiptType},
+        PrepareForPaymentArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+fn user_payment(payment_amount: u64) -> PrepareForPaymentArgs {
+    PrepareForPaymentArgs {
+        payment_amount,
+        payment_type: ReceiptType::User,
+        payment_tier: Some(0),
+        coupon_code: None,
+    }
+}
+
+#[tokio::test]
+async fn prepare_for_payment_splits_the_mint_fee() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let sender = ctx.funded_keypair().await;
+    let receiver = Pubkey::new_unique();
+    let sender_token_account = ctx.fund_payment_tokens(&sender.pubkey(), TIER_PRICE).await;
+
+    let receipt = ctx
+        .prepare_for_payment(&sender, &receiver, user_payment(TIER_PRICE))
+        .await;
+
+    let fee = TIER_PRICE * u64::from(MINT_FEE_BASIS_POINTS) / 10_000;
+    let receipt: Receipt = ctx.account(&receipt).await;
+    assert!(matches!(receipt.payment_type, ReceiptType::User));
+    assert_eq!(receipt.sender, sender.pubkey());
+    assert_eq!(receipt.receiver, receiver);
+    assert_eq!(receipt.payment_amount, TIER_PRICE);
+    assert_eq!(receipt.protocol_fee, fee);
+
+    let receiver_token_account = get_associated_token_address_with_program_id(
+        &receiver,
+        &ctx.payment_mint,
+        &spl_token_2022::ID,
+    );
+    let treasury_token_account = get_associated_token_address_with_program_id(
+        &ctx.treasury.pubkey(),
+        &ctx.payment_mint,
+        &spl_token_2022::ID,
+    );
+    assert_eq!(ctx.token_account(&sender_token_account).await.amount, 0);
+    assert_eq!(
+        ctx.token_account(&receiver_token_account).await.amount,
+        TIER_PRICE - fee
+    );
+    assert_eq!(ctx.token_account(&treasury_token_account).await.amount, fee);
+}
+
+#[tokio::test]
+async fn prepare_for_payment_sends_community_fee_to_treasury() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let authority = ctx.community_authority().await;
+
+    let receipt = pda::receipt(&authority.pubkey(), &authority.pubkey(), &ctx.payment_mint).0;
+    let receipt: Receipt = ctx.account(&receipt).await;
+    assert!(matches!(receipt.payment_type, ReceiptType::Community));
+    assert_eq!(receipt.payment_amount, COMMUNITY_FEE);
+    assert_eq!(receipt.protocol_fee, COMMUNITY_FEE);
+
+    let treasury_token_account = get_associated_token_address_with_program_id(
+        &ctx.treasury.pubkey(),
+        &ctx.payment_mint,
+        &spl_token_2022::ID,
+    );
+    assert_eq!(
+        ctx.token_account(&treasury_token_account).await.amount,
+        COMMUNITY_FEE
+    );
+    assert_eq!(
+        ctx.token_account(&receipt.sender_token_account)
+            .await
+            .amount,
+        0
+    );
+}
+
+#[tokio::test]
+async fn prepare_for_payment_rejects_other_treasury() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let sender = ctx.funded_keypair().await;
+    ctx.fund_payment_tokens(&sender.pubkey(), TIER_PRICE).await;
+
+    let mut accounts =
+        ctx.prepare_for_payment_accounts(&sender.pubkey(), &Pubkey::new_unique(), None);
+    let treasury = ctx.funded_keypair().await.pubkey();
+    accounts.treasury = treasury;
+    accounts.treasury_token_account = get_associated_token_address_with_program_id(
+        &treasury,
+        &ctx.payment_mint,
+        &spl_token_2022::ID,
+    );
+
+    let instruction = minter::prepare_for_payment(accounts, user_payment(TIER_PRICE));
+    let result = ctx.process(&[instruction], &[&sender]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidTreasury);
+}
+
+#[tokio::test]
+async fn prepare_for_payment_rejects_mints_outside_the_allow_list() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let sender = ctx.funded_keypair().await;
+    let receiver = Pubkey::new_unique();
+
+    let mint_authority = ctx.payment_mint_authority.pubkey();
+    let other_mint = ctx
+        .create_mint(&mint_authority, PAYMENT_MINT_DECIMALS)
+        .await;
+    let sender_token_account = ctx
+        .create_token_account(&sender.pubkey(), &other_mint)
+        .await;
+
+    let mut accounts = ctx.prepare_for_payment_accounts(&sender.pubkey(), &receiver, None);
+    accounts.mint = other_mint;
+    accounts.receipt = pda::receipt(&sender.pubkey(), &receiver, &other_mint).0;
+    accounts.sender_token_account = sender_token_account;
+    accounts.receiver_token_account =
+        get_associated_token_address_with_program_id(&receiver, &other_mint, &spl_token_2022::ID);
+    accounts.treasury_token_account = get_associated_token_address_with_program_id(
+        &ctx.treasury.pubkey(),
+        &other_mint,
+        &spl_token_2022::ID,
+    );
+
+    let instruction = minter::prepare_for_payment(accounts, user_payment(0));
+    let result = ctx.process(&[instruction], &[&sender]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::PaymentMintNotAllowed);
+}
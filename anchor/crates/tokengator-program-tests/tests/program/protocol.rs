@@ -0,0 +1,205 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable, system_program};
+use solana_sdk::{
+    account::Account,
+    bpf_loader_upgradeable::UpgradeableLoaderState,
+    signature::{Keypair, Signer},
+};
+use tokengator_client::{
+    instructions::minter,
+    pda,
+    tokengator_minter::{
+        accounts, errors::TokenGatorMinterError, state::ProtocolConfig,
+        InitializeProtocolConfigArgs, UpdateProtocolConfigArgs,
+    },
+    TOKENGATOR_MINTER_PROGRAM_ID,
+};
+use tokengator_program_tests::*;
+
+fn program_data_address() -> Pubkey {
+    Pubkey::find_program_address(
+        &[TOKENGATOR_MINTER_PROGRAM_ID.as_ref()],
+        &bpf_loader_upgradeable::ID,
+    )
+    .0
+}
+
+fn initialize_protocol_config_instruction(
+    ctx: &TestContext,
+    admin: &Pubkey,
+) -> solana_sdk::instruction::Instruction {
+    minter::initialize_protocol_config(
+        accounts::InitializeProtocolConfig {
+            protocol_config: pda::protocol_config().0,
+            program: TOKENGATOR_MINTER_PROGRAM_ID,
+            program_data: program_data_address(),
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        InitializeProtocolConfigArgs {
+            treasury: ctx.treasury.pubkey(),
+            community_fee: COMMUNITY_FEE,
+            mint_fee_basis_points: MINT_FEE_BASIS_POINTS,
+            allowed_payment_mints: vec![ctx.payment_mint],
+            identity_verifier: ctx.identity_verifier.pubkey(),
+        },
+    )
+}
+
+fn update_protocol_config_args() -> UpdateProtocolConfigArgs {
+    UpdateProtocolConfigArgs {
+        admin: None,
+        treasury: None,
+        community_fee: None,
+        mint_fee_basis_points: None,
+        allowed_payment_mints: None,
+        identity_verifier: None,
+    }
+}
+
+fn update_protocol_config_instruction(
+    admin: &Pubkey,
+    args: UpdateProtocolConfigArgs,
+) -> solana_sdk::instruction::Instruction {
+    minter::update_protocol_config(
+        accounts::UpdateProtocolConfig {
+            protocol_config: pda::protocol_config().0,
+            admin: *admin,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+#[tokio::test]
+async fn initialize_protocol_config() {
+    let mut ctx = TestContext::new().await;
+
+    // Natively loaded programs have no ProgramData account to check the upgrade authority against
+    if !ctx.is_upgradeable() {
+        return;
+    }
+
+    let admin = ctx.admin.insecure_clone();
+    let instruction = initialize_protocol_config_instruction(&ctx, &admin.pubkey());
+    ctx.process(&[instruction], &[&admin]).await.unwrap();
+
+    let protocol_config: ProtocolConfig = ctx.account(&pda::protocol_config().0).await;
+    assert_eq!(protocol_config.admin, admin.pubkey());
+    assert_eq!(protocol_config.treasury, ctx.treasury.pubkey());
+    assert_eq!(protocol_config.community_fee, COMMUNITY_FEE);
+    assert_eq!(
+        protocol_config.allowed_payment_mints,
+        vec![ctx.payment_mint]
+    );
+    assert!(!protocol_config.paused);
+}
+
+#[tokio::test]
+async fn initialize_protocol_config_requires_upgrade_authority() {
+    let mut ctx = TestContext::new().await;
+
+    if !ctx.is_upgradeable() {
+        let programdata = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(ctx.admin.pubkey()),
+        })
+        .unwrap();
+
+        ctx.context.set_account(
+            &program_data_address(),
+            &Account {
+                lamports: Rent::default().minimum_balance(programdata.len()),
+                data: programdata,
+                owner: bpf_loader_upgradeable::ID,
+                executable: false,
+                rent_epoch: 0,
+            }
+            .into(),
+        );
+    }
+
+    let impostor = ctx.funded_keypair().await;
+    let instruction = initialize_protocol_config_instruction(&ctx, &impostor.pubkey());
+    let result = ctx.process(&[instruction], &[&impostor]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::UnAuthorized);
+}
+
+#[tokio::test]
+async fn update_protocol_config() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let admin = ctx.admin.insecure_clone();
+    let other_mint = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+
+    let instruction = update_protocol_config_instruction(
+        &admin.pubkey(),
+        UpdateProtocolConfigArgs {
+            treasury: Some(treasury),
+            community_fee: Some(2 * COMMUNITY_FEE),
+            allowed_payment_mints: Some(vec![ctx.payment_mint, other_mint]),
+            ..update_protocol_config_args()
+        },
+    );
+    ctx.process(&[instruction], &[&admin]).await.unwrap();
+
+    let protocol_config: ProtocolConfig = ctx.account(&pda::protocol_config().0).await;
+    assert_eq!(protocol_config.treasury, treasury);
+    assert_eq!(protocol_config.community_fee, 2 * COMMUNITY_FEE);
+    assert_eq!(
+        protocol_config.allowed_payment_mints,
+        vec![ctx.payment_mint, other_mint]
+    );
+    assert_eq!(protocol_config.admin, admin.pubkey());
+}
+
+#[tokio::test]
+async fn update_protocol_config_requires_admin() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let impostor = Keypair::new();
+
+    let instruction = update_protocol_config_instruction(
+        &impostor.pubkey(),
+        UpdateProtocolConfigArgs {
+            community_fee: Some(0),
+            ..update_protocol_config_args()
+        },
+    );
+    let result = ctx.process(&[instruction], &[&impostor]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::UnAuthorized);
+}
+
+#[tokio::test]
+async fn update_protocol_config_rejects_mint_fee_above_max() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let admin = ctx.admin.insecure_clone();
+
+    let instruction = update_protocol_config_instruction(
+        &admin.pubkey(),
+        UpdateProtocolConfigArgs {
+            mint_fee_basis_points: Some(10_001),
+            ..update_protocol_config_args()
+        },
+    );
+    let result = ctx.process(&[instruction], &[&admin]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidProtocolFee);
+}
+
+#[tokio::test]
+async fn update_protocol_config_rejects_too_many_payment_mints() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let admin = ctx.admin.insecure_clone();
+
+    let instruction = update_protocol_config_instruction(
+        &admin.pubkey(),
+        UpdateProtocolConfigArgs {
+            allowed_payment_mints: Some((0..21).map(|_| Pubkey::new_unique()).collect()),
+            ..update_protocol_config_args()
+        },
+    );
+    let result = ctx.process(&[instruction], &[&admin]).await;
+
+    assert_minter_error(result, TokenGatorMinterError::MaxSizeReached);
+}
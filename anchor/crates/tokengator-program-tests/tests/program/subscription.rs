@@ -0,0 +1,260 @@
+use anchor_lang::{prelude::Pubkey, system_program};
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id, ID as ASSOCIATED_TOKEN_PROGRAM_ID,
+};
+use solana_program_test::BanksClientError;
+use solana_sdk::{instruction::Instruction, signature::Signer};
+use tokengator_client::{
+    instructions::minter,
+    pda,
+    tokengator_minter::{
+        accounts, errors::TokenGatorMinterError, state::Subscription, CreateSubscriptionArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+const DAY: i64 = 60 * 60 * 24;
+
+fn payment_token_account(ctx: &TestContext, owner: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, &ctx.payment_mint, &spl_token_2022::ID)
+}
+
+async fn try_create_subscription(
+    ctx: &mut TestContext,
+    minter: &WnsMinter,
+    member: &Member,
+    periods: u16,
+) -> Result<(), BanksClientError> {
+    let subscriber = member.holder.pubkey();
+    let instruction = minter::create_subscription(
+        accounts::CreateSubscription {
+            subscription: pda::subscription(&member.mint).0,
+            minter: minter.minter,
+            group: minter.group,
+            member: member.member,
+            mint: member.mint,
+            subscriber_token_account: member.token_account,
+            payment_token_account: payment_token_account(ctx, &subscriber),
+            receiver_token_account: payment_token_account(ctx, &minter.authority.pubkey()),
+            payment_mint: ctx.payment_mint,
+            subscriber,
+            fee_payer: ctx.payer(),
+            protocol_config: pda::protocol_config().0,
+            token_program: spl_token_2022::ID,
+            system_program: system_program::ID,
+        },
+        CreateSubscriptionArgs {
+            payment_tier: 0,
+            periods,
+        },
+    );
+    let holder = member.holder.insecure_clone();
+
+    ctx.process(&[instruction], &[&holder]).await
+}
+
+async fn try_collect_subscription(
+    ctx: &mut TestContext,
+    minter: &WnsMinter,
+    member: &Member,
+) -> Result<(), BanksClientError> {
+    let instruction = collect_subscription_instruction(ctx, minter, member);
+
+    ctx.process(&[instruction], &[]).await
+}
+
+fn collect_subscription_instruction(
+    ctx: &TestContext,
+    minter: &WnsMinter,
+    member: &Member,
+) -> Instruction {
+    minter::collect_subscription(accounts::CollectSubscription {
+        subscription: pda::subscription(&member.mint).0,
+        minter: minter.minter,
+        mint: member.mint,
+        member_token_account: member.token_account,
+        payment_token_account: payment_token_account(ctx, &member.holder.pubkey()),
+        receiver_token_account: payment_token_account(ctx, &minter.authority.pubkey()),
+        payment_mint: ctx.payment_mint,
+        payer: ctx.payer(),
+        fee_payer: ctx.payer(),
+        protocol_config: pda::protocol_config().0,
+        treasury: ctx.treasury.pubkey(),
+        treasury_token_account: payment_token_account(ctx, &ctx.treasury.pubkey()),
+        token_program: spl_token_2022::ID,
+        associated_token_program: ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program: system_program::ID,
+    })
+}
+
+async fn expires_at(ctx: &mut TestContext, member: &Member) -> i64 {
+    ctx.metadata_field(&member.mint, "expires_at")
+        .await
+        .unwrap()
+        .parse()
+        .unwrap()
+}
+
+async fn subscribed_member() -> (TestContext, WnsMinter, Member) {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let mut args = ctx.create_minter_wns_args("Business Visa");
+    args.transferable = true;
+    let minter = ctx.create_minter_wns(args).await;
+    let member = ctx.mint_member_wns(&minter).await;
+
+    try_create_subscription(&mut ctx, &minter, &member, 3)
+        .await
+        .unwrap();
+
+    (ctx, minter, member)
+}
+
+#[tokio::test]
+async fn create_subscription() {
+    let (mut ctx, minter, member) = subscribed_member().await;
+
+    let subscription: Subscription = ctx.account(&pda::subscription(&member.mint).0).await;
+    assert_eq!(subscription.minter, minter.minter);
+    assert_eq!(subscription.mint, member.mint);
+    assert_eq!(subscription.subscriber, member.holder.pubkey());
+    assert_eq!(subscription.price, TIER_PRICE);
+    assert_eq!(subscription.days, TIER_DAYS);
+    assert_eq!(subscription.expires_at, expires_at(&mut ctx, &member).await);
+
+    // The minter may pull every approved period from the subscriber
+    let payment_token_account = payment_token_account(&ctx, &member.holder.pubkey());
+    let payment_token_account = ctx.token_account(&payment_token_account).await;
+    assert_eq!(payment_token_account.delegate, Some(minter.minter).into());
+    assert_eq!(payment_token_account.delegated_amount, 3 * TIER_PRICE);
+}
+
+#[tokio::test]
+async fn create_subscription_rejects_zero_periods() {
+    let mut ctx = TestContext::with_protocol_config().await;
+    let args = ctx.create_minter_wns_args("Business Visa");
+    let minter = ctx.create_minter_wns(args).await;
+    let member = ctx.mint_member_wns(&minter).await;
+
+    let result = try_create_subscription(&mut ctx, &minter, &member, 0).await;
+
+    assert_minter_error(result, TokenGatorMinterError::InvalidSubscription);
+}
+
+#[tokio::test]
+async fn collect_subscription() {
+    let (mut ctx, minter, member) = subscribed_member().await;
+    ctx.fund_payment_tokens(&member.holder.pubkey(), TIER_PRICE)
+        .await;
+    let receiver_token_account = payment_token_account(&ctx, &minter.authority.pubkey());
+    let received = ctx.token_account(&receiver_token_account).await.amount;
+    let treasury_token_account = payment_token_account(&ctx, &ctx.treasury.pubkey());
+    let collected = ctx.token_account(&treasury_token_account).await.amount;
+    let previous_expires_at = expires_at(&mut ctx, &member).await;
+
+    ctx.warp_forward(i64::from(TIER_DAYS) * DAY).await;
+    try_collect_subscription(&mut ctx, &minter, &member)
+        .await
+        .unwrap();
+
+    // Renewals pay the protocol fee like the first payment did
+    let fee = TIER_PRICE * u64::from(MINT_FEE_BASIS_POINTS) / 10_000;
+    assert_eq!(
+        ctx.token_account(&receiver_token_account).await.amount,
+        received + TIER_PRICE - fee
+    );
+    assert_eq!(
+        ctx.token_account(&treasury_token_account).await.amount,
+        collected + fee
+    );
+    assert_eq!(
+        expires_at(&mut ctx, &member).await,
+        previous_expires_at + i64::from(TIER_DAYS) * DAY
+    );
+
+    let subscription: Subscription = ctx.account(&pda::subscription(&member.mint).0).await;
+    assert_eq!(
+        subscription.expires_at,
+        previous_expires_at + i64::from(TIER_DAYS) * DAY
+    );
+}
+
+#[tokio::test]
+async fn collect_subscription_rejects_early_collection() {
+    let (mut ctx, minter, member) = subscribed_member().await;
+    ctx.fund_payment_tokens(&member.holder.pubkey(), TIER_PRICE)
+        .await;
+
+    let result = try_collect_subscription(&mut ctx, &minter, &member).await;
+
+    assert_minter_error(result, TokenGatorMinterError::SubscriptionNotDue);
+}
+
+#[tokio::test]
+async fn collect_subscription_renews_lapsed_subscription_once() {
+    let (mut ctx, minter, member) = subscribed_member().await;
+    ctx.fund_payment_tokens(&member.holder.pubkey(), 3 * TIER_PRICE)
+        .await;
+    let receiver_token_account = payment_token_account(&ctx, &minter.authority.pubkey());
+    let received = ctx.token_account(&receiver_token_account).await.amount;
+
+    ctx.warp_forward(3 * i64::from(TIER_DAYS) * DAY).await;
+
+    // Collecting again right away must not pull the periods that lapsed in between
+    let instructions = [
+        collect_subscription_instruction(&ctx, &minter, &member),
+        collect_subscription_instruction(&ctx, &minter, &member),
+    ];
+    let result = ctx.process(&instructions, &[]).await;
+    assert_minter_error(result, TokenGatorMinterError::SubscriptionNotDue);
+
+    let now = ctx.unix_timestamp().await;
+    try_collect_subscription(&mut ctx, &minter, &member)
+        .await
+        .unwrap();
+
+    let fee = TIER_PRICE * u64::from(MINT_FEE_BASIS_POINTS) / 10_000;
+    assert_eq!(
+        ctx.token_account(&receiver_token_account).await.amount,
+        received + TIER_PRICE - fee
+    );
+
+    let subscription: Subscription = ctx.account(&pda::subscription(&member.mint).0).await;
+    assert!(subscription.expires_at >= now + i64::from(TIER_DAYS) * DAY);
+    assert_eq!(subscription.expires_at, expires_at(&mut ctx, &member).await);
+}
+
+#[tokio::test]
+async fn collect_subscription_cancels_unfunded_subscription() {
+    let (mut ctx, minter, member) = subscribed_member().await;
+    let previous_expires_at = expires_at(&mut ctx, &member).await;
+
+    ctx.warp_forward(i64::from(TIER_DAYS) * DAY).await;
+    try_collect_subscription(&mut ctx, &minter, &member)
+        .await
+        .unwrap();
+
+    assert!(!ctx.account_exists(&pda::subscription(&member.mint).0).await);
+    assert_eq!(expires_at(&mut ctx, &member).await, previous_expires_at);
+}
+
+#[tokio::test]
+async fn collect_subscription_cancels_after_badge_transfer() {
+    let (mut ctx, minter, member) = subscribed_member().await;
+    ctx.fund_payment_tokens(&member.holder.pubkey(), TIER_PRICE)
+        .await;
+    let payment_token_account = payment_token_account(&ctx, &member.holder.pubkey());
+    let balance = ctx.token_account(&payment_token_account).await.amount;
+
+    ctx.transfer_member(&member, &Pubkey::new_unique()).await;
+    ctx.warp_forward(i64::from(TIER_DAYS) * DAY).await;
+    try_collect_subscription(&mut ctx, &minter, &member)
+        .await
+        .unwrap();
+
+    // The former holder is no longer charged for a badge they gave away
+    assert!(!ctx.account_exists(&pda::subscription(&member.mint).0).await);
+    assert_eq!(
+        ctx.token_account(&payment_token_account).await.amount,
+        balance
+    );
+}
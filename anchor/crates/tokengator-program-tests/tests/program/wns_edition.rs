@@ -0,0 +1,146 @@
+use solana_sdk::signature::{Keypair, Signer};
+use tokengator_client::{
+    pda,
+    wen_new_standard::{
+        MasterEdition, MetadataErrors, TokenGroup, TokenGroupMember, UpdateGroupAccountArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+async fn master_edition_group(ctx: &mut TestContext, max_size: u32) -> WnsGroup {
+    let mut args = ctx.create_wns_group_args();
+    args.max_size = max_size;
+    let group = ctx.create_wns_group(args).await;
+
+    let instruction = ctx.create_master_edition_instruction(&group);
+    let authority = group.authority.insecure_clone();
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+
+    group
+}
+
+#[tokio::test]
+async fn create_master_edition() {
+    let mut ctx = TestContext::new().await;
+    let group = master_edition_group(&mut ctx, WNS_GROUP_MAX_SIZE).await;
+
+    let state: MasterEdition = ctx.account(&pda::wns_master_edition(&group.group).0).await;
+    assert_eq!(state.group, group.group);
+    assert_eq!(state.supply, 0);
+    assert_eq!(state.max_supply, WNS_GROUP_MAX_SIZE);
+}
+
+#[tokio::test]
+async fn create_master_edition_rejects_group_with_members() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    ctx.create_wns_member(&group).await;
+
+    let instruction = ctx.create_master_edition_instruction(&group);
+    let authority = group.authority.insecure_clone();
+    let result = ctx.process(&[instruction], &[&authority]).await;
+
+    assert_wns_error(result, MetadataErrors::GroupNotEmpty);
+}
+
+#[tokio::test]
+async fn print_edition() {
+    let mut ctx = TestContext::new().await;
+    let group = master_edition_group(&mut ctx, WNS_GROUP_MAX_SIZE).await;
+
+    ctx.print_wns_edition(&group).await;
+    let print = ctx.print_wns_edition(&group).await;
+
+    assert_eq!(ctx.token_account(&print.token_account).await.amount, 1);
+    let member: TokenGroupMember = ctx.account(&print.member).await;
+    assert_eq!(member.group, group.group);
+    assert_eq!(member.member_number, 2);
+
+    // Prints copy the group metadata and carry their edition number
+    let template = ctx.token_metadata(&group.mint).await;
+    let metadata = ctx.token_metadata(&print.mint).await;
+    assert_eq!(metadata.name, template.name);
+    assert_eq!(metadata.uri, template.uri);
+    assert_eq!(
+        ctx.metadata_field(&print.mint, "edition").await.as_deref(),
+        Some("2")
+    );
+    assert_eq!(
+        ctx.metadata_field(&print.mint, "max_supply").await,
+        Some(WNS_GROUP_MAX_SIZE.to_string())
+    );
+    assert!(
+        ctx.account_exists(&pda::wns_extra_meta_list(&print.mint).0)
+            .await
+    );
+
+    let state: MasterEdition = ctx.account(&pda::wns_master_edition(&group.group).0).await;
+    assert_eq!(state.supply, 2);
+    let state: TokenGroup = ctx.account(&group.group).await;
+    assert_eq!(state.size, 2);
+}
+
+#[tokio::test]
+async fn print_edition_continues_member_numbers_of_removed_members() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let removed = ctx.create_wns_member(&group).await;
+    let authority = group.authority.insecure_clone();
+    let instruction = ctx.remove_wns_member_instruction(&group, &removed.mint);
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+
+    let instruction = ctx.create_master_edition_instruction(&group);
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+    let print = ctx.print_wns_edition(&group).await;
+
+    // The edition restarts at 1 but the member number is never handed out twice
+    assert_eq!(
+        ctx.metadata_field(&print.mint, "edition").await.as_deref(),
+        Some("1")
+    );
+    let member: TokenGroupMember = ctx.account(&print.member).await;
+    assert_eq!(member.member_number, 2);
+}
+
+#[tokio::test]
+async fn print_edition_keeps_max_supply_after_group_resize() {
+    let mut ctx = TestContext::new().await;
+    let group = master_edition_group(&mut ctx, 1).await;
+    ctx.print_wns_edition(&group).await;
+
+    let instruction = ctx.update_wns_group_instruction(
+        &group,
+        UpdateGroupAccountArgs {
+            name: "Collection".to_owned(),
+            symbol: "COL".to_owned(),
+            uri: "https://tokengator.app/collection.json".to_owned(),
+            max_size: WNS_GROUP_MAX_SIZE,
+            additional_metadata: vec![],
+        },
+    );
+    let authority = group.authority.insecure_clone();
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+
+    let receiver = Keypair::new().pubkey();
+    let mint = Keypair::new();
+    let instruction = ctx.print_edition_instruction(&group, &receiver, &mint.pubkey());
+    let result = ctx.process(&[instruction], &[&authority, &mint]).await;
+
+    assert_wns_error(result, MetadataErrors::SizeExceedsMaxSize);
+}
+
+#[tokio::test]
+async fn add_mint_to_group_rejects_master_edition_group() {
+    let mut ctx = TestContext::new().await;
+    let group = master_edition_group(&mut ctx, WNS_GROUP_MAX_SIZE).await;
+    let args = ctx.create_wns_mint_args();
+    let mint = ctx.create_wns_mint(&group.authority, args).await;
+
+    let instruction = ctx.add_wns_member_instruction(&group, &mint.mint);
+    let authority = group.authority.insecure_clone();
+    let result = ctx.process(&[instruction], &[&authority]).await;
+
+    assert_wns_error(result, MetadataErrors::GroupIsMasterEdition);
+}
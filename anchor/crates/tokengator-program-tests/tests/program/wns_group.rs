@@ -0,0 +1,397 @@
+use anchor_lang::{prelude::Pubkey, system_program};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use tokengator_client::{
+    instructions::wns,
+    pda,
+    wen_new_standard::{
+        accounts, AddMetadataArgs, GroupAuthorityTransfer, MetadataErrors, TokenGroup,
+        TokenGroupMember, TransferGroupAuthorityArgs, UpdateGroupAccountArgs,
+    },
+};
+use tokengator_program_tests::*;
+
+fn transfer_group_authority_instruction(
+    ctx: &TestContext,
+    group: &WnsGroup,
+    new_authority: Pubkey,
+) -> Instruction {
+    wns::transfer_group_authority(
+        accounts::TransferGroupAuthority {
+            payer: ctx.payer(),
+            authority: group.authority.pubkey(),
+            group: group.group,
+            group_authority_transfer: pda::wns_group_authority_transfer(&group.group).0,
+            mint: group.mint,
+            manager: pda::wns_manager().0,
+            system_program: system_program::ID,
+            token_program: spl_token_2022::ID,
+        },
+        TransferGroupAuthorityArgs { new_authority },
+    )
+}
+
+fn accept_group_authority_instruction(
+    ctx: &TestContext,
+    group: &WnsGroup,
+    new_authority: &Pubkey,
+) -> Instruction {
+    wns::accept_group_authority(accounts::AcceptGroupAuthority {
+        payer: ctx.payer(),
+        new_authority: *new_authority,
+        group: group.group,
+        group_authority_transfer: pda::wns_group_authority_transfer(&group.group).0,
+        mint: group.mint,
+        manager: pda::wns_manager().0,
+        token_program: spl_token_2022::ID,
+    })
+}
+
+fn cancel_group_authority_transfer_instruction(ctx: &TestContext, group: &WnsGroup) -> Instruction {
+    wns::cancel_group_authority_transfer(accounts::CancelGroupAuthorityTransfer {
+        payer: ctx.payer(),
+        authority: group.authority.pubkey(),
+        group: group.group,
+        group_authority_transfer: pda::wns_group_authority_transfer(&group.group).0,
+        mint: group.mint,
+        manager: pda::wns_manager().0,
+        token_program: spl_token_2022::ID,
+    })
+}
+
+fn close_group_account_instruction(ctx: &TestContext, group: &WnsGroup) -> Instruction {
+    wns::close_group_account(accounts::CloseGroupAccount {
+        payer: ctx.payer(),
+        authority: group.authority.pubkey(),
+        group: group.group,
+        mint: group.mint,
+        mint_token_account: group.token_account,
+        manager: pda::wns_manager().0,
+        token_program: spl_token_2022::ID,
+    })
+}
+
+fn update_group_args(additional_metadata: Vec<AddMetadataArgs>) -> UpdateGroupAccountArgs {
+    UpdateGroupAccountArgs {
+        name: "Renamed".to_owned(),
+        symbol: "REN".to_owned(),
+        uri: "https://tokengator.app/renamed.json".to_owned(),
+        max_size: WNS_GROUP_MAX_SIZE,
+        additional_metadata,
+    }
+}
+
+fn field(field: &str, value: &str) -> AddMetadataArgs {
+    AddMetadataArgs {
+        field: field.to_owned(),
+        value: value.to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn create_group_account() {
+    let mut ctx = TestContext::new().await;
+    let mut args = ctx.create_wns_group_args();
+    args.additional_metadata = vec![field("description", "A collection")];
+    let group = ctx.create_wns_group(args).await;
+
+    let state: TokenGroup = ctx.account(&group.group).await;
+    assert_eq!(state.update_authority, group.authority.pubkey());
+    assert_eq!(state.mint, group.mint);
+    assert_eq!(state.size, 0);
+    assert_eq!(state.max_size, WNS_GROUP_MAX_SIZE);
+    assert_eq!(
+        ctx.metadata_field(&group.mint, "description")
+            .await
+            .as_deref(),
+        Some("A collection")
+    );
+}
+
+#[tokio::test]
+async fn create_group_account_rejects_reserved_field() {
+    let mut ctx = TestContext::new().await;
+    let authority = ctx.funded_keypair().await;
+    let mint = Keypair::new();
+    let mut args = ctx.create_wns_group_args();
+    args.additional_metadata = vec![field("royalty_basis_points", "10000")];
+
+    let accounts = ctx.create_wns_group_accounts(&authority.pubkey(), &mint.pubkey());
+    let result = ctx
+        .process(
+            &[wns::create_group_account(accounts, args)],
+            &[&authority, &mint],
+        )
+        .await;
+
+    assert_wns_error(result, MetadataErrors::InvalidField);
+}
+
+#[tokio::test]
+async fn update_group_account() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+
+    let args = update_group_args(vec![field("website", "https://tokengator.app")]);
+    let instruction = ctx.update_wns_group_instruction(&group, args);
+    let authority = group.authority.insecure_clone();
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+
+    let metadata = ctx.token_metadata(&group.mint).await;
+    assert_eq!(metadata.name, "Renamed");
+    assert_eq!(metadata.uri, "https://tokengator.app/renamed.json");
+    assert_eq!(
+        ctx.metadata_field(&group.mint, "website").await.as_deref(),
+        Some("https://tokengator.app")
+    );
+}
+
+#[tokio::test]
+async fn update_group_account_rejects_creator_field() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+
+    // Pubkey fields hold creator shares, which only the royalty instructions write
+    let creator = Keypair::new().pubkey().to_string();
+    let args = update_group_args(vec![field(&creator, "100")]);
+    let instruction = ctx.update_wns_group_instruction(&group, args);
+    let authority = group.authority.insecure_clone();
+    let result = ctx.process(&[instruction], &[&authority]).await;
+
+    assert_wns_error(result, MetadataErrors::InvalidField);
+}
+
+#[tokio::test]
+async fn add_mint_to_group() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+
+    let first = ctx.create_wns_member(&group).await;
+    let second = ctx.create_wns_member(&group).await;
+
+    let state: TokenGroup = ctx.account(&group.group).await;
+    assert_eq!(state.size, 2);
+    assert_eq!(state.member_count, 2);
+
+    let first: TokenGroupMember = ctx.account(&first.member).await;
+    let second: TokenGroupMember = ctx.account(&second.member).await;
+    assert_eq!(first.group, group.group);
+    assert_eq!(first.member_number, 1);
+    assert_eq!(second.member_number, 2);
+}
+
+#[tokio::test]
+async fn remove_mint_from_group() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let member = ctx.create_wns_member(&group).await;
+
+    let remove = ctx.remove_wns_member_instruction(&group, &member.mint);
+    let authority = group.authority.insecure_clone();
+    ctx.process(&[remove], &[&authority]).await.unwrap();
+
+    let state: TokenGroup = ctx.account(&group.group).await;
+    assert_eq!(state.size, 0);
+    assert!(!ctx.account_exists(&member.member).await);
+    assert_eq!(ctx.token_account(&member.token_account).await.amount, 1);
+}
+
+#[tokio::test]
+async fn remove_mint_from_group_rejects_other_authority() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let member = ctx.create_wns_member(&group).await;
+
+    let other = WnsGroup {
+        authority: ctx.funded_keypair().await,
+        ..group
+    };
+    let remove = ctx.remove_wns_member_instruction(&other, &member.mint);
+    let result = ctx.process(&[remove], &[&other.authority]).await;
+
+    assert_anchor_error(result, anchor_lang::error::ErrorCode::ConstraintRaw);
+    assert!(ctx.account_exists(&member.member).await);
+}
+
+#[tokio::test]
+async fn remove_mint_from_group_never_reuses_member_numbers() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+
+    let first = ctx.create_wns_member(&group).await;
+    ctx.create_wns_member(&group).await;
+
+    let remove = ctx.remove_wns_member_instruction(&group, &first.mint);
+    let authority = group.authority.insecure_clone();
+    ctx.process(&[remove], &[&authority]).await.unwrap();
+    assert!(!ctx.account_exists(&first.member).await);
+
+    let third = ctx.create_wns_member(&group).await;
+
+    let state: TokenGroup = ctx.account(&group.group).await;
+    assert_eq!(state.size, 2);
+    assert_eq!(state.member_count, 3);
+    let third: TokenGroupMember = ctx.account(&third.member).await;
+    assert_eq!(third.member_number, 3);
+}
+
+#[tokio::test]
+async fn migrate_group_account() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    ctx.create_wns_member(&group).await;
+    ctx.downgrade_wns_group(&group.group).await;
+
+    // Anyone can pay for the migration, the group authority does not sign
+    let instruction = ctx.migrate_wns_group_instruction(&group.group, &group.mint);
+    ctx.process(&[instruction], &[]).await.unwrap();
+
+    let state: TokenGroup = ctx.account(&group.group).await;
+    assert_eq!(state.update_authority, group.authority.pubkey());
+    assert_eq!(state.mint, group.mint);
+    assert_eq!(state.size, 1);
+    assert_eq!(state.max_size, WNS_GROUP_MAX_SIZE);
+    assert_eq!(state.member_count, 1);
+
+    let member = ctx.create_wns_member(&group).await;
+    let member: TokenGroupMember = ctx.account(&member.member).await;
+    assert_eq!(member.member_number, 2);
+}
+
+#[tokio::test]
+async fn migrate_group_account_rejects_migrated_group() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+
+    let instruction = ctx.migrate_wns_group_instruction(&group.group, &group.mint);
+    let result = ctx.process(&[instruction], &[]).await;
+
+    assert_wns_error(result, MetadataErrors::GroupAlreadyMigrated);
+}
+
+#[tokio::test]
+async fn transfer_group_authority() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let new_authority = Keypair::new();
+    let group_authority_transfer = pda::wns_group_authority_transfer(&group.group).0;
+
+    let transfer = transfer_group_authority_instruction(&ctx, &group, new_authority.pubkey());
+    ctx.process(&[transfer], &[&group.authority]).await.unwrap();
+
+    // The manager holds the metadata authority until the new authority accepts
+    let pending: GroupAuthorityTransfer = ctx.account(&group_authority_transfer).await;
+    assert_eq!(pending.group, group.group);
+    assert_eq!(pending.new_authority, new_authority.pubkey());
+    let metadata = ctx.token_metadata(&group.mint).await;
+    assert_eq!(
+        Option::<Pubkey>::from(metadata.update_authority),
+        Some(pda::wns_manager().0)
+    );
+
+    let accept = accept_group_authority_instruction(&ctx, &group, &new_authority.pubkey());
+    ctx.process(&[accept], &[&new_authority]).await.unwrap();
+
+    let state: TokenGroup = ctx.account(&group.group).await;
+    assert_eq!(state.update_authority, new_authority.pubkey());
+    let metadata = ctx.token_metadata(&group.mint).await;
+    assert_eq!(
+        Option::<Pubkey>::from(metadata.update_authority),
+        Some(new_authority.pubkey())
+    );
+    assert!(!ctx.account_exists(&group_authority_transfer).await);
+}
+
+#[tokio::test]
+async fn transfer_group_authority_rejects_other_authority() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+
+    let other = WnsGroup {
+        authority: ctx.funded_keypair().await,
+        ..group
+    };
+    let transfer = transfer_group_authority_instruction(&ctx, &other, Pubkey::new_unique());
+    let result = ctx.process(&[transfer], &[&other.authority]).await;
+
+    assert_wns_error(result, MetadataErrors::InvalidGroupAuthority);
+}
+
+#[tokio::test]
+async fn accept_group_authority_rejects_other_authority() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+
+    let transfer = transfer_group_authority_instruction(&ctx, &group, Pubkey::new_unique());
+    ctx.process(&[transfer], &[&group.authority]).await.unwrap();
+
+    let impostor = Keypair::new();
+    let accept = accept_group_authority_instruction(&ctx, &group, &impostor.pubkey());
+    let result = ctx.process(&[accept], &[&impostor]).await;
+
+    assert_wns_error(result, MetadataErrors::InvalidGroupAuthority);
+    let state: TokenGroup = ctx.account(&group.group).await;
+    assert_eq!(state.update_authority, group.authority.pubkey());
+}
+
+#[tokio::test]
+async fn cancel_group_authority_transfer() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let group_authority_transfer = pda::wns_group_authority_transfer(&group.group).0;
+
+    let transfer = transfer_group_authority_instruction(&ctx, &group, Pubkey::new_unique());
+    ctx.process(&[transfer], &[&group.authority]).await.unwrap();
+
+    let cancel = cancel_group_authority_transfer_instruction(&ctx, &group);
+    ctx.process(&[cancel], &[&group.authority]).await.unwrap();
+
+    assert!(!ctx.account_exists(&group_authority_transfer).await);
+    let metadata = ctx.token_metadata(&group.mint).await;
+    assert_eq!(
+        Option::<Pubkey>::from(metadata.update_authority),
+        Some(group.authority.pubkey())
+    );
+}
+
+#[tokio::test]
+async fn close_group_account() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+
+    let close = close_group_account_instruction(&ctx, &group);
+    ctx.process(&[close], &[&group.authority]).await.unwrap();
+
+    assert!(!ctx.account_exists(&group.group).await);
+    assert!(!ctx.account_exists(&group.mint).await);
+    assert!(!ctx.account_exists(&group.token_account).await);
+}
+
+#[tokio::test]
+async fn close_group_account_rejects_group_with_members() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    ctx.create_wns_member(&group).await;
+
+    let close = close_group_account_instruction(&ctx, &group);
+    let result = ctx.process(&[close], &[&group.authority]).await;
+
+    assert_wns_error(result, MetadataErrors::GroupNotEmpty);
+    assert!(ctx.account_exists(&group.group).await);
+}
@@ -0,0 +1,234 @@
+use anchor_lang::{prelude::Pubkey, system_program};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::{Keypair, Signer},
+};
+use tokengator_client::{
+    instructions::wns,
+    pda,
+    wen_new_standard::{
+        accounts, ApproveAccount, ApproveTransferArgs, DistributeRoyaltiesArgs, MetadataErrors,
+        MintErrors,
+    },
+};
+use tokengator_program_tests::*;
+
+const ROYALTY_BASIS_POINTS: u16 = 500;
+const SALE_AMOUNT: u64 = 1_000_000_000;
+const ROYALTY_AMOUNT: u64 = SALE_AMOUNT * ROYALTY_BASIS_POINTS as u64 / 10_000;
+
+/// Mint with royalties going in full to `creator`
+async fn royalty_mint(ctx: &mut TestContext, creator: &Pubkey) -> Member {
+    let authority = ctx.funded_keypair().await;
+    let args = ctx.create_wns_mint_args();
+    let member = ctx.create_wns_mint(&authority, args).await;
+
+    let args = wns_royalties_args(ROYALTY_BASIS_POINTS, &[(*creator, 100)]);
+    let instruction = ctx.add_wns_royalties_instruction(&authority.pubkey(), &member.mint, args);
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+
+    member
+}
+
+fn approve_transfer_instruction(
+    ctx: &TestContext,
+    mint: &Pubkey,
+    creator: &Pubkey,
+    buy_amount: u64,
+) -> Instruction {
+    let mut instruction = wns::approve_transfer(
+        accounts::ApproveTransfer {
+            payer: ctx.payer(),
+            mint: *mint,
+            approve_account: pda::wns_approve_account(mint).0,
+            system_program: system_program::ID,
+            token_program: spl_token_2022::ID,
+        },
+        ApproveTransferArgs { buy_amount },
+    );
+
+    // Creators are paid through the remaining accounts
+    instruction.accounts.push(AccountMeta::new(*creator, false));
+
+    instruction
+}
+
+#[tokio::test]
+async fn approve_transfer() {
+    let mut ctx = TestContext::new().await;
+    let creator = ctx.funded_keypair().await.pubkey();
+    let member = royalty_mint(&mut ctx, &creator).await;
+    let to = Keypair::new().pubkey();
+    let destination = ctx.create_token_account(&to, &member.mint).await;
+    let creator_lamports = ctx.get_account(&creator).await.unwrap().lamports;
+
+    let approve = approve_transfer_instruction(&ctx, &member.mint, &creator, SALE_AMOUNT);
+    let transfer = transfer_member_instruction(&member, &destination);
+    ctx.process(&[approve, transfer], &[&member.holder])
+        .await
+        .unwrap();
+
+    assert_eq!(ctx.token_account(&destination).await.amount, 1);
+    assert_eq!(
+        ctx.get_account(&creator).await.unwrap().lamports,
+        creator_lamports + ROYALTY_AMOUNT
+    );
+
+    // The transfer consumed the approval
+    let approval: ApproveAccount = ctx.account(&pda::wns_approve_account(&member.mint).0).await;
+    assert_eq!(approval.slot, 0);
+}
+
+#[tokio::test]
+async fn approve_transfer_rejects_missing_creator() {
+    let mut ctx = TestContext::new().await;
+    let creator = ctx.funded_keypair().await.pubkey();
+    let member = royalty_mint(&mut ctx, &creator).await;
+
+    let approve =
+        approve_transfer_instruction(&ctx, &member.mint, &Pubkey::new_unique(), SALE_AMOUNT);
+    let result = ctx.process(&[approve], &[]).await;
+
+    assert_wns_error(result, MetadataErrors::CreatorAddressInvalid);
+}
+
+#[tokio::test]
+async fn approve_transfer_rejects_zero_buy_amount() {
+    let mut ctx = TestContext::new().await;
+    let creator = ctx.funded_keypair().await.pubkey();
+    let member = royalty_mint(&mut ctx, &creator).await;
+
+    let approve = approve_transfer_instruction(&ctx, &member.mint, &creator, 0);
+    let result = ctx.process(&[approve], &[]).await;
+
+    assert_wns_error(result, MetadataErrors::BuyAmountInvalid);
+}
+
+#[tokio::test]
+async fn burn_closes_approve_account() {
+    let mut ctx = TestContext::new().await;
+    let creator = ctx.funded_keypair().await.pubkey();
+    let member = royalty_mint(&mut ctx, &creator).await;
+    let approve_account = pda::wns_approve_account(&member.mint).0;
+
+    let approve = approve_transfer_instruction(&ctx, &member.mint, &creator, SALE_AMOUNT);
+    ctx.process(&[approve], &[]).await.unwrap();
+    assert!(ctx.account_exists(&approve_account).await);
+
+    let burn = wns::burn_non_member_mint_account(accounts::BurnNonMemberMintAccount {
+        payer: ctx.payer(),
+        user: member.holder.pubkey(),
+        mint: member.mint,
+        mint_token_account: member.token_account,
+        member: member.member,
+        extra_metas_account: pda::wns_extra_meta_list(&member.mint).0,
+        approve_account,
+        manager: pda::wns_manager().0,
+        token_program: spl_token_2022::ID,
+    });
+    ctx.process(&[burn], &[&member.holder]).await.unwrap();
+
+    assert!(!ctx.account_exists(&approve_account).await);
+}
+
+#[tokio::test]
+async fn execute_rejects_royalty_transfer_without_approval() {
+    let mut ctx = TestContext::new().await;
+    let creator = ctx.funded_keypair().await.pubkey();
+    let member = royalty_mint(&mut ctx, &creator).await;
+
+    let to = Keypair::new().pubkey();
+    let (_, result) = ctx.try_transfer_member(&member, &to).await;
+
+    assert_wns_error(result, MetadataErrors::MissingApproveAccount);
+    assert_eq!(ctx.token_account(&member.token_account).await.amount, 1);
+}
+
+#[tokio::test]
+async fn execute_rejects_reused_approval() {
+    let mut ctx = TestContext::new().await;
+    let creator = ctx.funded_keypair().await.pubkey();
+    let member = royalty_mint(&mut ctx, &creator).await;
+    let holder = member.holder.pubkey();
+    let to = Keypair::new();
+    let destination = ctx.create_token_account(&to.pubkey(), &member.mint).await;
+
+    let approve = approve_transfer_instruction(&ctx, &member.mint, &creator, SALE_AMOUNT);
+    let transfer = transfer_member_instruction(&member, &destination);
+    ctx.process(&[approve, transfer], &[&member.holder])
+        .await
+        .unwrap();
+
+    // Sending it back without a new approval finds the consumed one
+    let returned = Member {
+        token_account: destination,
+        holder: to,
+        ..member
+    };
+    let (_, result) = ctx.try_transfer_member(&returned, &holder).await;
+
+    assert_wns_error(result, MetadataErrors::ExpiredApproveAccount);
+}
+
+#[tokio::test]
+async fn execute_rejects_call_outside_transfer() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_mint_args();
+    let authority = ctx.funded_keypair().await;
+    let member = ctx.create_wns_mint(&authority, args).await;
+    let destination = ctx
+        .create_token_account(&Keypair::new().pubkey(), &member.mint)
+        .await;
+
+    let execute = wns::execute(
+        accounts::ExecuteTransferHook {
+            source_account: member.token_account,
+            mint: member.mint,
+            destination_account: destination,
+            owner_delegate: member.holder.pubkey(),
+            extra_metas_account: pda::wns_extra_meta_list(&member.mint).0,
+            approve_account: pda::wns_approve_account(&member.mint).0,
+        },
+        1,
+    );
+    let result = ctx.process(&[execute], &[]).await;
+
+    assert_wns_error(result, MintErrors::NotTransferring);
+}
+
+#[tokio::test]
+async fn distribute_royalties() {
+    let mut ctx = TestContext::new().await;
+    let creator = Keypair::new().pubkey();
+    let member = royalty_mint(&mut ctx, &creator).await;
+    let buyer = ctx.funded_keypair().await;
+    let buyer_token_account = ctx.fund_payment_tokens(&buyer.pubkey(), SALE_AMOUNT).await;
+    let payment_mint = ctx.payment_mint;
+    let creator_token_account = ctx.create_token_account(&creator, &payment_mint).await;
+
+    let mut distribute = wns::distribute_royalties(
+        accounts::DistributeRoyalties {
+            payer: buyer.pubkey(),
+            mint: member.mint,
+            payment_mint,
+            payer_token_account: buyer_token_account,
+            payment_token_program: spl_token_2022::ID,
+        },
+        DistributeRoyaltiesArgs {
+            sale_amount: SALE_AMOUNT,
+        },
+    );
+    distribute
+        .accounts
+        .push(AccountMeta::new(creator_token_account, false));
+    ctx.process(&[distribute], &[&buyer]).await.unwrap();
+
+    assert_eq!(
+        ctx.token_account(&creator_token_account).await.amount,
+        ROYALTY_AMOUNT
+    );
+    assert_eq!(
+        ctx.token_account(&buyer_token_account).await.amount,
+        SALE_AMOUNT - ROYALTY_AMOUNT
+    );
+}
@@ -0,0 +1,317 @@
+use anchor_lang::{prelude::Pubkey, solana_program::program_option::COption};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+use tokengator_client::{
+    instructions::wns,
+    pda,
+    wen_new_standard::{accounts, MetadataErrors, MintErrors, TokenGroup},
+};
+use tokengator_program_tests::*;
+
+fn burn_mint_account_instruction(
+    ctx: &TestContext,
+    group: &Pubkey,
+    member: &Member,
+) -> Instruction {
+    wns::burn_mint_account(accounts::BurnMintAccount {
+        payer: ctx.payer(),
+        user: member.holder.pubkey(),
+        mint: member.mint,
+        mint_token_account: member.token_account,
+        group: *group,
+        member: member.member,
+        extra_metas_account: pda::wns_extra_meta_list(&member.mint).0,
+        approve_account: pda::wns_approve_account(&member.mint).0,
+        manager: pda::wns_manager().0,
+        token_program: spl_token_2022::ID,
+    })
+}
+
+fn burn_non_member_mint_account_instruction(ctx: &TestContext, member: &Member) -> Instruction {
+    wns::burn_non_member_mint_account(accounts::BurnNonMemberMintAccount {
+        payer: ctx.payer(),
+        user: member.holder.pubkey(),
+        mint: member.mint,
+        mint_token_account: member.token_account,
+        member: member.member,
+        extra_metas_account: pda::wns_extra_meta_list(&member.mint).0,
+        approve_account: pda::wns_approve_account(&member.mint).0,
+        manager: pda::wns_manager().0,
+        token_program: spl_token_2022::ID,
+    })
+}
+
+fn finalize_mint_instruction(authority: &Pubkey, mint: &Pubkey) -> Instruction {
+    wns::finalize_mint(accounts::FinalizeMintAccount {
+        authority: *authority,
+        mint: *mint,
+        member: pda::wns_member(mint).0,
+        manager: pda::wns_manager().0,
+        token_program: spl_token_2022::ID,
+    })
+}
+
+#[tokio::test]
+async fn burn_mint_account() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let member = ctx.create_wns_member(&group).await;
+
+    let burn = burn_mint_account_instruction(&ctx, &group.group, &member);
+    ctx.process(&[burn], &[&member.holder]).await.unwrap();
+
+    assert!(!ctx.account_exists(&member.mint).await);
+    assert!(!ctx.account_exists(&member.token_account).await);
+    assert!(!ctx.account_exists(&member.member).await);
+    assert!(
+        !ctx.account_exists(&pda::wns_extra_meta_list(&member.mint).0)
+            .await
+    );
+
+    // Burning frees the slot, the member number is never handed out again
+    let state: TokenGroup = ctx.account(&group.group).await;
+    assert_eq!(state.size, 0);
+    assert_eq!(state.member_count, 1);
+}
+
+#[tokio::test]
+async fn burn_mint_account_rejects_other_holder() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let member = ctx.create_wns_member(&group).await;
+
+    let other = Member {
+        holder: ctx.funded_keypair().await,
+        ..member
+    };
+    let burn = burn_mint_account_instruction(&ctx, &group.group, &other);
+    let result = ctx.process(&[burn], &[&other.holder]).await;
+
+    assert_anchor_error(result, anchor_lang::error::ErrorCode::ConstraintTokenOwner);
+    assert!(ctx.account_exists(&other.mint).await);
+}
+
+#[tokio::test]
+async fn burn_non_member_mint_account() {
+    let mut ctx = TestContext::new().await;
+    let authority = ctx.funded_keypair().await;
+    let args = ctx.create_wns_mint_args();
+    let member = ctx.create_wns_mint(&authority, args).await;
+
+    let burn = burn_non_member_mint_account_instruction(&ctx, &member);
+    ctx.process(&[burn], &[&member.holder]).await.unwrap();
+
+    assert!(!ctx.account_exists(&member.mint).await);
+    assert!(!ctx.account_exists(&member.token_account).await);
+    assert!(
+        !ctx.account_exists(&pda::wns_extra_meta_list(&member.mint).0)
+            .await
+    );
+}
+
+#[tokio::test]
+async fn burn_non_member_mint_account_rejects_group_member() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let member = ctx.create_wns_member(&group).await;
+
+    let burn = burn_non_member_mint_account_instruction(&ctx, &member);
+    let result = ctx.process(&[burn], &[&member.holder]).await;
+
+    assert_wns_error(result, MintErrors::MintIsGroupMember);
+    assert!(ctx.account_exists(&member.mint).await);
+}
+
+#[tokio::test]
+async fn finalize_mint() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let member = ctx.create_wns_member(&group).await;
+
+    let finalize = finalize_mint_instruction(&group.authority.pubkey(), &member.mint);
+    let authority = group.authority.insecure_clone();
+    ctx.process(&[finalize], &[&authority]).await.unwrap();
+
+    assert_eq!(ctx.mint(&member.mint).await.mint_authority, COption::None);
+}
+
+#[tokio::test]
+async fn finalize_mint_rejects_mint_outside_group() {
+    let mut ctx = TestContext::new().await;
+    let authority = ctx.funded_keypair().await;
+    let args = ctx.create_wns_mint_args();
+    let member = ctx.create_wns_mint(&authority, args).await;
+
+    let finalize = finalize_mint_instruction(&authority.pubkey(), &member.mint);
+    let result = ctx.process(&[finalize], &[&authority]).await;
+
+    assert_anchor_error(result, anchor_lang::error::ErrorCode::AccountNotInitialized);
+    assert_eq!(
+        ctx.mint(&member.mint).await.mint_authority,
+        COption::Some(pda::wns_manager().0)
+    );
+}
+
+#[tokio::test]
+async fn finalize_mint_rejects_other_authority() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let member = ctx.create_wns_member(&group).await;
+
+    let other = Keypair::new();
+    let finalize = finalize_mint_instruction(&other.pubkey(), &member.mint);
+    let result = ctx.process(&[finalize], &[&other]).await;
+
+    assert_wns_error(result, MintErrors::InvalidMintAuthority);
+    assert_eq!(
+        ctx.mint(&member.mint).await.mint_authority,
+        COption::Some(pda::wns_manager().0)
+    );
+}
+
+#[tokio::test]
+async fn finalize_mint_rejects_finalized_mint() {
+    let mut ctx = TestContext::new().await;
+    let args = ctx.create_wns_group_args();
+    let group = ctx.create_wns_group(args).await;
+    let mut args = ctx.create_wns_mint_args();
+    args.finalize = true;
+    let member = ctx.create_wns_mint(&group.authority, args).await;
+    let authority = group.authority.insecure_clone();
+    let add = ctx.add_wns_member_instruction(&group, &member.mint);
+    ctx.process(&[add], &[&authority]).await.unwrap();
+
+    let finalize = finalize_mint_instruction(&authority.pubkey(), &member.mint);
+    let result = ctx.process(&[finalize], &[&authority]).await;
+
+    assert_wns_error(result, MintErrors::InvalidMintAuthority);
+}
+
+#[tokio::test]
+async fn add_royalties() {
+    let mut ctx = TestContext::new().await;
+    let authority = ctx.funded_keypair().await;
+    let args = ctx.create_wns_mint_args();
+    let member = ctx.create_wns_mint(&authority, args).await;
+    let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let args = wns_royalties_args(500, &[(first, 70), (second, 30)]);
+    let instruction = ctx.add_wns_royalties_instruction(&authority.pubkey(), &member.mint, args);
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+
+    assert_eq!(
+        ctx.metadata_field(&member.mint, "royalty_basis_points")
+            .await
+            .as_deref(),
+        Some("500")
+    );
+    assert_eq!(
+        ctx.metadata_field(&member.mint, &first.to_string())
+            .await
+            .as_deref(),
+        Some("70")
+    );
+    assert_eq!(
+        ctx.metadata_field(&member.mint, &second.to_string())
+            .await
+            .as_deref(),
+        Some("30")
+    );
+}
+
+#[tokio::test]
+async fn add_royalties_rejects_invalid_shares() {
+    let mut ctx = TestContext::new().await;
+    let authority = ctx.funded_keypair().await;
+    let args = ctx.create_wns_mint_args();
+    let member = ctx.create_wns_mint(&authority, args).await;
+
+    let args = wns_royalties_args(500, &[(Pubkey::new_unique(), 90)]);
+    let instruction = ctx.add_wns_royalties_instruction(&authority.pubkey(), &member.mint, args);
+    let result = ctx.process(&[instruction], &[&authority]).await;
+
+    assert_wns_error(result, MetadataErrors::CreatorShareInvalid);
+}
+
+#[tokio::test]
+async fn add_royalties_rejects_mint_with_royalties() {
+    let mut ctx = TestContext::new().await;
+    let authority = ctx.funded_keypair().await;
+    let args = ctx.create_wns_mint_args();
+    let member = ctx.create_wns_mint(&authority, args).await;
+
+    let args = wns_royalties_args(500, &[(Pubkey::new_unique(), 100)]);
+    let instruction = ctx.add_wns_royalties_instruction(&authority.pubkey(), &member.mint, args);
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+
+    let args = wns_royalties_args(1000, &[(Pubkey::new_unique(), 100)]);
+    let instruction = ctx.add_wns_royalties_instruction(&authority.pubkey(), &member.mint, args);
+    let result = ctx.process(&[instruction], &[&authority]).await;
+
+    assert_wns_error(result, MetadataErrors::RoyaltiesAlreadySet);
+}
+
+#[tokio::test]
+async fn modify_royalties() {
+    let mut ctx = TestContext::new().await;
+    let authority = ctx.funded_keypair().await;
+    let args = ctx.create_wns_mint_args();
+    let member = ctx.create_wns_mint(&authority, args).await;
+    let (kept, dropped, added) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    let args = wns_royalties_args(500, &[(kept, 50), (dropped, 50)]);
+    let instruction = ctx.add_wns_royalties_instruction(&authority.pubkey(), &member.mint, args);
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+
+    let args = wns_royalties_args(250, &[(kept, 80), (added, 20)]);
+    let instruction = ctx.modify_wns_royalties_instruction(&authority.pubkey(), &member.mint, args);
+    ctx.process(&[instruction], &[&authority]).await.unwrap();
+
+    assert_eq!(
+        ctx.metadata_field(&member.mint, "royalty_basis_points")
+            .await
+            .as_deref(),
+        Some("250")
+    );
+    assert_eq!(
+        ctx.metadata_field(&member.mint, &kept.to_string())
+            .await
+            .as_deref(),
+        Some("80")
+    );
+    assert_eq!(
+        ctx.metadata_field(&member.mint, &added.to_string())
+            .await
+            .as_deref(),
+        Some("20")
+    );
+    assert_eq!(
+        ctx.metadata_field(&member.mint, &dropped.to_string()).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn modify_royalties_rejects_mint_without_royalties() {
+    let mut ctx = TestContext::new().await;
+    let authority = ctx.funded_keypair().await;
+    let args = ctx.create_wns_mint_args();
+    let member = ctx.create_wns_mint(&authority, args).await;
+
+    let args = wns_royalties_args(500, &[(Pubkey::new_unique(), 100)]);
+    let instruction = ctx.modify_wns_royalties_instruction(&authority.pubkey(), &member.mint, args);
+    let result = ctx.process(&[instruction], &[&authority]).await;
+
+    assert_wns_error(result, MetadataErrors::RoyaltiesNotSet);
+}
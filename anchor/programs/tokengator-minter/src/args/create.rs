@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::*;
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct PaymentConfigArgs {
     pub amount: u16,
@@ -7,3 +9,9 @@ pub struct PaymentConfigArgs {
     pub mint: Pubkey,
     pub days: u8,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MinterApplicationConfigArgs {
+    pub identities: Vec<IdentityProvider>,
+    pub payment_tiers: Vec<PaymentTier>,
+}
@@ -4,6 +4,11 @@ pub const GROUP: &[u8] = b"group";
 pub const COMMUNITY: &[u8] = b"community";
 pub const ACTIVITY: &[u8] = b"activity";
 pub const RECEIPT: &[u8] = b"receipt";
+pub const COUPON: &[u8] = b"coupon";
+pub const SUBSCRIPTION: &[u8] = b"subscription";
+pub const PROTOCOL_CONFIG: &[u8] = b"protocol_config";
+pub const IDENTITY_LINK: &[u8] = b"identity_link";
+pub const IDENTITY_CLAIM: &[u8] = b"identity_claim";
 
 // Metadata
 pub const MAX_NAME_SIZE: usize = 50;
@@ -11,7 +16,19 @@ pub const MAX_SYMBOL_SIZE: usize = 10;
 pub const MAX_DESCRIPTION_SIZE: usize = 200;
 pub const MAX_IMAGE_URL_SIZE: usize = 100;
 pub const MAX_METADATA_KEY_VALUE_SIZE: usize = 15;
+// Bounded by the max PDA seed length as it seeds identity links
+pub const MAX_IDENTITY_PROVIDER_VALUE_SIZE: usize = 32;
 pub const MAX_URI_SIZE: usize = 100;
+pub const MAX_WEBSITE_SIZE: usize = 100;
+
+// Group
+pub const MAX_GROUP_SIZE: u32 = 100;
+
+// Payment
+pub const MAX_TIER_LABEL_SIZE: usize = 20;
+pub const MAX_PAYMENT_TIERS: usize = 10;
+pub const MAX_ALLOWED_PAYMENT_MINTS: usize = 20;
+pub const MAX_BASIS_POINTS: u16 = 10_000;
 
 // Activity
 pub const MAX_LABEL_SIZE: usize = 50;
@@ -44,4 +44,54 @@ pub enum TokenGatorMinterError {
     InvalidTokenProgram,
     #[msg("Cannot remove minter of non-zero supply")]
     CannotRemoveNonZeroSupplyMinter,
+    #[msg("Invalid payment tier")]
+    InvalidPaymentTier,
+    #[msg("Invalid coupon")]
+    InvalidCoupon,
+    #[msg("Coupon has expired")]
+    CouponExpired,
+    #[msg("Coupon has no redemptions left")]
+    CouponRedemptionsExhausted,
+    #[msg("Invalid subscription")]
+    InvalidSubscription,
+    #[msg("Subscription is not due yet")]
+    SubscriptionNotDue,
+    #[msg("Invalid protocol fee")]
+    InvalidProtocolFee,
+    #[msg("Invalid treasury account")]
+    InvalidTreasury,
+    #[msg("Payment mint not allowed")]
+    PaymentMintNotAllowed,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Minter is paused")]
+    MinterPaused,
+    #[msg("Invalid minter")]
+    InvalidMinter,
+    #[msg("Invalid identity attestation")]
+    InvalidIdentityAttestation,
+    #[msg("Identity attestation has expired")]
+    IdentityAttestationExpired,
+    #[msg("Required identities are not linked")]
+    MissingIdentity,
+    #[msg("Invalid identity link")]
+    InvalidIdentityLink,
+    #[msg("Invalid identity provider")]
+    InvalidIdentityProvider,
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+    #[msg("Mint extension is not enabled")]
+    MintExtensionNotEnabled,
+    #[msg("Invalid transfer fee")]
+    InvalidTransferFee,
+    #[msg("Minter badges are not transferable")]
+    MinterNotTransferable,
+    #[msg("Minter group has not been migrated to the group extension")]
+    GroupNotMigrated,
+    #[msg("Cannot remove a minter whose group still has members")]
+    CannotRemoveNonEmptyGroup,
+    #[msg("Invalid website")]
+    InvalidWebsite,
+    #[msg("Invalid royalty basis points")]
+    InvalidRoyaltyBasisPoints,
 }
@@ -18,11 +18,35 @@ pub struct AppendActivityEntry<'info> {
       ],
       bump = activity.bump,
       has_one = fee_payer @ TokenGatorMinterError::InvalidFeePayer,
+      has_one = minter @ TokenGatorMinterError::InvalidMinter,
     )]
     pub activity: Account<'info, Activity>,
 
+    #[account(
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused,
+    )]
+    pub minter: Account<'info, Minter>,
+
     #[account(mut)]
     pub fee_payer: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -33,6 +33,7 @@ pub struct CreateActivity<'info> {
       bump = minter.bump,
       has_one = fee_payer @ TokenGatorMinterError::InvalidFeePayer,
       has_one = group @ TokenGatorMinterError::InvalidWNSGroup,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused,
     )]
     pub minter: Account<'info, Minter>,
 
@@ -51,6 +52,16 @@ pub struct CreateActivity<'info> {
 
     #[account(mut)]
     pub fee_payer: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
     pub system_program: Program<'info, System>,
 }
 
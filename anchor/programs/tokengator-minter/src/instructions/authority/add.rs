@@ -17,7 +17,8 @@ pub struct AddMinterAuthority<'info> {
       ],
       bump = minter.bump,
       has_one = fee_payer @ TokenGatorMinterError::UnAuthorized,
-      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized
+      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
     )]
     pub minter: Account<'info, Minter>,
 
@@ -28,6 +29,17 @@ pub struct AddMinterAuthority<'info> {
       constraint = fee_payer.key().ne(&authority.key()) @ TokenGatorMinterError::InvalidFeePayer
     )]
     pub fee_payer: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -47,7 +59,8 @@ pub fn add(ctx: Context<AddMinterAuthority>, args: AddMinterAuthorityArgs) -> Re
 
     let new_preset_size = Minter::size(
         &minter.authorities,
-        &minter.minter_config.application_config,
+        &minter.minter_config.application_config.identities,
+        &minter.payment_tiers,
         &minter.minter_config.metadata_config,
     );
 
@@ -16,7 +16,8 @@ pub struct RemoveMinterAuthority<'info> {
       ],
       bump = minter.bump,
       has_one = fee_payer @ TokenGatorMinterError::UnAuthorized,
-      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized
+      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
     )]
     pub minter: Account<'info, Minter>,
 
@@ -27,6 +28,16 @@ pub struct RemoveMinterAuthority<'info> {
     pub fee_payer: Signer<'info>,
 
     pub authority: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
 }
 
 pub fn remove(ctx: Context<RemoveMinterAuthority>, args: RemoveMinterAuthorityArgs) -> Result<()> {
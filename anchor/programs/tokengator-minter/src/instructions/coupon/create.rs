@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(args: CreateCouponArgs)]
+pub struct CreateCoupon<'info> {
+    #[account(
+      init,
+      space = Coupon::size(),
+      payer = fee_payer,
+      seeds = [
+        PREFIX,
+        COUPON,
+        minter.key().as_ref(),
+        args.code_hash.as_ref(),
+      ],
+      bump
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      has_one = fee_payer @ TokenGatorMinterError::UnAuthorized,
+      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
+    )]
+    pub minter: Account<'info, Minter>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+      mut,
+      constraint = fee_payer.key().ne(&authority.key()) @ TokenGatorMinterError::InvalidFeePayer
+    )]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create(ctx: Context<CreateCoupon>, args: CreateCouponArgs) -> Result<()> {
+    let coupon = &mut ctx.accounts.coupon;
+    let minter = &ctx.accounts.minter;
+    let fee_payer = &ctx.accounts.fee_payer;
+
+    coupon.set_inner(Coupon {
+        bump: ctx.bumps.coupon,
+        minter: minter.key(),
+        code_hash: args.code_hash,
+        discount: args.discount,
+        max_redemptions: args.max_redemptions,
+        redemptions: 0,
+        expires_at: args.expires_at,
+        wallet: args.wallet,
+        fee_payer: fee_payer.key(),
+    });
+
+    coupon.validate()?;
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateCouponArgs {
+    pub code_hash: [u8; 32],
+    pub discount: CouponDiscount,
+    pub max_redemptions: u32,
+    pub expires_at: Option<i64>,
+    pub wallet: Option<Pubkey>,
+}
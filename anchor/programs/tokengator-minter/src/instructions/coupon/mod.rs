@@ -0,0 +1,5 @@
+pub mod create;
+pub mod remove;
+
+pub use create::*;
+pub use remove::*;
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RemoveCoupon<'info> {
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        COUPON,
+        minter.key().as_ref(),
+        coupon.code_hash.as_ref(),
+      ],
+      bump = coupon.bump,
+      has_one = minter @ TokenGatorMinterError::InvalidCoupon,
+      has_one = fee_payer @ TokenGatorMinterError::InvalidFeePayer,
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
+    )]
+    pub minter: Account<'info, Minter>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+}
+
+pub fn remove(ctx: Context<RemoveCoupon>) -> Result<()> {
+    let fee_payer = &ctx.accounts.fee_payer;
+    let coupon = &ctx.accounts.coupon;
+
+    coupon.close(fee_payer.to_account_info())?;
+
+    Ok(())
+}
@@ -14,15 +14,16 @@ use anchor_spl::{
 };
 use spl_pod::optional_keys::OptionalNonZeroPubkey;
 use spl_token_2022::{extension::ExtensionType, state::Mint};
+use spl_token_group_interface::state::TokenGroup;
 use spl_token_metadata_interface::state::TokenMetadata;
+use spl_type_length_value::state::{TlvState, TlvStateBorrowed};
 
-/* Group extension related imports */
+/* Mint authority related imports */
 // use anchor_spl::token_2022::{
 //     set_authority, spl_token_2022::instruction::AuthorityType, SetAuthority,
 // };
-// use spl_token_group_interface::state::TokenGroup;
-// use spl_type_length_value::state::{TlvState, TlvStateBorrowed};
 
+use crate::args::*;
 use crate::constants::*;
 use crate::errors::*;
 use crate::state::*;
@@ -31,24 +32,15 @@ use crate::utils::*;
 #[derive(Accounts)]
 #[instruction(args: CreateMinterArgs)]
 pub struct CreateMinter<'info> {
-    /* Custom account till group extensions become live */
-    #[account(
-        init,
-        payer = fee_payer,
-        space = Group::size(),
-        seeds = [
-            PREFIX,
-            GROUP,
-            mint.key().as_ref()
-        ],
-        bump
-    )]
-    pub group: Account<'info, Group>,
-
     #[account(
       init,
       payer = fee_payer,
-      space = Minter::size(&[authority.key()], &args.application_config, &args.metadata_config),
+      space = Minter::size(
+        &[authority.key()],
+        &args.application_config.identities,
+        &args.application_config.payment_tiers,
+        &args.metadata_config
+      ),
       seeds = [
         PREFIX,
         MINTER,
@@ -73,6 +65,16 @@ pub struct CreateMinter<'info> {
     pub fee_payer: Signer<'info>,
     pub authority: Signer<'info>,
 
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -80,7 +82,6 @@ pub struct CreateMinter<'info> {
 
 pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()> {
     let minter = &mut ctx.accounts.minter;
-    let group = &mut ctx.accounts.group;
 
     let authority = &ctx.accounts.authority;
     let fee_payer = &ctx.accounts.fee_payer;
@@ -93,7 +94,6 @@ pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()>
 
     let minter_key = minter.key();
     let mint_key = mint.key();
-    let group_key = group.key();
 
     let community_id = fetch_community_id(&args.community);
 
@@ -103,13 +103,24 @@ pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()>
         interest_config,
         application_config,
         payment_config,
+        transferable,
         ..
     } = args;
 
+    let MinterApplicationConfigArgs {
+        identities,
+        payment_tiers,
+    } = application_config;
+
+    let application_config = MinterApplicationConfig {
+        identities,
+        payment_config: PaymentConfig::default(),
+    };
+
     // 1. Saving Minter onchain
     let minter_config = MinterConfig {
         mint: mint.key(),
-        application_config: application_config.clone(),
+        application_config,
         interest_config: interest_config.clone(),
         metadata_config: metadata_config.clone(),
         transfer_fee_config: transfer_fee_config.clone(),
@@ -118,7 +129,7 @@ pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()>
     minter.set_inner(Minter {
         bump: ctx.bumps.minter,
         community_id,
-        group: group_key,
+        group: mint_key,
         name: args.name.clone(),
         description: args.description,
         image_url: args.image_url,
@@ -126,18 +137,30 @@ pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()>
         authorities: vec![authority.key()],
         payment_config,
         minter_config,
+        paused: false,
+        payment_tiers,
+        transferable: TrailingBool(transferable),
     });
 
+    // Only minters created before payment tiers fall back to their payment config
+    require!(
+        !minter.payment_tiers.is_empty(),
+        TokenGatorMinterError::InvalidPaymentTier
+    );
+
     minter.validate()?;
 
     // 2. Creating Mint account
     let mut mint_extension_types = vec![
         ExtensionType::MintCloseAuthority,
-        ExtensionType::NonTransferable,
         ExtensionType::MetadataPointer,
         ExtensionType::GroupPointer,
     ];
 
+    if !transferable {
+        mint_extension_types.push(ExtensionType::NonTransferable);
+    }
+
     if transfer_fee_config.is_some() {
         mint_extension_types.push(ExtensionType::TransferFeeConfig);
     }
@@ -168,10 +191,9 @@ pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()>
 
     let mint_size = ExtensionType::try_calculate_account_len::<Mint>(&mint_extension_types)?;
     let metadata_size = metadata.tlv_size_of()?;
-    // let group_size = TlvStateBorrowed::get_base_len() + size_of::<TokenGroup>();
-    // let rent_lamports = Rent::get()?.minimum_balance(mint_size + metadata_size + group_size);
+    let group_size = TlvStateBorrowed::get_base_len() + std::mem::size_of::<TokenGroup>();
 
-    let rent_lamports = Rent::get()?.minimum_balance(mint_size + metadata_size);
+    let rent_lamports = Rent::get()?.minimum_balance(mint_size + metadata_size + group_size);
 
     system_program::create_account(
         CpiContext::new(
@@ -197,12 +219,14 @@ pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()>
         Some(&minter_key),
     )?;
 
-    initialize_mint_non_transferable(CpiContext::new(
-        token_extensions_program.to_account_info(),
-        InitializeMintNonTransferrable {
-            mint: mint.to_account_info(),
-        },
-    ))?;
+    if !transferable {
+        initialize_mint_non_transferable(CpiContext::new(
+            token_extensions_program.to_account_info(),
+            InitializeMintNonTransferrable {
+                mint: mint.to_account_info(),
+            },
+        ))?;
+    }
 
     initialize_metadata_pointer(
         CpiContext::new(
@@ -223,7 +247,7 @@ pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()>
             },
         ),
         Some(minter_key),
-        Some(group_key),
+        Some(mint_key),
     )?;
 
     if interest_config.is_some() {
@@ -321,27 +345,20 @@ pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()>
         }
     }
 
-    // 6. Initializing group
-    // Setting this after Group extensions are deployed
-    // initialize_group(
-    //     CpiContext::new_with_signer(
-    //         token_extensions_program.to_account_info(),
-    //         InitializeGroup {
-    //             group: mint.to_account_info(),
-    //             mint: mint.to_account_info(),
-    //             mint_authority: minter.to_account_info(),
-    //         },
-    //         signer_seeds,
-    //     ),
-    //     Some(minter_key.clone()),
-    //     100,
-    // )?;
-    group.set_inner(Group {
-        update_authority: minter_key,
-        mint: mint_key,
-        max_size: 100,
-        size: 0,
-    });
+    // 6. Initializing group on the mint itself
+    initialize_group(
+        CpiContext::new_with_signer(
+            token_extensions_program.to_account_info(),
+            InitializeGroup {
+                group: mint.to_account_info(),
+                mint: mint.to_account_info(),
+                mint_authority: minter.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(minter_key),
+        MAX_GROUP_SIZE,
+    )?;
 
     // 7. Minting Collection NFT and freezing Mint authority
     let expected_minter_token_account = get_associated_token_address_with_program_id(
@@ -381,7 +398,7 @@ pub fn create(ctx: Context<CreateMinter>, args: CreateMinterArgs) -> Result<()>
         1,
     )?;
 
-    /* Uncomment this after group extensions are live */
+    /* Uncomment this once members no longer need the collection mint authority */
     // set_authority(
     //     CpiContext::new_with_signer(
     //         token_extensions_program.to_account_info(),
@@ -405,8 +422,9 @@ pub struct CreateMinterArgs {
     pub description: String,
     pub image_url: String,
     pub payment_config: PaymentConfig,
-    pub application_config: MinterApplicationConfig,
+    pub application_config: MinterApplicationConfigArgs,
     pub metadata_config: MinterMetadataConfig,
     pub interest_config: Option<MinterInterestConfig>,
     pub transfer_fee_config: Option<MinterTransferFeeConfig>,
+    pub transferable: bool,
 }
@@ -0,0 +1,149 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+use anchor_spl::{
+    token_2022::ID as TOKEN_EXTENSIONS_PROGRAM_ID,
+    token_interface::{Mint, TokenInterface},
+};
+use spl_token_group_interface::state::TokenGroup;
+use spl_type_length_value::state::{TlvState, TlvStateBorrowed};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct MigrateGroup<'info> {
+    #[account(
+      mut,
+      close = fee_payer,
+      seeds = [
+        PREFIX,
+        GROUP,
+        mint.key().as_ref()
+      ],
+      bump,
+      has_one = mint @ TokenGatorMinterError::InvalidMint,
+      constraint = group.update_authority.eq(&minter.key()) @ TokenGatorMinterError::UnAuthorized
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        MINTER,
+        &minter.minter_config.mint.as_ref(),
+        &minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      has_one = fee_payer @ TokenGatorMinterError::UnAuthorized,
+      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
+      constraint = minter.minter_config.mint.eq(&mint.key()) @ TokenGatorMinterError::InvalidMint,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
+    )]
+    pub minter: Account<'info, Minter>,
+
+    #[account(
+      mut,
+      mint::authority = minter,
+      mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+      constraint = token_program.key().eq(&TOKEN_EXTENSIONS_PROGRAM_ID) @ TokenGatorMinterError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate(ctx: Context<MigrateGroup>) -> Result<()> {
+    let minter = &mut ctx.accounts.minter;
+    let group = &ctx.accounts.group;
+
+    let fee_payer = &ctx.accounts.fee_payer;
+    let mint = &ctx.accounts.mint;
+
+    let token_extensions_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    let minter_key = minter.key();
+    let mint_key = mint.key();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes(),
+        &[minter.bump],
+    ]];
+
+    // 1. Pointing the group pointer at the mint itself
+    update_group_pointer(
+        CpiContext::new_with_signer(
+            token_extensions_program.to_account_info(),
+            UpdateGroupPointer {
+                mint: mint.to_account_info(),
+                authority: minter.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(mint_key),
+    )?;
+
+    // 2. Funding the rent for the group extension
+    let group_size = TlvStateBorrowed::get_base_len() + std::mem::size_of::<TokenGroup>();
+    let mint_info = mint.to_account_info();
+    let rent_lamports = Rent::get()?.minimum_balance(mint_info.data_len() + group_size);
+    let lamports_diff = rent_lamports.saturating_sub(mint_info.lamports());
+
+    if lamports_diff > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: fee_payer.to_account_info(),
+                    to: mint_info,
+                },
+            ),
+            lamports_diff,
+        )?;
+    }
+
+    // 3. Initializing group with the legacy max size
+    initialize_group(
+        CpiContext::new_with_signer(
+            token_extensions_program.to_account_info(),
+            InitializeGroup {
+                group: mint.to_account_info(),
+                mint: mint.to_account_info(),
+                mint_authority: minter.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(minter_key),
+        group.max_size,
+    )?;
+
+    // 4. Legacy group account is closed to the fee payer
+    minter.group = mint_key;
+
+    Ok(())
+}
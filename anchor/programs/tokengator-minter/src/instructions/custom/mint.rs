@@ -1,13 +1,28 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, CreateAccount},
+};
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token_2022::{mint_to, MintTo, ID as TOKEN_EXTENSIONS_PROGRAM_ID},
-    token_interface::{Mint, Token2022, TokenAccount},
+    associated_token::{
+        create as create_associated_token, get_associated_token_address_with_program_id,
+        AssociatedToken, Create as CreateAssociatedToken,
+    },
+    token_2022::{
+        initialize_mint2, initialize_mint_close_authority, mint_to, InitializeMint2,
+        InitializeMintCloseAuthority, MintTo, ID as TOKEN_EXTENSIONS_PROGRAM_ID,
+    },
+    token_interface::{Mint, TokenInterface},
 };
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_2022::{extension::ExtensionType, state::Mint as MintState};
+use spl_token_group_interface::state::TokenGroupMember;
+use spl_token_metadata_interface::state::TokenMetadata;
+use spl_type_length_value::state::{TlvState, TlvStateBorrowed};
 
 use crate::constants::*;
 use crate::errors::*;
 use crate::state::*;
+use crate::utils::*;
 
 #[derive(Accounts)]
 pub struct MintPreset<'info> {
@@ -21,10 +36,13 @@ pub struct MintPreset<'info> {
       bump = minter.bump,
       has_one = fee_payer @ TokenGatorMinterError::UnAuthorized,
       constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
-      constraint = minter.minter_config.mint.eq(&mint.key()) @ TokenGatorMinterError::InvalidMint
+      constraint = minter.minter_config.mint.eq(&mint.key()) @ TokenGatorMinterError::InvalidMint,
+      constraint = minter.group.eq(&mint.key()) @ TokenGatorMinterError::GroupNotMigrated,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
     )]
     pub minter: Account<'info, Minter>,
 
+    // Collection mint holding the native group
     #[account(
       mut,
       mint::authority = minter,
@@ -33,23 +51,31 @@ pub struct MintPreset<'info> {
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(
-      init,
-      payer = fee_payer,
-      associated_token::mint = mint,
-      associated_token::authority = authority,
-      associated_token::token_program = token_program
-    )]
-    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub member_mint: Signer<'info>,
+
+    /// CHECK: Checks done inside the handler function
+    #[account(mut)]
+    pub authority_token_account: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub fee_payer: Signer<'info>,
     pub authority: Signer<'info>,
 
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     #[account(
       constraint = token_program.key().eq(&TOKEN_EXTENSIONS_PROGRAM_ID) @ TokenGatorMinterError::InvalidTokenProgram
     )]
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -57,14 +83,105 @@ pub struct MintPreset<'info> {
 pub fn mint(ctx: Context<MintPreset>) -> Result<()> {
     let minter = &ctx.accounts.minter;
 
+    let authority = &ctx.accounts.authority;
+    let fee_payer = &ctx.accounts.fee_payer;
     let mint = &ctx.accounts.mint;
-    let destination_token_account = &ctx.accounts.authority_token_account;
+    let member_mint = &ctx.accounts.member_mint;
+    let authority_token_account = &ctx.accounts.authority_token_account;
+
     let token_extensions_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let associated_token_program = &ctx.accounts.associated_token_program;
+
+    let minter_key = minter.key();
+    let member_mint_key = member_mint.key();
+
+    let MinterMetadataConfig {
+        name, symbol, uri, ..
+    } = minter.minter_config.metadata_config.clone();
 
-    let amount_with_decimals = 1u64
-        .checked_mul(10u64.checked_pow(mint.decimals.into()).unwrap())
-        .unwrap();
+    // 1. Creating member Mint account
+    let mut mint_extension_types = vec![
+        ExtensionType::MintCloseAuthority,
+        ExtensionType::MetadataPointer,
+        ExtensionType::GroupMemberPointer,
+    ];
 
+    if !minter.is_transferable() {
+        mint_extension_types.push(ExtensionType::NonTransferable);
+    }
+
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(minter_key)).unwrap(),
+        mint: member_mint_key,
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    };
+
+    let mint_size = ExtensionType::try_calculate_account_len::<MintState>(&mint_extension_types)?;
+    let metadata_size = metadata.tlv_size_of()?;
+    let member_size = TlvStateBorrowed::get_base_len() + std::mem::size_of::<TokenGroupMember>();
+
+    let rent_lamports = Rent::get()?.minimum_balance(mint_size + metadata_size + member_size);
+
+    system_program::create_account(
+        CpiContext::new(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: fee_payer.to_account_info(),
+                to: member_mint.to_account_info(),
+            },
+        ),
+        rent_lamports,
+        u64::try_from(mint_size).unwrap(),
+        token_extensions_program.key,
+    )?;
+
+    // 2. Initializing extensions
+    initialize_mint_close_authority(
+        CpiContext::new(
+            token_extensions_program.to_account_info(),
+            InitializeMintCloseAuthority {
+                mint: member_mint.to_account_info(),
+            },
+        ),
+        Some(&minter_key),
+    )?;
+
+    if !minter.is_transferable() {
+        initialize_mint_non_transferable(CpiContext::new(
+            token_extensions_program.to_account_info(),
+            InitializeMintNonTransferrable {
+                mint: member_mint.to_account_info(),
+            },
+        ))?;
+    }
+
+    initialize_metadata_pointer(
+        CpiContext::new(
+            token_extensions_program.to_account_info(),
+            InitializeMetadataPointer {
+                mint: member_mint.to_account_info(),
+            },
+        ),
+        Some(minter_key),
+        Some(member_mint_key),
+    )?;
+
+    initialize_group_member_pointer(
+        CpiContext::new(
+            token_extensions_program.to_account_info(),
+            InitializeGroupMemberPointer {
+                mint: member_mint.to_account_info(),
+            },
+        ),
+        Some(minter_key),
+        Some(member_mint_key),
+    )?;
+
+    // 3. Initializing member mint
     let signer_seeds: &[&[&[u8]]] = &[&[
         PREFIX,
         MINTER,
@@ -73,17 +190,85 @@ pub fn mint(ctx: Context<MintPreset>) -> Result<()> {
         &[minter.bump],
     ]];
 
+    initialize_mint2(
+        CpiContext::new_with_signer(
+            token_extensions_program.to_account_info(),
+            InitializeMint2 {
+                mint: member_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        0,
+        &minter_key,
+        Some(&minter_key),
+    )?;
+
+    // 4. Initializing metadata
+    intialize_metadata(
+        CpiContext::new_with_signer(
+            token_extensions_program.to_account_info(),
+            InitializeMetadata {
+                metadata: member_mint.to_account_info(),
+                mint: member_mint.to_account_info(),
+                mint_authority: minter.to_account_info(),
+                update_authority: minter.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
+
+    // 5. Adding member to the group
+    initialize_member(CpiContext::new_with_signer(
+        token_extensions_program.to_account_info(),
+        InitializeMember {
+            member: member_mint.to_account_info(),
+            member_mint: member_mint.to_account_info(),
+            member_mint_authority: minter.to_account_info(),
+            group: mint.to_account_info(),
+            group_update_authority: minter.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    // 6. Minting member NFT to the authority
+    let expected_authority_token_account = get_associated_token_address_with_program_id(
+        &authority.key(),
+        &member_mint_key,
+        token_extensions_program.key,
+    );
+
+    require_eq!(
+        expected_authority_token_account,
+        authority_token_account.key(),
+        TokenGatorMinterError::InvalidTokenAccount
+    );
+
+    create_associated_token(CpiContext::new(
+        associated_token_program.to_account_info(),
+        CreateAssociatedToken {
+            associated_token: authority_token_account.to_account_info(),
+            authority: authority.to_account_info(),
+            mint: member_mint.to_account_info(),
+            payer: fee_payer.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_extensions_program.to_account_info(),
+        },
+    ))?;
+
     mint_to(
         CpiContext::new_with_signer(
             token_extensions_program.to_account_info(),
             MintTo {
-                mint: mint.to_account_info(),
+                mint: member_mint.to_account_info(),
                 authority: minter.to_account_info(),
-                to: destination_token_account.to_account_info(),
+                to: authority_token_account.to_account_info(),
             },
             signer_seeds,
         ),
-        amount_with_decimals,
+        1,
     )?;
 
     Ok(())
@@ -1,10 +1,12 @@
 pub mod create;
+pub mod migrate;
 pub mod mint;
 pub mod prepare;
 pub mod remove;
 pub mod update;
 
 pub use create::*;
+pub use migrate::*;
 pub use mint::*;
 pub use prepare::*;
 pub use remove::*;
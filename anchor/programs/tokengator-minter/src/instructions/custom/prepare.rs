@@ -6,6 +6,7 @@ use anchor_spl::{
 };
 
 use crate::constants::*;
+use crate::errors::*;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -46,7 +47,36 @@ pub struct PrepareForPayment<'info> {
     )]
     pub receiver_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+      constraint = protocol_config.is_payment_mint_allowed(&mint.key()) @ TokenGatorMinterError::PaymentMintNotAllowed
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      has_one = treasury @ TokenGatorMinterError::InvalidTreasury,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+      init_if_needed,
+      payer = sender,
+      associated_token::mint = mint,
+      associated_token::authority = treasury,
+      associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub coupon: Option<Account<'info, Coupon>>,
+
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
@@ -60,11 +90,28 @@ pub fn prepare(ctx: Context<PrepareForPayment>, args: PrepareForPaymentArgs) ->
     let receiver_token_account = &ctx.accounts.receiver_token_account;
     let sender_token_account = &ctx.accounts.sender_token_account;
     let mint = &ctx.accounts.mint;
+    let protocol_config = &ctx.accounts.protocol_config;
+    let treasury_token_account = &ctx.accounts.treasury_token_account;
 
     let receipt = &mut ctx.accounts.receipt;
 
     let created_at = Clock::get()?.unix_timestamp;
 
+    // Redeemed here so the receipt holds its coupon use until the badge is minted
+    let coupon_key = if let Some(coupon) = &mut ctx.accounts.coupon {
+        coupon.check(&sender.key(), &args.coupon_code, created_at)?;
+        coupon.redeem(created_at)?;
+        Some(coupon.key())
+    } else {
+        None
+    };
+
+    // Community payments are the minter creation fee and go to the treasury in full
+    let protocol_fee = match args.payment_type {
+        ReceiptType::User => protocol_config.mint_fee(args.payment_amount),
+        ReceiptType::Community => args.payment_amount,
+    };
+
     receipt.set_inner(Receipt {
         bump: ctx.bumps.receipt,
         payment_type: args.payment_type,
@@ -75,6 +122,9 @@ pub fn prepare(ctx: Context<PrepareForPayment>, args: PrepareForPaymentArgs) ->
         sender_token_account: sender_token_account.key(),
         receiver_token_account: receiver_token_account.key(),
         payment_mint: mint.key(),
+        payment_tier: args.payment_tier.unwrap_or(0),
+        coupon: coupon_key,
+        protocol_fee,
     });
 
     transfer_checked(
@@ -87,10 +137,26 @@ pub fn prepare(ctx: Context<PrepareForPayment>, args: PrepareForPaymentArgs) ->
                 to: receiver_token_account.to_account_info(),
             },
         ),
-        args.payment_amount,
+        args.payment_amount.checked_sub(protocol_fee).unwrap(),
         mint.decimals,
     )?;
 
+    if protocol_fee > 0 {
+        transfer_checked(
+            CpiContext::new(
+                token_extensions_program.to_account_info(),
+                TransferChecked {
+                    authority: sender.to_account_info(),
+                    mint: mint.to_account_info(),
+                    from: sender_token_account.to_account_info(),
+                    to: treasury_token_account.to_account_info(),
+                },
+            ),
+            protocol_fee,
+            mint.decimals,
+        )?;
+    }
+
     Ok(())
 }
 
@@ -98,4 +164,8 @@ pub fn prepare(ctx: Context<PrepareForPayment>, args: PrepareForPaymentArgs) ->
 pub struct PrepareForPaymentArgs {
     pub payment_amount: u64,
     pub payment_type: ReceiptType,
+    // Index into the minter payment tiers, only used for user receipts
+    pub payment_tier: Option<u8>,
+    // Plain coupon code, required for coupons not bound to the sender
+    pub coupon_code: Option<String>,
 }
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_2022::{close_account, CloseAccount, ID as TOKEN_EXTENSIONS_PROGRAM_ID},
-    token_interface::{Mint, Token2022},
+    token_interface::{Mint, TokenInterface},
 };
 
 use crate::constants::*;
@@ -22,7 +22,8 @@ pub struct RemoveMinter<'info> {
       bump = minter.bump,
       has_one = fee_payer @ TokenGatorMinterError::UnAuthorized,
       constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
-      constraint = minter.minter_config.mint.eq(&mint.key()) @ TokenGatorMinterError::InvalidMint
+      constraint = minter.minter_config.mint.eq(&mint.key()) @ TokenGatorMinterError::InvalidMint,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
     )]
     pub minter: Account<'info, Minter>,
 
@@ -39,10 +40,20 @@ pub struct RemoveMinter<'info> {
     pub fee_payer: Signer<'info>,
     pub authority: Signer<'info>,
 
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     #[account(
       constraint = token_program.key().eq(&TOKEN_EXTENSIONS_PROGRAM_ID) @ TokenGatorMinterError::InvalidTokenProgram
     )]
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+// Permissionless, the token accounts to harvest from are passed as remaining accounts
+#[derive(Accounts)]
+pub struct HarvestTransferFees<'info> {
+    #[account(
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      constraint = minter.minter_config.mint.eq(&mint.key()) @ TokenGatorMinterError::InvalidMint,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
+    )]
+    pub minter: Account<'info, Minter>,
+
+    #[account(
+      mut,
+      mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn harvest<'info>(ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty(),
+        TokenGatorMinterError::InvalidTokenAccount
+    );
+
+    harvest_withheld_tokens_to_mint(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            HarvestWithheldTokensToMint {
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+    )
+}
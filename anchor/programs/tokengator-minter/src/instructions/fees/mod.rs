@@ -0,0 +1,7 @@
+pub mod harvest;
+pub mod update;
+pub mod withdraw;
+
+pub use harvest::*;
+pub use update::*;
+pub use withdraw::*;
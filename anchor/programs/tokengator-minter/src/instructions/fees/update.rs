@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct UpdateTransferFee<'info> {
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
+      constraint = minter.minter_config.mint.eq(&mint.key()) @ TokenGatorMinterError::InvalidMint,
+      constraint = minter.minter_config.transfer_fee_config.is_some() @ TokenGatorMinterError::MintExtensionNotEnabled,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
+    )]
+    pub minter: Account<'info, Minter>,
+
+    #[account(
+      mut,
+      mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn update(ctx: Context<UpdateTransferFee>, args: UpdateTransferFeeArgs) -> Result<()> {
+    let minter = &mut ctx.accounts.minter;
+
+    // The new fee only takes effect on the mint two epochs later
+    minter.minter_config.transfer_fee_config = Some(MinterTransferFeeConfig {
+        transfer_fee_basis_points: args.transfer_fee_basis_points,
+        max_fee_rate: args.max_fee_rate,
+    });
+
+    minter.validate()?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes(),
+        &[minter.bump],
+    ]];
+
+    set_transfer_fee(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetTransferFee {
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: minter.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        args.transfer_fee_basis_points,
+        args.max_fee_rate,
+    )
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateTransferFeeArgs {
+    pub transfer_fee_basis_points: u16,
+    pub max_fee_rate: u64,
+}
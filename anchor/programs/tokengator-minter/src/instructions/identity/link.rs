@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(args: LinkIdentityArgs)]
+pub struct LinkIdentity<'info> {
+    #[account(
+      init,
+      space = IdentityLink::size(&args.provider),
+      payer = fee_payer,
+      seeds = [
+        PREFIX,
+        IDENTITY_LINK,
+        minter.key().as_ref(),
+        wallet.key().as_ref(),
+        args.provider.seed(),
+        args.provider.value_seed(),
+      ],
+      bump
+    )]
+    pub identity_link: Account<'info, IdentityLink>,
+
+    // Fails to initialize when the provider account already backs another member of the minter
+    #[account(
+      init,
+      space = IdentityClaim::size(),
+      payer = fee_payer,
+      seeds = [
+        PREFIX,
+        IDENTITY_CLAIM,
+        minter.key().as_ref(),
+        args.provider.seed(),
+        args.provider.value_seed(),
+        args.id_hash.as_ref(),
+      ],
+      bump
+    )]
+    pub identity_claim: Account<'info, IdentityClaim>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
+    )]
+    pub minter: Account<'info, Minter>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused,
+      constraint = protocol_config.identity_verifier.eq(&verifier.key()) @ TokenGatorMinterError::InvalidIdentityLink
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub wallet: Signer<'info>,
+    pub verifier: Signer<'info>,
+
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn link(ctx: Context<LinkIdentity>, args: LinkIdentityArgs) -> Result<()> {
+    args.provider.validate()?;
+
+    let identity_link = &mut ctx.accounts.identity_link;
+    let identity_claim = &mut ctx.accounts.identity_claim;
+
+    identity_link.set_inner(IdentityLink {
+        bump: ctx.bumps.identity_link,
+        minter: ctx.accounts.minter.key(),
+        wallet: ctx.accounts.wallet.key(),
+        provider: args.provider,
+        id_hash: args.id_hash,
+        verifier: ctx.accounts.verifier.key(),
+        verified_at: Clock::get()?.unix_timestamp,
+        fee_payer: ctx.accounts.fee_payer.key(),
+    });
+
+    identity_claim.set_inner(IdentityClaim {
+        bump: ctx.bumps.identity_claim,
+        identity_link: identity_link.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LinkIdentityArgs {
+    pub provider: IdentityProvider,
+    pub id_hash: [u8; 32],
+}
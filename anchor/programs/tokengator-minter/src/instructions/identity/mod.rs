@@ -0,0 +1,5 @@
+pub mod link;
+pub mod unlink;
+
+pub use link::*;
+pub use unlink::*;
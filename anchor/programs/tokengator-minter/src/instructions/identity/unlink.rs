@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UnlinkIdentity<'info> {
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        IDENTITY_LINK,
+        identity_link.minter.as_ref(),
+        identity_link.wallet.as_ref(),
+        identity_link.provider.seed(),
+        identity_link.provider.value_seed(),
+      ],
+      bump = identity_link.bump,
+      has_one = fee_payer @ TokenGatorMinterError::InvalidFeePayer,
+      // Either the member or the verifier can drop the link
+      constraint = identity_link.wallet.eq(&authority.key()) || protocol_config.identity_verifier.eq(&authority.key()) @ TokenGatorMinterError::UnAuthorized
+    )]
+    pub identity_link: Account<'info, IdentityLink>,
+
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        IDENTITY_CLAIM,
+        identity_link.minter.as_ref(),
+        identity_link.provider.seed(),
+        identity_link.provider.value_seed(),
+        identity_link.id_hash.as_ref(),
+      ],
+      bump = identity_claim.bump,
+      has_one = identity_link @ TokenGatorMinterError::InvalidIdentityLink
+    )]
+    pub identity_claim: Account<'info, IdentityClaim>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+}
+
+pub fn unlink(ctx: Context<UnlinkIdentity>) -> Result<()> {
+    let fee_payer = &ctx.accounts.fee_payer;
+
+    ctx.accounts
+        .identity_claim
+        .close(fee_payer.to_account_info())?;
+    ctx.accounts
+        .identity_link
+        .close(fee_payer.to_account_info())?;
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod update;
+
+pub use update::*;
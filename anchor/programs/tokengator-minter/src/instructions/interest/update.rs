@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct UpdateInterestRate<'info> {
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
+      constraint = minter.minter_config.mint.eq(&mint.key()) @ TokenGatorMinterError::InvalidMint,
+      constraint = minter.minter_config.interest_config.is_some() @ TokenGatorMinterError::MintExtensionNotEnabled,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
+    )]
+    pub minter: Account<'info, Minter>,
+
+    #[account(
+      mut,
+      mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn update(ctx: Context<UpdateInterestRate>, args: UpdateInterestRateArgs) -> Result<()> {
+    let minter = &mut ctx.accounts.minter;
+
+    minter.minter_config.interest_config = Some(MinterInterestConfig { rate: args.rate });
+
+    minter.validate()?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes(),
+        &[minter.bump],
+    ]];
+
+    update_rate_interest_bearing_mint(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            UpdateRateInterestBearingMint {
+                mint: ctx.accounts.mint.to_account_info(),
+                rate_authority: minter.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        args.rate,
+    )
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateInterestRateArgs {
+    pub rate: i16,
+}
@@ -1,9 +1,23 @@
 pub mod activity;
 pub mod authority;
+pub mod coupon;
 pub mod custom;
+pub mod fees;
+pub mod identity;
+pub mod interest;
+pub mod pause;
+pub mod protocol;
+pub mod subscription;
 pub mod wns;
 
 pub use activity::*;
 pub use authority::*;
+pub use coupon::*;
 pub use custom::*;
+pub use fees::*;
+pub use identity::*;
+pub use interest::*;
+pub use pause::*;
+pub use protocol::*;
+pub use subscription::*;
 pub use wns::*;
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+use super::SetPausedArgs;
+
+#[derive(Accounts)]
+pub struct SetMinterPaused<'info> {
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized
+    )]
+    pub minter: Account<'info, Minter>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_minter(ctx: Context<SetMinterPaused>, args: SetPausedArgs) -> Result<()> {
+    let minter = &mut ctx.accounts.minter;
+
+    minter.paused = args.paused;
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod minter;
+pub mod protocol;
+
+pub use minter::*;
+pub use protocol::*;
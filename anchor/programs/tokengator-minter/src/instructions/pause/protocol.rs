@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetProtocolPaused<'info> {
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      has_one = admin @ TokenGatorMinterError::UnAuthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn set_protocol(ctx: Context<SetProtocolPaused>, args: SetPausedArgs) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+
+    protocol_config.paused = args.paused;
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPausedArgs {
+    pub paused: bool,
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::program::TokengatorMinter;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(args: InitializeProtocolConfigArgs)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(
+      init,
+      space = ProtocolConfig::size(&args.allowed_payment_mints),
+      payer = admin,
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+      constraint = program.programdata_address()? == Some(program_data.key()) @ TokenGatorMinterError::UnAuthorized
+    )]
+    pub program: Program<'info, TokengatorMinter>,
+
+    // Only the upgrade authority can bootstrap the protocol config
+    #[account(
+      constraint = program_data.upgrade_authority_address == Some(admin.key()) @ TokenGatorMinterError::UnAuthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize(
+    ctx: Context<InitializeProtocolConfig>,
+    args: InitializeProtocolConfigArgs,
+) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    let admin = &ctx.accounts.admin;
+
+    protocol_config.set_inner(ProtocolConfig {
+        bump: ctx.bumps.protocol_config,
+        admin: admin.key(),
+        treasury: args.treasury,
+        community_fee: args.community_fee,
+        mint_fee_basis_points: args.mint_fee_basis_points,
+        allowed_payment_mints: args.allowed_payment_mints,
+        paused: false,
+        identity_verifier: args.identity_verifier,
+    });
+
+    protocol_config.validate()?;
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeProtocolConfigArgs {
+    pub treasury: Pubkey,
+    pub community_fee: u64,
+    pub mint_fee_basis_points: u16,
+    pub allowed_payment_mints: Vec<Pubkey>,
+    pub identity_verifier: Pubkey,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      has_one = admin @ TokenGatorMinterError::UnAuthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update(ctx: Context<UpdateProtocolConfig>, args: UpdateProtocolConfigArgs) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    let admin = &ctx.accounts.admin;
+    let system_program = &ctx.accounts.system_program;
+
+    if let Some(new_admin) = args.admin {
+        protocol_config.admin = new_admin;
+    }
+
+    if let Some(treasury) = args.treasury {
+        protocol_config.treasury = treasury;
+    }
+
+    if let Some(community_fee) = args.community_fee {
+        protocol_config.community_fee = community_fee;
+    }
+
+    if let Some(mint_fee_basis_points) = args.mint_fee_basis_points {
+        protocol_config.mint_fee_basis_points = mint_fee_basis_points;
+    }
+
+    if let Some(identity_verifier) = args.identity_verifier {
+        protocol_config.identity_verifier = identity_verifier;
+    }
+
+    if let Some(allowed_payment_mints) = args.allowed_payment_mints {
+        protocol_config.allowed_payment_mints = allowed_payment_mints;
+
+        let new_protocol_config_size = ProtocolConfig::size(&protocol_config.allowed_payment_mints);
+        realloc_account(
+            protocol_config.to_account_info(),
+            new_protocol_config_size,
+            admin.to_account_info(),
+            system_program.to_account_info(),
+        )?;
+    }
+
+    protocol_config.validate()?;
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateProtocolConfigArgs {
+    pub admin: Option<Pubkey>,
+    pub treasury: Option<Pubkey>,
+    pub community_fee: Option<u64>,
+    pub mint_fee_basis_points: Option<u16>,
+    pub allowed_payment_mints: Option<Vec<Pubkey>>,
+    pub identity_verifier: Option<Pubkey>,
+}
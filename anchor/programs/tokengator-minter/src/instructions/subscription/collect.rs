@@ -0,0 +1,202 @@
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{transfer_checked, TransferChecked, ID as TOKEN_EXTENSIONS_PROGRAM_ID},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct CollectSubscription<'info> {
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        SUBSCRIPTION,
+        mint.key().as_ref(),
+      ],
+      bump = subscription.bump,
+      has_one = minter @ TokenGatorMinterError::InvalidSubscription,
+      has_one = mint @ TokenGatorMinterError::InvalidSubscription,
+      has_one = payment_mint @ TokenGatorMinterError::InvalidSubscription,
+      has_one = payment_token_account @ TokenGatorMinterError::InvalidSubscription,
+      has_one = receiver_token_account @ TokenGatorMinterError::InvalidSubscription,
+      has_one = fee_payer @ TokenGatorMinterError::InvalidFeePayer,
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused,
+    )]
+    pub minter: Account<'info, Minter>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Renewals stop once the subscriber no longer holds the badge
+    #[account(
+      associated_token::mint = mint,
+      associated_token::authority = subscription.subscriber,
+      associated_token::token_program = token_program,
+    )]
+    pub member_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub receiver_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Anyone can crank a due subscription
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Checked against the subscription, receives its rent on cancellation
+    pub fee_payer: UncheckedAccount<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      has_one = treasury @ TokenGatorMinterError::InvalidTreasury,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+      init_if_needed,
+      payer = payer,
+      associated_token::mint = payment_mint,
+      associated_token::authority = treasury,
+      associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      constraint = token_program.key().eq(&TOKEN_EXTENSIONS_PROGRAM_ID) @ TokenGatorMinterError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn collect(ctx: Context<CollectSubscription>) -> Result<()> {
+    let minter = &ctx.accounts.minter;
+    let mint = &ctx.accounts.mint;
+    let payer = &ctx.accounts.payer;
+    let fee_payer = &ctx.accounts.fee_payer;
+    let payment_mint = &ctx.accounts.payment_mint;
+    let payment_token_account = &ctx.accounts.payment_token_account;
+    let receiver_token_account = &ctx.accounts.receiver_token_account;
+    let member_token_account = &ctx.accounts.member_token_account;
+    let protocol_config = &ctx.accounts.protocol_config;
+    let treasury_token_account = &ctx.accounts.treasury_token_account;
+
+    let token_extensions_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    let subscription = &mut ctx.accounts.subscription;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        subscription.is_due(now),
+        TokenGatorMinterError::SubscriptionNotDue
+    );
+
+    // 1. Cancelling when the badge was given up, the approval was revoked or the balance is insufficient
+    let can_collect = member_token_account.amount == 1
+        && payment_token_account.delegate == COption::Some(minter.key())
+        && payment_token_account.delegated_amount >= subscription.price
+        && payment_token_account.amount >= subscription.price;
+
+    if !can_collect {
+        return subscription.close(fee_payer.to_account_info());
+    }
+
+    // 2. Collecting the next period
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes(),
+        &[minter.bump],
+    ]];
+
+    // Renewals are user payments, charged the protocol fee like prepared ones
+    let protocol_fee = protocol_config.mint_fee(subscription.price);
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_extensions_program.to_account_info(),
+            TransferChecked {
+                authority: minter.to_account_info(),
+                mint: payment_mint.to_account_info(),
+                from: payment_token_account.to_account_info(),
+                to: receiver_token_account.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        subscription.price.checked_sub(protocol_fee).unwrap(),
+        payment_mint.decimals,
+    )?;
+
+    if protocol_fee > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_extensions_program.to_account_info(),
+                TransferChecked {
+                    authority: minter.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    from: payment_token_account.to_account_info(),
+                    to: treasury_token_account.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            protocol_fee,
+            payment_mint.decimals,
+        )?;
+    }
+
+    // 3. Extending the membership
+    let expires_at = subscription.extend(now);
+
+    // Updated directly so the minter does not have to be writable, as the WNS instruction demands
+    update_metadata_field(
+        CpiContext::new_with_signer(
+            token_extensions_program.to_account_info(),
+            UpdateMetadataField {
+                metadata: mint.to_account_info(),
+                update_authority: minter.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        "expires_at".to_owned(),
+        expires_at.to_string(),
+    )?;
+
+    fund_rent_exemption(
+        mint.to_account_info(),
+        payer.to_account_info(),
+        system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{approve, Approve, ID as TOKEN_EXTENSIONS_PROGRAM_ID},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use wen_new_standard::{TokenGroup, TokenGroupMember};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+#[instruction(args: CreateSubscriptionArgs)]
+pub struct CreateSubscription<'info> {
+    #[account(
+      init,
+      space = Subscription::size(),
+      payer = fee_payer,
+      seeds = [
+        PREFIX,
+        SUBSCRIPTION,
+        mint.key().as_ref(),
+      ],
+      bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      has_one = fee_payer @ TokenGatorMinterError::InvalidFeePayer,
+      has_one = group @ TokenGatorMinterError::InvalidWNSGroup,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused,
+    )]
+    pub minter: Account<'info, Minter>,
+
+    #[account(
+      constraint = group.update_authority.eq(&minter.key()) @ TokenGatorMinterError::UnAuthorized
+    )]
+    pub group: Account<'info, TokenGroup>,
+
+    #[account(
+      has_one = group @ TokenGatorMinterError::InvalidWNSGroup,
+      has_one = mint @ TokenGatorMinterError::InvalidWNSMember
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+      token::mint = mint,
+      token::authority = subscriber,
+      constraint = subscriber_token_account.amount == 1 @ TokenGatorMinterError::InvalidSubscription
+    )]
+    pub subscriber_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      mut,
+      token::mint = payment_mint,
+      token::authority = subscriber,
+    )]
+    pub payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+      token::mint = payment_mint,
+      constraint = minter.check_for_authority(&receiver_token_account.owner) @ TokenGatorMinterError::InvalidReceiver
+    )]
+    pub receiver_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub subscriber: Signer<'info>,
+
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+      constraint = token_program.key().eq(&TOKEN_EXTENSIONS_PROGRAM_ID) @ TokenGatorMinterError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create(ctx: Context<CreateSubscription>, args: CreateSubscriptionArgs) -> Result<()> {
+    let minter = &ctx.accounts.minter;
+    let mint = &ctx.accounts.mint;
+    let subscriber = &ctx.accounts.subscriber;
+    let fee_payer = &ctx.accounts.fee_payer;
+    let payment_mint = &ctx.accounts.payment_mint;
+    let payment_token_account = &ctx.accounts.payment_token_account;
+    let receiver_token_account = &ctx.accounts.receiver_token_account;
+    let token_extensions_program = &ctx.accounts.token_program;
+
+    let payment_tier = minter.get_payment_tier(args.payment_tier)?;
+
+    require_keys_eq!(
+        payment_tier.mint,
+        payment_mint.key(),
+        TokenGatorMinterError::InvalidMint
+    );
+
+    // Lifetime memberships have nothing to renew
+    let days = payment_tier
+        .days
+        .ok_or(TokenGatorMinterError::InvalidSubscription)?;
+
+    let expires_at = get_metadata_field(&mint.to_account_info(), "expires_at")?
+        .and_then(|expires_at| expires_at.parse::<i64>().ok())
+        .ok_or(TokenGatorMinterError::InvalidSubscription)?;
+
+    require!(args.periods > 0, TokenGatorMinterError::InvalidSubscription);
+
+    let approved_amount = payment_tier
+        .price
+        .checked_mul(u64::from(args.periods))
+        .unwrap();
+
+    // 1. Approving the minter to pull the renewals
+    approve(
+        CpiContext::new(
+            token_extensions_program.to_account_info(),
+            Approve {
+                to: payment_token_account.to_account_info(),
+                delegate: minter.to_account_info(),
+                authority: subscriber.to_account_info(),
+            },
+        ),
+        approved_amount,
+    )?;
+
+    // 2. Saving subscription onchain
+    let subscription = &mut ctx.accounts.subscription;
+
+    subscription.set_inner(Subscription {
+        bump: ctx.bumps.subscription,
+        minter: minter.key(),
+        mint: mint.key(),
+        subscriber: subscriber.key(),
+        payment_tier: args.payment_tier,
+        payment_mint: payment_mint.key(),
+        payment_token_account: payment_token_account.key(),
+        receiver_token_account: receiver_token_account.key(),
+        price: payment_tier.price,
+        days,
+        expires_at,
+        fee_payer: fee_payer.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSubscriptionArgs {
+    pub payment_tier: u8,
+    // Number of renewals the minter is approved to collect
+    pub periods: u16,
+}
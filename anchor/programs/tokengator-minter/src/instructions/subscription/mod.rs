@@ -0,0 +1,5 @@
+pub mod collect;
+pub mod create;
+
+pub use collect::*;
+pub use create::*;
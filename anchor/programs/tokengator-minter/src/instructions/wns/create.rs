@@ -1,17 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::{
-        create as create_associated_token, get_associated_token_address_with_program_id,
-        AssociatedToken, Create as CreateAssociatedToken,
-    },
-    token_2022::{transfer_checked, Token2022, TransferChecked},
+    associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
+    token_2022::Token2022,
     token_interface::{Mint, TokenAccount},
 };
 use wen_new_standard::{
-    cpi::{
-        accounts::{AddMetadata, CreateGroupAccount},
-        add_metadata, create_group_account,
-    },
+    cpi::{accounts::CreateGroupAccount, create_group_account},
     program::WenNewStandard,
     AddMetadataArgs, CreateGroupAccountArgs,
 };
@@ -47,14 +41,20 @@ pub struct CreateMinterWNS<'info> {
       constraint = receipt.receiver.eq(&authority.key()) @ TokenGatorMinterError::InvalidAuthority,
       constraint = receipt.receiver_token_account.eq(&authority_token_account.key()) @ TokenGatorMinterError::InvalidAuthority,
       has_one = payment_mint @ TokenGatorMinterError::InvalidMint,
-      constraint = receipt.payment_amount.eq(&args.payment_config.price) @ TokenGatorMinterError::InvalidReceipt
+      constraint = receipt.payment_amount.eq(&protocol_config.community_fee) @ TokenGatorMinterError::InvalidProtocolFee,
+      constraint = receipt.protocol_fee.eq(&receipt.payment_amount) @ TokenGatorMinterError::InvalidProtocolFee
     )]
     pub receipt: Account<'info, Receipt>,
 
     #[account(
       init,
       payer = fee_payer,
-      space = Minter::size(&[authority.key()], &args.application_config, &args.metadata_config),
+      space = Minter::size(
+        &[authority.key()],
+        &args.application_config.identities,
+        &args.application_config.payment_tiers,
+        &args.metadata_config
+      ),
       seeds = [
         PREFIX,
         MINTER,
@@ -80,18 +80,24 @@ pub struct CreateMinterWNS<'info> {
     pub authority: Signer<'info>,
 
     #[account(
-      mut,
       token::authority = authority,
       token::mint = payment_mint,
     )]
     pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: PDA checks done below
-    #[account(mut)]
-    pub fee_payer_token_account: UncheckedAccount<'info>,
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
 
     #[account(
-      constraint = payment_mint.to_account_info().owner.eq(&token_program.key())
+      constraint = payment_mint.to_account_info().owner.eq(&token_program.key()),
+      constraint = protocol_config.is_payment_mint_allowed(&payment_mint.key()) @ TokenGatorMinterError::PaymentMintNotAllowed
     )]
     pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
 
@@ -131,54 +137,12 @@ pub fn create(ctx: Context<CreateMinterWNS>, args: CreateMinterWNSArgs) -> Resul
         interest_config,
         application_config,
         payment_config,
+        transferable,
+        website,
+        royalty_basis_points,
         ..
     } = args;
 
-    // Transfer payment for minter creation
-    let expected_fee_payer_token_account = get_associated_token_address_with_program_id(
-        &ctx.accounts.fee_payer.key(),
-        &ctx.accounts.payment_mint.key(),
-        &ctx.accounts.token_program.key(),
-    );
-
-    require_eq!(
-        expected_fee_payer_token_account,
-        ctx.accounts.fee_payer_token_account.key()
-    );
-
-    if ctx
-        .accounts
-        .fee_payer_token_account
-        .to_account_info()
-        .data_is_empty()
-    {
-        create_associated_token(CpiContext::new(
-            ctx.accounts.associated_token_program.to_account_info(),
-            CreateAssociatedToken {
-                payer: ctx.accounts.fee_payer.to_account_info(),
-                associated_token: ctx.accounts.fee_payer_token_account.to_account_info(),
-                authority: ctx.accounts.fee_payer.to_account_info(),
-                mint: ctx.accounts.payment_mint.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-            },
-        ))?;
-    }
-
-    transfer_checked(
-        CpiContext::new(
-            token_extensions_program.to_account_info(),
-            TransferChecked {
-                authority: ctx.accounts.authority.to_account_info(),
-                from: ctx.accounts.authority_token_account.to_account_info(),
-                to: ctx.accounts.fee_payer_token_account.to_account_info(),
-                mint: ctx.accounts.payment_mint.to_account_info(),
-            },
-        ),
-        payment_config.price.into(),
-        ctx.accounts.payment_mint.decimals,
-    )?;
-
     let expected_minter_token_account = get_associated_token_address_with_program_id(
         &minter_key,
         &mint_key,
@@ -203,17 +167,14 @@ pub fn create(ctx: Context<CreateMinterWNS>, args: CreateMinterWNSArgs) -> Resul
             .unwrap(),
     };
 
-    let application_payment_config = PaymentConfig {
-        price: application_config.payment_config.price,
-        amount: application_config.payment_config.amount,
-        mint: application_config.payment_config.mint,
-        days: application_config.payment_config.days,
-        expires_at: 0,
-    };
+    let MinterApplicationConfigArgs {
+        identities,
+        payment_tiers,
+    } = application_config;
 
     let application_config = MinterApplicationConfig {
-        identities: application_config.identities,
-        payment_config: application_payment_config,
+        identities,
+        payment_config: PaymentConfig::default(),
     };
 
     // 1. Saving Minter onchain
@@ -236,11 +197,65 @@ pub fn create(ctx: Context<CreateMinterWNS>, args: CreateMinterWNSArgs) -> Resul
         authorities: vec![authority.key()],
         minter_config,
         payment_config,
+        paused: false,
+        payment_tiers,
+        transferable: TrailingBool(transferable),
     });
 
+    // Only minters created before payment tiers fall back to their payment config
+    require!(
+        !minter.payment_tiers.is_empty(),
+        TokenGatorMinterError::InvalidPaymentTier
+    );
+
     minter.validate()?;
 
-    // 2. Creating WNS group
+    // 2. Creating WNS group with the minter details as group level metadata
+    let mut additional_metadata = vec![
+        AddMetadataArgs {
+            field: "description".to_owned(),
+            value: minter.description.clone(),
+        },
+        AddMetadataArgs {
+            field: "image_url".to_owned(),
+            value: minter.image_url.clone(),
+        },
+        AddMetadataArgs {
+            field: "community".to_owned(),
+            value: community_id.to_string(),
+        },
+    ];
+
+    if let Some(website) = website {
+        require!(
+            is_valid_url(&website) && website.len() <= MAX_WEBSITE_SIZE,
+            TokenGatorMinterError::InvalidWebsite
+        );
+        additional_metadata.push(AddMetadataArgs {
+            field: "website".to_owned(),
+            value: website,
+        });
+    }
+
+    // Only a default for the community, WNS keeps the royalties each member mint carries
+    if let Some(royalty_basis_points) = royalty_basis_points {
+        require!(
+            royalty_basis_points <= MAX_BASIS_POINTS,
+            TokenGatorMinterError::InvalidRoyaltyBasisPoints
+        );
+        additional_metadata.push(AddMetadataArgs {
+            field: "default_royalty_basis_points".to_owned(),
+            value: royalty_basis_points.to_string(),
+        });
+    }
+
+    if let Some(metadata) = metadata_config.metadata {
+        additional_metadata.extend(metadata.iter().map(|m| AddMetadataArgs {
+            field: m[0].clone(),
+            value: m[1].clone(),
+        }));
+    }
+
     let signer_seeds: &[&[&[u8]]] = &[&[
         PREFIX,
         MINTER,
@@ -271,37 +286,12 @@ pub fn create(ctx: Context<CreateMinterWNS>, args: CreateMinterWNSArgs) -> Resul
             name: metadata_config.name,
             symbol: metadata_config.symbol,
             uri: metadata_config.uri,
-            max_size: 100,
+            max_size: MAX_GROUP_SIZE,
+            additional_metadata,
         },
     )?;
 
-    // 3. Updating additional metadata
-    if let Some(metadata) = metadata_config.metadata {
-        let metadata_args = metadata
-            .iter()
-            .map(|m| AddMetadataArgs {
-                field: m[0].clone(),
-                value: m[1].clone(),
-            })
-            .collect();
-
-        add_metadata(
-            CpiContext::new_with_signer(
-                wns_program.to_account_info(),
-                AddMetadata {
-                    payer: fee_payer.to_account_info(),
-                    authority: minter.to_account_info(),
-                    mint: mint.to_account_info(),
-                    system_program: system_program.to_account_info(),
-                    token_program: token_extensions_program.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            metadata_args,
-        )?;
-    }
-
-    // 4. Closing receipt
+    // 3. Closing receipt
     ctx.accounts.receipt.close(fee_payer.to_account_info())?;
 
     Ok(())
@@ -314,8 +304,11 @@ pub struct CreateMinterWNSArgs {
     pub description: String,
     pub image_url: String,
     pub payment_config: PaymentConfigArgs,
-    pub application_config: MinterApplicationConfig,
+    pub application_config: MinterApplicationConfigArgs,
     pub metadata_config: MinterMetadataConfig,
     pub interest_config: Option<MinterInterestConfig>,
     pub transfer_fee_config: Option<MinterTransferFeeConfig>,
+    pub transferable: bool,
+    pub website: Option<String>,
+    pub royalty_basis_points: Option<u16>,
 }
@@ -1,8 +1,8 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions::ID as INSTRUCTIONS_SYSVAR_ID};
 use anchor_spl::{
     associated_token::{get_associated_token_address_with_program_id, AssociatedToken},
     token_2022::ID as TOKEN_EXTENSIONS_PROGRAM_ID,
-    token_interface::Token2022,
+    token_interface::TokenInterface,
 };
 use wen_new_standard::{
     cpi::{
@@ -27,6 +27,11 @@ pub struct MintMinterWNS<'info> {
     #[account(mut)]
     /// CHECK: PDA checks done below
     pub member: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Initialized by WNS along with the member mint
+    pub extra_metas_account: UncheckedAccount<'info>,
+    /// CHECK: PDA checks done by WNS, minter groups never become master editions
+    pub master_edition: UncheckedAccount<'info>,
     /** */
 
     #[account(
@@ -42,7 +47,6 @@ pub struct MintMinterWNS<'info> {
       constraint = matches!(receipt.payment_type, ReceiptType::User) @ TokenGatorMinterError::InvalidReceipt,
       constraint = receipt.receiver.eq(&authority.key()) @ TokenGatorMinterError::InvalidAuthority,
       constraint = receipt.sender.eq(&receiver.key()) @ TokenGatorMinterError::InvalidReceiver,
-      constraint = receipt.payment_amount.eq(&minter.minter_config.application_config.payment_config.price) @ TokenGatorMinterError::InvalidReceipt
     )]
     pub receipt: Account<'info, Receipt>,
 
@@ -57,9 +61,26 @@ pub struct MintMinterWNS<'info> {
       bump = minter.bump,
       has_one = fee_payer @ TokenGatorMinterError::UnAuthorized,
       constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused,
     )]
     pub minter: Account<'info, Minter>,
 
+    #[account(
+      has_one = minter @ TokenGatorMinterError::InvalidCoupon,
+    )]
+    pub coupon: Option<Account<'info, Coupon>>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused,
+      constraint = protocol_config.is_payment_mint_allowed(&receipt.payment_mint) @ TokenGatorMinterError::PaymentMintNotAllowed,
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     #[account(mut)]
     pub mint: Signer<'info>,
 
@@ -77,11 +98,14 @@ pub struct MintMinterWNS<'info> {
     #[account(
       constraint = token_program.key().eq(&TOKEN_EXTENSIONS_PROGRAM_ID) @ TokenGatorMinterError::InvalidTokenProgram
     )]
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub wns_program: Program<'info, WenNewStandard>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    /// CHECK: Address checked above
+    pub instructions_sysvar: UncheckedAccount<'info>,
 }
 
 pub fn mint(ctx: Context<MintMinterWNS>, args: MintMinterWNSArgs) -> Result<()> {
@@ -127,6 +151,82 @@ pub fn mint(ctx: Context<MintMinterWNS>, args: MintMinterWNSArgs) -> Result<()>
         &Some(member_key),
     )?;
 
+    // Applicants need an attestation from the protocol verifier for every required identity
+    let required_identities = &minter.minter_config.application_config.identities;
+    if !required_identities.is_empty() {
+        let attestation = verify_identity_attestation(
+            &ctx.accounts.instructions_sysvar.to_account_info(),
+            &ctx.accounts.protocol_config.identity_verifier,
+        )?;
+
+        require!(
+            attestation.minter == minter_key && attestation.wallet == receiver_key,
+            TokenGatorMinterError::InvalidIdentityAttestation
+        );
+        require!(
+            !attestation.is_expired(Clock::get()?.unix_timestamp),
+            TokenGatorMinterError::IdentityAttestationExpired
+        );
+        require!(
+            attestation.covers(required_identities),
+            TokenGatorMinterError::MissingIdentity
+        );
+
+        // The receiver also has to hold the link for each identity, its claim keeps one
+        // provider account from backing the memberships of several wallets
+        for provider in required_identities {
+            let (identity_link_key, _) = Pubkey::find_program_address(
+                &[
+                    PREFIX,
+                    IDENTITY_LINK,
+                    minter_key.as_ref(),
+                    receiver_key.as_ref(),
+                    provider.seed(),
+                    provider.value_seed(),
+                ],
+                &crate::id(),
+            );
+
+            let identity_link = ctx
+                .remaining_accounts
+                .iter()
+                .find(|account| account.key() == identity_link_key)
+                .filter(|account| account.owner == &crate::id())
+                .ok_or(TokenGatorMinterError::MissingIdentity)?;
+
+            IdentityLink::try_deserialize(&mut &identity_link.try_borrow_data()?[..])
+                .map_err(|_| TokenGatorMinterError::MissingIdentity)?;
+        }
+    }
+
+    let receipt = &ctx.accounts.receipt;
+    let payment_tier = minter.get_payment_tier(receipt.payment_tier)?;
+
+    let price = match receipt.coupon {
+        Some(coupon_key) => {
+            let coupon = ctx
+                .accounts
+                .coupon
+                .as_ref()
+                .ok_or(TokenGatorMinterError::InvalidCoupon)?;
+
+            require_keys_eq!(
+                coupon.key(),
+                coupon_key,
+                TokenGatorMinterError::InvalidCoupon
+            );
+
+            // Already redeemed when the receipt was prepared
+            coupon.apply(payment_tier.price)
+        }
+        None => payment_tier.price,
+    };
+
+    require!(
+        receipt.payment_amount == price && receipt.payment_mint == payment_tier.mint,
+        TokenGatorMinterError::InvalidReceipt
+    );
+
     let signer_seeds: &[&[&[u8]]] = &[&[
         PREFIX,
         MINTER,
@@ -149,6 +249,7 @@ pub fn mint(ctx: Context<MintMinterWNS>, args: MintMinterWNSArgs) -> Result<()>
                 manager: manager.to_account_info(),
                 mint: mint.to_account_info(),
                 mint_token_account: receiver_token_account.to_account_info(),
+                extra_metas_account: ctx.accounts.extra_metas_account.to_account_info(),
                 payer: fee_payer.to_account_info(),
                 receiver: receiver.to_account_info(),
                 rent: rent.to_account_info(),
@@ -162,27 +263,29 @@ pub fn mint(ctx: Context<MintMinterWNS>, args: MintMinterWNSArgs) -> Result<()>
             symbol,
             uri,
             permanent_delegate: Some(minter_key),
+            non_transferable: !minter.is_transferable(),
+            // a badge is a single token, the supply is locked right away
+            finalize: true,
         },
     )?;
 
     // 2. Updating additional metadata
-    if let Some(metadata) = args.metadata {
-        let issued_at = Clock::get()?.unix_timestamp;
-        let expires_at = issued_at
-            .checked_add(
-                60 * 60
-                    * 24
-                    * i64::try_from(minter.minter_config.application_config.payment_config.days)
-                        .unwrap(),
-            )
-            .unwrap();
-
-        let metadata_args: Vec<AddMetadataArgs> = [
-            metadata,
-            vec![["issued_at".to_owned(), issued_at.to_string()]],
-            vec![["expires_at".to_owned(), expires_at.to_string()]],
-        ]
-        .concat()
+    let issued_at = Clock::get()?.unix_timestamp;
+    let mut metadata = args.metadata.unwrap_or_default();
+
+    metadata.push(["issued_at".to_owned(), issued_at.to_string()]);
+    // Gating reads the holder, it only moves along with transferable badges
+    metadata.push(["holder".to_owned(), receiver_key.to_string()]);
+
+    if let Some(expires_at) = payment_tier.expires_at(issued_at) {
+        metadata.push(["expires_at".to_owned(), expires_at.to_string()]);
+    }
+
+    if let Some(label) = payment_tier.label {
+        metadata.push(["tier".to_owned(), label]);
+    }
+
+    let metadata_args: Vec<AddMetadataArgs> = metadata
         .iter()
         .map(|m| AddMetadataArgs {
             field: m[0].clone(),
@@ -190,21 +293,20 @@ pub fn mint(ctx: Context<MintMinterWNS>, args: MintMinterWNSArgs) -> Result<()>
         })
         .collect();
 
-        add_metadata(
-            CpiContext::new_with_signer(
-                wns_program.to_account_info(),
-                AddMetadata {
-                    payer: fee_payer.to_account_info(),
-                    authority: minter.to_account_info(),
-                    mint: mint.to_account_info(),
-                    system_program: system_program.to_account_info(),
-                    token_program: token_extensions_program.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            metadata_args,
-        )?;
-    }
+    add_metadata(
+        CpiContext::new_with_signer(
+            wns_program.to_account_info(),
+            AddMetadata {
+                payer: fee_payer.to_account_info(),
+                authority: minter.to_account_info(),
+                mint: mint.to_account_info(),
+                system_program: system_program.to_account_info(),
+                token_program: token_extensions_program.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        metadata_args,
+    )?;
 
     // 3. Adding member to group
     add_mint_to_group(CpiContext::new_with_signer(
@@ -212,6 +314,7 @@ pub fn mint(ctx: Context<MintMinterWNS>, args: MintMinterWNSArgs) -> Result<()>
         AddGroup {
             authority: minter.to_account_info(),
             group: group.to_account_info(),
+            master_edition: ctx.accounts.master_edition.to_account_info(),
             member: member.to_account_info(),
             manager: manager.to_account_info(),
             mint: mint.to_account_info(),
@@ -222,20 +325,9 @@ pub fn mint(ctx: Context<MintMinterWNS>, args: MintMinterWNSArgs) -> Result<()>
         signer_seeds,
     ))?;
 
-    // 4. Updating minter
-    minter
-        .minter_config
-        .application_config
-        .payment_config
-        .expires_at = Clock::get()?.unix_timestamp
-        + (60
-            * 60
-            * 24
-            * i64::try_from(minter.minter_config.application_config.payment_config.days).unwrap());
-
     minter.validate()?;
 
-    // 5. Closing Receipt
+    // 4. Closing Receipt
     ctx.accounts.receipt.close(fee_payer.to_account_info())?;
 
     Ok(())
@@ -1,7 +1,15 @@
+pub mod cancel;
 pub mod create;
 pub mod mint;
+pub mod remove;
+pub mod sync;
+pub mod transfer;
 pub mod update;
 
+pub use cancel::*;
 pub use create::*;
 pub use mint::*;
+pub use remove::*;
+pub use sync::*;
+pub use transfer::*;
 pub use update::*;
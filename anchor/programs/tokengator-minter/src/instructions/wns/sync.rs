@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+use wen_new_standard::{
+    cpi::{accounts::AddMetadata, add_metadata},
+    program::WenNewStandard,
+    AddMetadataArgs, TokenGroup, TokenGroupMember,
+};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+// Permissionless, moves the holder metadata to whoever currently owns the badge
+#[derive(Accounts)]
+pub struct SyncMemberHolderWNS<'info> {
+    #[account(
+      mut,
+      seeds = [
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      has_one = group @ TokenGatorMinterError::InvalidWNSGroup,
+      constraint = minter.is_transferable() @ TokenGatorMinterError::MinterNotTransferable,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused,
+    )]
+    pub minter: Account<'info, Minter>,
+
+    #[account(
+      constraint = group.update_authority.eq(&minter.key()) @ TokenGatorMinterError::UnAuthorized
+    )]
+    pub group: Account<'info, TokenGroup>,
+
+    #[account(
+      has_one = group @ TokenGatorMinterError::InvalidWNSGroup,
+      has_one = mint @ TokenGatorMinterError::InvalidWNSMember
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+      token::mint = mint,
+      token::token_program = token_program,
+      constraint = holder_token_account.amount == 1 @ TokenGatorMinterError::InvalidTokenAccount
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub wns_program: Program<'info, WenNewStandard>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn sync(ctx: Context<SyncMemberHolderWNS>) -> Result<()> {
+    let minter = &ctx.accounts.minter;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes(),
+        &[minter.bump],
+    ]];
+
+    add_metadata(
+        CpiContext::new_with_signer(
+            ctx.accounts.wns_program.to_account_info(),
+            AddMetadata {
+                payer: ctx.accounts.payer.to_account_info(),
+                authority: minter.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        vec![AddMetadataArgs {
+            field: "holder".to_owned(),
+            value: ctx.accounts.holder_token_account.owner.to_string(),
+        }],
+    )
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::Token2022, token_interface::Mint};
+use wen_new_standard::{
+    cpi::{accounts::TransferGroupAuthority, transfer_group_authority},
+    program::WenNewStandard,
+    TokenGroup, TransferGroupAuthorityArgs,
+};
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::*;
+
+// Proposes a new group authority, the transfer completes once it is accepted on WNS
+#[derive(Accounts)]
+pub struct TransferGroupAuthorityWNS<'info> {
+    #[account(
+      constraint = group.update_authority.eq(&minter.key()) @ TokenGatorMinterError::UnAuthorized
+    )]
+    pub group: Account<'info, TokenGroup>,
+    /// CHECK: PDA checks done by WNS
+    #[account(mut)]
+    pub group_authority_transfer: UncheckedAccount<'info>,
+    /// CHECK: PDA checks done by WNS
+    pub manager: UncheckedAccount<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        MINTER,
+        &minter.minter_config.mint.as_ref(),
+        &minter.name.as_bytes()
+      ],
+      bump = minter.bump,
+      has_one = group @ TokenGatorMinterError::InvalidWNSGroup,
+      has_one = fee_payer @ TokenGatorMinterError::UnAuthorized,
+      constraint = minter.check_for_authority(&authority.key()) @ TokenGatorMinterError::UnAuthorized,
+      constraint = minter.minter_config.mint.eq(&mint.key()) @ TokenGatorMinterError::InvalidMint,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused
+    )]
+    pub minter: Account<'info, Minter>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    pub wns_program: Program<'info, WenNewStandard>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer(
+    ctx: Context<TransferGroupAuthorityWNS>,
+    args: TransferGroupAuthorityWNSArgs,
+) -> Result<()> {
+    let minter = &ctx.accounts.minter;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        PREFIX,
+        MINTER,
+        minter.minter_config.mint.as_ref(),
+        minter.name.as_bytes(),
+        &[minter.bump],
+    ]];
+
+    transfer_group_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.wns_program.to_account_info(),
+            TransferGroupAuthority {
+                payer: ctx.accounts.fee_payer.to_account_info(),
+                authority: minter.to_account_info(),
+                group: ctx.accounts.group.to_account_info(),
+                group_authority_transfer: ctx.accounts.group_authority_transfer.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                manager: ctx.accounts.manager.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        TransferGroupAuthorityArgs {
+            new_authority: args.new_authority,
+        },
+    )?;
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransferGroupAuthorityWNSArgs {
+    pub new_authority: Pubkey,
+}
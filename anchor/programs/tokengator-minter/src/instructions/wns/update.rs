@@ -27,6 +27,7 @@ pub struct UpdateMemberMetadataWNS<'info> {
       bump = minter.bump,
       has_one = fee_payer @ TokenGatorMinterError::InvalidFeePayer,
       has_one = group @ TokenGatorMinterError::InvalidWNSGroup,
+      constraint = !minter.paused @ TokenGatorMinterError::MinterPaused,
     )]
     pub minter: Account<'info, Minter>,
 
@@ -47,6 +48,16 @@ pub struct UpdateMemberMetadataWNS<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
 
+    #[account(
+      seeds = [
+        PREFIX,
+        PROTOCOL_CONFIG,
+      ],
+      bump = protocol_config.bump,
+      constraint = !protocol_config.paused @ TokenGatorMinterError::ProgramPaused
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
     pub wns_program: Program<'info, WenNewStandard>,
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
@@ -18,6 +18,28 @@ declare_id!("GAToRDEEZmbXSe7ECcChQ1TsZCQXDBCtVhSd1Ypas9h6");
 pub mod tokengator_minter {
     use super::*;
 
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        args: InitializeProtocolConfigArgs,
+    ) -> Result<()> {
+        protocol::initialize(ctx, args)
+    }
+
+    pub fn update_protocol_config(
+        ctx: Context<UpdateProtocolConfig>,
+        args: UpdateProtocolConfigArgs,
+    ) -> Result<()> {
+        protocol::update(ctx, args)
+    }
+
+    pub fn set_protocol_paused(ctx: Context<SetProtocolPaused>, args: SetPausedArgs) -> Result<()> {
+        pause::set_protocol(ctx, args)
+    }
+
+    pub fn set_minter_paused(ctx: Context<SetMinterPaused>, args: SetPausedArgs) -> Result<()> {
+        pause::set_minter(ctx, args)
+    }
+
     pub fn prepare_for_payment(
         ctx: Context<PrepareForPayment>,
         args: PrepareForPaymentArgs,
@@ -50,6 +72,10 @@ pub mod tokengator_minter {
         authority::remove(ctx, args)
     }
 
+    pub fn mint_preset(ctx: Context<MintPreset>) -> Result<()> {
+        custom::mint(ctx)
+    }
+
     pub fn mint_minter_wns(ctx: Context<MintMinterWNS>, args: MintMinterWNSArgs) -> Result<()> {
         wns::mint(ctx, args)
     }
@@ -68,10 +94,86 @@ pub mod tokengator_minter {
         wns::update(ctx, args)
     }
 
+    pub fn sync_member_holder_wns(ctx: Context<SyncMemberHolderWNS>) -> Result<()> {
+        wns::sync(ctx)
+    }
+
     pub fn remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
         custom::remove(ctx)
     }
 
+    pub fn transfer_group_authority_wns(
+        ctx: Context<TransferGroupAuthorityWNS>,
+        args: TransferGroupAuthorityWNSArgs,
+    ) -> Result<()> {
+        wns::transfer(ctx, args)
+    }
+
+    pub fn cancel_group_authority_transfer_wns(
+        ctx: Context<CancelGroupAuthorityTransferWNS>,
+    ) -> Result<()> {
+        wns::cancel(ctx)
+    }
+
+    pub fn remove_minter_wns(ctx: Context<RemoveMinterWNS>) -> Result<()> {
+        wns::remove(ctx)
+    }
+
+    pub fn migrate_group(ctx: Context<MigrateGroup>) -> Result<()> {
+        custom::migrate(ctx)
+    }
+
+    pub fn create_coupon(ctx: Context<CreateCoupon>, args: CreateCouponArgs) -> Result<()> {
+        coupon::create(ctx, args)
+    }
+
+    pub fn remove_coupon(ctx: Context<RemoveCoupon>) -> Result<()> {
+        coupon::remove(ctx)
+    }
+
+    pub fn harvest_transfer_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>,
+    ) -> Result<()> {
+        fees::harvest(ctx)
+    }
+
+    pub fn withdraw_transfer_fees(ctx: Context<WithdrawTransferFees>) -> Result<()> {
+        fees::withdraw(ctx)
+    }
+
+    pub fn update_transfer_fee(
+        ctx: Context<UpdateTransferFee>,
+        args: UpdateTransferFeeArgs,
+    ) -> Result<()> {
+        fees::update(ctx, args)
+    }
+
+    pub fn update_interest_rate(
+        ctx: Context<UpdateInterestRate>,
+        args: UpdateInterestRateArgs,
+    ) -> Result<()> {
+        interest::update(ctx, args)
+    }
+
+    pub fn link_identity(ctx: Context<LinkIdentity>, args: LinkIdentityArgs) -> Result<()> {
+        identity::link(ctx, args)
+    }
+
+    pub fn unlink_identity(ctx: Context<UnlinkIdentity>) -> Result<()> {
+        identity::unlink(ctx)
+    }
+
+    pub fn create_subscription(
+        ctx: Context<CreateSubscription>,
+        args: CreateSubscriptionArgs,
+    ) -> Result<()> {
+        subscription::create(ctx, args)
+    }
+
+    pub fn collect_subscription(ctx: Context<CollectSubscription>) -> Result<()> {
+        subscription::collect(ctx)
+    }
+
     pub fn create_activity(ctx: Context<CreateActivity>, args: CreateActivityArgs) -> Result<()> {
         activity::create(ctx, args)
     }
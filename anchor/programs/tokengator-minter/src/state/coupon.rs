@@ -0,0 +1,109 @@
+use anchor_lang::{prelude::*, solana_program::hash::hash};
+
+use crate::constants::*;
+use crate::errors::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum CouponDiscount {
+    // Discount in basis points of the tier price
+    Percentage(u16),
+    // Discount in base units of the payment mint
+    Fixed(u64),
+}
+
+#[account]
+pub struct Coupon {
+    // Bump of the PDA
+    pub bump: u8,
+    // Minter the coupon can be redeemed on
+    pub minter: Pubkey,
+    // SHA-256 hash of the coupon code, doubles as the coupon id
+    pub code_hash: [u8; 32],
+    pub discount: CouponDiscount,
+    pub max_redemptions: u32,
+    pub redemptions: u32,
+    pub expires_at: Option<i64>,
+    // Wallet the coupon is bound to, redeemable without revealing the code
+    pub wallet: Option<Pubkey>,
+    pub fee_payer: Pubkey,
+}
+
+impl Coupon {
+    pub fn size() -> usize {
+        8 + // anchor discriminator
+        1 + // bump
+        32 + // minter
+        32 + // code_hash
+        1 + 8 + // discount
+        4 + // max_redemptions
+        4 + // redemptions
+        1 + 8 + // expires_at
+        1 + 32 + // wallet
+        32 // fee_payer
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let CouponDiscount::Percentage(basis_points) = self.discount {
+            require!(
+                basis_points > 0 && basis_points <= MAX_BASIS_POINTS,
+                TokenGatorMinterError::InvalidCoupon
+            );
+        }
+
+        require!(
+            self.max_redemptions > 0 && self.redemptions <= self.max_redemptions,
+            TokenGatorMinterError::InvalidCoupon
+        );
+
+        Ok(())
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+
+    pub fn check(&self, redeemer: &Pubkey, code: &Option<String>, now: i64) -> Result<()> {
+        require!(!self.is_expired(now), TokenGatorMinterError::CouponExpired);
+
+        require!(
+            self.redemptions < self.max_redemptions,
+            TokenGatorMinterError::CouponRedemptionsExhausted
+        );
+
+        match (&self.wallet, code) {
+            (Some(wallet), _) => {
+                require_keys_eq!(*wallet, *redeemer, TokenGatorMinterError::InvalidCoupon)
+            }
+            (None, Some(code)) => require!(
+                hash(code.as_bytes()).to_bytes() == self.code_hash,
+                TokenGatorMinterError::InvalidCoupon
+            ),
+            (None, None) => return err!(TokenGatorMinterError::InvalidCoupon),
+        }
+
+        Ok(())
+    }
+
+    pub fn apply(&self, price: u64) -> u64 {
+        match self.discount {
+            CouponDiscount::Percentage(basis_points) => {
+                let discount =
+                    u128::from(price) * u128::from(basis_points) / u128::from(MAX_BASIS_POINTS);
+                price.saturating_sub(u64::try_from(discount).unwrap())
+            }
+            CouponDiscount::Fixed(amount) => price.saturating_sub(amount),
+        }
+    }
+
+    pub fn redeem(&mut self, now: i64) -> Result<()> {
+        require!(!self.is_expired(now), TokenGatorMinterError::CouponExpired);
+
+        self.redemptions = self
+            .redemptions
+            .checked_add(1)
+            .filter(|redemptions| *redemptions <= self.max_redemptions)
+            .ok_or(TokenGatorMinterError::CouponRedemptionsExhausted)?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-// Custom group account till group extensions are live
+// Legacy custom group account, only kept around to migrate minters onto the group extension
 
 use anchor_lang::prelude::*;
 
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+// Message signed off-chain by the protocol identity verifier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct IdentityAttestation {
+    pub minter: Pubkey,
+    pub wallet: Pubkey,
+    // Identities the verifier has seen linked to the wallet
+    pub identities: Vec<IdentityProvider>,
+    pub expires_at: i64,
+}
+
+impl IdentityAttestation {
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn covers(&self, required_identities: &[IdentityProvider]) -> bool {
+        required_identities
+            .iter()
+            .all(|identity| self.identities.contains(identity))
+    }
+}
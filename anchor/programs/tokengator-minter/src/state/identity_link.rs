@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[account]
+pub struct IdentityLink {
+    // Bump of the PDA
+    pub bump: u8,
+    pub minter: Pubkey,
+    // Member wallet the identity is linked to
+    pub wallet: Pubkey,
+    pub provider: IdentityProvider,
+    // Salted hash of the provider user id, the salt never leaves the verifier
+    pub id_hash: [u8; 32],
+    // Verifier that vouched for the link
+    pub verifier: Pubkey,
+    pub verified_at: i64,
+    pub fee_payer: Pubkey,
+}
+
+impl IdentityLink {
+    pub fn size(provider: &IdentityProvider) -> usize {
+        8 + // anchor discriminator
+        1 + // bump
+        32 + // minter
+        32 + // wallet
+        provider.size() + // provider
+        32 + // id_hash
+        32 + // verifier
+        8 + // verified_at
+        32 // fee_payer
+    }
+}
+
+// Claims a provider account within a minter so it cannot back a second membership
+#[account]
+pub struct IdentityClaim {
+    // Bump of the PDA
+    pub bump: u8,
+    pub identity_link: Pubkey,
+}
+
+impl IdentityClaim {
+    pub fn size() -> usize {
+        8 + // anchor discriminator
+        1 + // bump
+        32 // identity_link
+    }
+}
@@ -27,19 +27,43 @@ pub struct Minter {
     pub payment_config: PaymentConfig,
     // Identities user have added onto
     pub minter_config: MinterConfig,
+    // Minting and member updates are halted while paused
+    pub paused: bool,
+    // Appended so accounts created before tiers still deserialize, empty for those minters
+    pub payment_tiers: Vec<PaymentTier>,
+    // Lets members trade their badges instead of minting soulbound ones
+    pub transferable: TrailingBool,
+}
+
+// Flag appended to an account, read as false from accounts serialized before it existed
+#[derive(AnchorSerialize, Clone, Copy, Default, PartialEq)]
+pub struct TrailingBool(pub bool);
+
+impl AnchorDeserialize for TrailingBool {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut byte = [0u8; 1];
+
+        match reader.read(&mut byte)? {
+            0 => Ok(Self(false)),
+            _ => bool::deserialize(&mut &byte[..]).map(Self),
+        }
+    }
 }
 
 impl Minter {
     pub fn size(
         authorities: &[Pubkey],
-        application_config: &MinterApplicationConfig,
+        identities: &[IdentityProvider],
+        payment_tiers: &[PaymentTier],
         metadata_config: &MinterMetadataConfig,
     ) -> usize {
         let authorities_size = 4 + // Vector discriminator
         (authorities.len() * 32); // Total authorities pubkey length
 
         let payment_config_size = PaymentConfig::size();
-        let minter_config_size = MinterConfig::size(application_config, metadata_config);
+        let minter_config_size = MinterConfig::size(identities, metadata_config);
+        let payment_tiers_size = 4 + // Vector discriminator
+        (payment_tiers.len() * PaymentTier::size()); // Total payment tiers length
 
         8 + // Anchor discriminator
         1 + // bump
@@ -52,7 +76,10 @@ impl Minter {
         authorities_size + // authorities
         8 + // payment_expires_at
         payment_config_size + // payment_config
-        minter_config_size // minter_config
+        minter_config_size + // minter_config
+        1 + // paused
+        payment_tiers_size + // payment_tiers
+        1 // transferable
     }
 
     pub fn validate(&self) -> Result<()> {
@@ -95,9 +122,43 @@ impl Minter {
         // Minter config
         self.minter_config.validate()?;
 
+        // Payment tiers, the fallback tier of older minters may be free and is left as it was
+        require!(
+            self.payment_tiers.len() <= MAX_PAYMENT_TIERS,
+            TokenGatorMinterError::InvalidPaymentTier
+        );
+
+        for payment_tier in &self.payment_tiers {
+            payment_tier.validate()?;
+        }
+
         Ok(())
     }
 
+    // Minters created before payment tiers sell their application payment config as the only tier
+    pub fn get_payment_tiers(&self) -> Vec<PaymentTier> {
+        if self.payment_tiers.is_empty() {
+            vec![self
+                .minter_config
+                .application_config
+                .payment_config
+                .payment_tier()]
+        } else {
+            self.payment_tiers.clone()
+        }
+    }
+
+    pub fn get_payment_tier(&self, index: u8) -> Result<PaymentTier> {
+        self.get_payment_tiers()
+            .into_iter()
+            .nth(usize::from(index))
+            .ok_or(TokenGatorMinterError::InvalidPaymentTier.into())
+    }
+
+    pub fn is_transferable(&self) -> bool {
+        self.transferable.0
+    }
+
     pub fn check_for_authority(&self, authority: &Pubkey) -> bool {
         self.authorities.binary_search(authority).is_ok()
     }
@@ -1,27 +1,83 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::*;
+use crate::errors::*;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+// Borsh encodes the variant index, new providers must only ever be appended
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
 pub enum IdentityProvider {
-    Discord = 0,
-    GitHub = 1,
-    Google = 2,
-    Twitter = 3,
+    Discord,
+    GitHub,
+    Google,
+    Twitter,
+    Telegram,
+    // Ownership of another Solana wallet
+    Solana,
+    // Verified email address on the given domain
+    EmailDomain(String),
+    // Custom OpenID Connect issuer
+    Oidc(String),
 }
 
 impl IdentityProvider {
-    pub fn get_provider(&self) -> String {
+    pub fn size(&self) -> usize {
         match self {
-            IdentityProvider::Discord => "Discord".to_owned(),
-            IdentityProvider::GitHub => "GitHub".to_owned(),
-            IdentityProvider::Google => "Google".to_owned(),
-            IdentityProvider::Twitter => "Twitter".to_owned(),
+            IdentityProvider::EmailDomain(value) | IdentityProvider::Oidc(value) => {
+                1 + 4 + value.len()
+            }
+            _ => 1,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let IdentityProvider::EmailDomain(value) | IdentityProvider::Oidc(value) = self {
+            require!(
+                !value.is_empty() && value.len() <= MAX_IDENTITY_PROVIDER_VALUE_SIZE,
+                TokenGatorMinterError::InvalidIdentityProvider
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn get_provider(&self) -> &str {
+        match self {
+            IdentityProvider::Discord => "Discord",
+            IdentityProvider::GitHub => "GitHub",
+            IdentityProvider::Google => "Google",
+            IdentityProvider::Twitter => "Twitter",
+            IdentityProvider::Telegram => "Telegram",
+            IdentityProvider::Solana => "Solana",
+            IdentityProvider::EmailDomain(_) => "EmailDomain",
+            IdentityProvider::Oidc(_) => "Oidc",
+        }
+    }
+
+    pub fn seed(&self) -> &[u8] {
+        match self {
+            IdentityProvider::Discord => b"discord",
+            IdentityProvider::GitHub => b"github",
+            IdentityProvider::Google => b"google",
+            IdentityProvider::Twitter => b"twitter",
+            IdentityProvider::Telegram => b"telegram",
+            IdentityProvider::Solana => b"solana",
+            IdentityProvider::EmailDomain(_) => b"email_domain",
+            IdentityProvider::Oidc(_) => b"oidc",
+        }
+    }
+
+    // Tells providers of the same kind apart, empty for the fixed providers
+    pub fn value_seed(&self) -> &[u8] {
+        match self {
+            IdentityProvider::EmailDomain(value) | IdentityProvider::Oidc(value) => {
+                value.as_bytes()
+            }
+            _ => &[],
         }
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct PaymentConfig {
     pub amount: u16,
     pub price: u64,
@@ -42,6 +98,61 @@ impl PaymentConfig {
         // TODO: Implement Validation
         Ok(())
     }
+
+    // Single tier of the minters created before payment tiers, `days` of 0 never expire
+    pub fn payment_tier(&self) -> PaymentTier {
+        PaymentTier {
+            label: None,
+            price: self.price,
+            mint: self.mint,
+            days: (self.days > 0).then(|| u16::from(self.days)),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PaymentTier {
+    // Optional label written into the member metadata
+    pub label: Option<String>,
+    pub price: u64,
+    pub mint: Pubkey,
+    // Duration of the membership, `None` for lifetime memberships
+    pub days: Option<u16>,
+}
+
+impl PaymentTier {
+    pub fn size() -> usize {
+        1 + 4 + MAX_TIER_LABEL_SIZE + // label
+        8 + // price
+        32 + // mint
+        1 + 2 // days
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let Some(label) = &self.label {
+            require!(
+                !label.is_empty() && label.len() <= MAX_TIER_LABEL_SIZE,
+                TokenGatorMinterError::InvalidPaymentTier
+            );
+        }
+
+        require!(self.price > 0, TokenGatorMinterError::InvalidPaymentTier);
+
+        require!(
+            !matches!(self.days, Some(0)),
+            TokenGatorMinterError::InvalidPaymentTier
+        );
+
+        Ok(())
+    }
+
+    pub fn expires_at(&self, issued_at: i64) -> Option<i64> {
+        self.days.map(|days| {
+            issued_at
+                .checked_add(60 * 60 * 24 * i64::from(days))
+                .unwrap()
+        })
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -101,7 +212,11 @@ impl MinterTransferFeeConfig {
     }
 
     pub fn validate(&self) -> Result<()> {
-        // TODO: Implement validation
+        require!(
+            self.transfer_fee_basis_points <= MAX_BASIS_POINTS,
+            TokenGatorMinterError::InvalidTransferFee
+        );
+
         Ok(())
     }
 }
@@ -109,6 +224,7 @@ impl MinterTransferFeeConfig {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct MinterApplicationConfig {
     pub identities: Vec<IdentityProvider>,
+    // Only read for minters created before `Minter::payment_tiers`
     pub payment_config: PaymentConfig,
 }
 
@@ -117,12 +233,16 @@ impl MinterApplicationConfig {
         let payment_config_size = PaymentConfig::size();
 
         4 + // Vector discriminator
-        (identities.len() * (1 + 1)) + // Identity
+        identities.iter().map(IdentityProvider::size).sum::<usize>() + // identities
+        8 + // payment_expires_at
         payment_config_size // payment_config
     }
 
     pub fn validate(&self) -> Result<()> {
-        // TODO: Implement validation
+        for identity in &self.identities {
+            identity.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -137,12 +257,9 @@ pub struct MinterConfig {
 }
 
 impl MinterConfig {
-    pub fn size(
-        application_config: &MinterApplicationConfig,
-        metadata_config: &MinterMetadataConfig,
-    ) -> usize {
+    pub fn size(identities: &[IdentityProvider], metadata_config: &MinterMetadataConfig) -> usize {
         32 + // mint
-        MinterApplicationConfig::size(&application_config.identities) + // application_config
+        MinterApplicationConfig::size(identities) + // application_config
         MinterMetadataConfig::size(&metadata_config.metadata) + // metadata_config
         1 + MinterInterestConfig::size() + // interest_config
         1 + MinterTransferFeeConfig::size() // transfer_fee_config
@@ -1,11 +1,21 @@
 pub mod activity;
+pub mod coupon;
 pub mod group_extensions;
+pub mod identity_attestation;
+pub mod identity_link;
 pub mod minter;
 pub mod minter_config;
+pub mod protocol_config;
 pub mod receipt;
+pub mod subscription;
 
 pub use activity::*;
+pub use coupon::*;
 pub use group_extensions::*;
+pub use identity_attestation::*;
+pub use identity_link::*;
 pub use minter::*;
 pub use minter_config::*;
+pub use protocol_config::*;
 pub use receipt::*;
+pub use subscription::*;
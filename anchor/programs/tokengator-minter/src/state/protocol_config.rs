@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+
+#[account]
+pub struct ProtocolConfig {
+    // Bump of the PDA
+    pub bump: u8,
+    // Authority allowed to update the protocol config
+    pub admin: Pubkey,
+    // Wallet receiving the protocol fees
+    pub treasury: Pubkey,
+    // Fee paid by communities to create a minter
+    pub community_fee: u64,
+    // Fee taken from every member payment, in basis points
+    pub mint_fee_basis_points: u16,
+    // Mints accepted for payments, any mint is accepted when empty
+    pub allowed_payment_mints: Vec<Pubkey>,
+    // Every mutating instruction is halted while paused
+    pub paused: bool,
+    // Key signing the identity attestations checked at mint time
+    pub identity_verifier: Pubkey,
+}
+
+impl ProtocolConfig {
+    pub fn size(allowed_payment_mints: &[Pubkey]) -> usize {
+        8 + // anchor discriminator
+        1 + // bump
+        32 + // admin
+        32 + // treasury
+        8 + // community_fee
+        2 + // mint_fee_basis_points
+        4 + // vector discriminator
+        (allowed_payment_mints.len() * 32) + // allowed_payment_mints
+        1 + // paused
+        32 // identity_verifier
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.mint_fee_basis_points <= MAX_BASIS_POINTS,
+            TokenGatorMinterError::InvalidProtocolFee
+        );
+
+        require!(
+            self.allowed_payment_mints.len() <= MAX_ALLOWED_PAYMENT_MINTS,
+            TokenGatorMinterError::MaxSizeReached
+        );
+
+        Ok(())
+    }
+
+    pub fn is_payment_mint_allowed(&self, mint: &Pubkey) -> bool {
+        self.allowed_payment_mints.is_empty() || self.allowed_payment_mints.contains(mint)
+    }
+
+    pub fn mint_fee(&self, amount: u64) -> u64 {
+        let fee = u128::from(amount) * u128::from(self.mint_fee_basis_points)
+            / u128::from(MAX_BASIS_POINTS);
+        u64::try_from(fee).unwrap()
+    }
+}
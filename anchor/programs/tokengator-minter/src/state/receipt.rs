@@ -17,6 +17,10 @@ pub struct Receipt {
     pub sender_token_account: Pubkey,
     pub receiver_token_account: Pubkey,
     pub payment_mint: Pubkey,
+    pub payment_tier: u8,
+    pub coupon: Option<Pubkey>,
+    // Share of the payment sent to the protocol treasury
+    pub protocol_fee: u64,
 }
 
 impl Receipt {
@@ -30,6 +34,9 @@ impl Receipt {
         32 + // receiver
         32 + // sender_token_account
         32 + // receiver_token_account
-        32 // payment_mint
+        32 + // payment_mint
+        1 + // payment_tier
+        1 + 32 + // coupon
+        8 // protocol_fee
     }
 }
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Subscription {
+    // Bump of the PDA
+    pub bump: u8,
+    pub minter: Pubkey,
+    // Member mint the subscription renews
+    pub mint: Pubkey,
+    pub subscriber: Pubkey,
+    pub payment_tier: u8,
+    pub payment_mint: Pubkey,
+    // Subscriber token account the minter is approved as delegate on
+    pub payment_token_account: Pubkey,
+    // Minter authority token account receiving the renewals
+    pub receiver_token_account: Pubkey,
+    pub price: u64,
+    pub days: u16,
+    pub expires_at: i64,
+    pub fee_payer: Pubkey,
+}
+
+impl Subscription {
+    pub fn size() -> usize {
+        8 + // anchor discriminator
+        1 + // bump
+        32 + // minter
+        32 + // mint
+        32 + // subscriber
+        1 + // payment_tier
+        32 + // payment_mint
+        32 + // payment_token_account
+        32 + // receiver_token_account
+        8 + // price
+        2 + // days
+        8 + // expires_at
+        32 // fee_payer
+    }
+
+    pub fn is_due(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    // A lapsed membership restarts from `now`, one collection only ever buys one period ahead
+    pub fn extend(&mut self, now: i64) -> i64 {
+        self.expires_at = self
+            .expires_at
+            .max(now)
+            .checked_add(60 * 60 * 24 * i64::from(self.days))
+            .unwrap();
+
+        self.expires_at
+    }
+}
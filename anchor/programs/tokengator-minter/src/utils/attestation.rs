@@ -0,0 +1,77 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+
+use crate::errors::*;
+use crate::state::*;
+
+// Layout of the ed25519 program instruction data
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+pub fn verify_identity_attestation(
+    instructions_sysvar: &AccountInfo,
+    verifier: &Pubkey,
+) -> Result<IdentityAttestation> {
+    // The ed25519 program instruction has to directly precede the current instruction
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require_gt!(
+        current_index,
+        0,
+        TokenGatorMinterError::InvalidIdentityAttestation
+    );
+
+    let ed25519_instruction =
+        load_instruction_at_checked(usize::from(current_index - 1), instructions_sysvar)?;
+    require_keys_eq!(
+        ed25519_instruction.program_id,
+        ed25519_program::ID,
+        TokenGatorMinterError::InvalidIdentityAttestation
+    );
+
+    let data = &ed25519_instruction.data;
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE && data[0] == 1,
+        TokenGatorMinterError::InvalidIdentityAttestation
+    );
+
+    let read_u16 = |offset: usize| {
+        let start = ED25519_OFFSETS_START + offset;
+        u16::from_le_bytes([data[start], data[start + 1]])
+    };
+
+    let signature_instruction_index = read_u16(2);
+    let public_key_offset = usize::from(read_u16(4));
+    let public_key_instruction_index = read_u16(6);
+    let message_offset = usize::from(read_u16(8));
+    let message_size = usize::from(read_u16(10));
+    let message_instruction_index = read_u16(12);
+
+    // Signature, public key and message must live inside the ed25519 instruction itself
+    require!(
+        signature_instruction_index == ED25519_CURRENT_INSTRUCTION
+            && public_key_instruction_index == ED25519_CURRENT_INSTRUCTION
+            && message_instruction_index == ED25519_CURRENT_INSTRUCTION,
+        TokenGatorMinterError::InvalidIdentityAttestation
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(TokenGatorMinterError::InvalidIdentityAttestation)?;
+    require!(
+        public_key == verifier.as_ref(),
+        TokenGatorMinterError::InvalidIdentityAttestation
+    );
+
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(TokenGatorMinterError::InvalidIdentityAttestation)?;
+
+    IdentityAttestation::try_from_slice(message)
+        .map_err(|_| error!(TokenGatorMinterError::InvalidIdentityAttestation))
+}
@@ -1,4 +1,9 @@
 use anchor_lang::{prelude::*, system_program};
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+use spl_token_metadata_interface::state::TokenMetadata;
 use wen_new_standard::{
     id as wns_program_id, GROUP_ACCOUNT_SEED, MANAGER_SEED, MEMBER_ACCOUNT_SEED,
 };
@@ -55,6 +60,18 @@ pub fn check_for_wns_accounts(
     Ok(())
 }
 
+pub fn get_metadata_field(mint: &AccountInfo, field: &str) -> Result<Option<String>> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let metadata = mint_with_extension.get_variable_len_extension::<TokenMetadata>()?;
+
+    Ok(metadata
+        .additional_metadata
+        .into_iter()
+        .find(|(key, _)| key == field)
+        .map(|(_, value)| value))
+}
+
 pub fn is_valid_username(username: &str) -> bool {
     if username.len() < 3 || username.len() > MAX_NAME_SIZE {
         return false;
@@ -130,3 +147,28 @@ pub fn realloc_account<'a>(
     AccountInfo::realloc(&account, new_account_size, false)?;
     Ok(())
 }
+
+// Tops up an account that grew through a CPI, like a mint whose metadata was updated
+pub fn fund_rent_exemption<'a>(
+    account: AccountInfo<'a>,
+    rent_payer: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(account.data_len());
+
+    let lamports_diff = rent_exempt_lamports.saturating_sub(account.lamports());
+    if lamports_diff.gt(&0) {
+        system_program::transfer(
+            CpiContext::new(
+                system_program,
+                system_program::Transfer {
+                    from: rent_payer,
+                    to: account,
+                },
+            ),
+            lamports_diff,
+        )?;
+    }
+
+    Ok(())
+}
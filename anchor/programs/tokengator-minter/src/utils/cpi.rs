@@ -54,6 +54,34 @@ pub fn initialize_interest_bearing_mint<'info>(
     solana_program::program::invoke(&ix, &[ctx.accounts.mint]).map_err(Into::into)
 }
 
+#[derive(Accounts)]
+pub struct UpdateRateInterestBearingMint<'info> {
+    /// CHECK: CPI Account
+    pub mint: AccountInfo<'info>,
+    /// CHECK: CPI Account
+    pub rate_authority: AccountInfo<'info>,
+}
+
+pub fn update_rate_interest_bearing_mint<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, UpdateRateInterestBearingMint<'info>>,
+    rate: i16,
+) -> Result<()> {
+    let ix = interest_bearing_mint::instruction::update_rate(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.rate_authority.key,
+        &[],
+        rate,
+    )?;
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &[ctx.accounts.mint, ctx.accounts.rate_authority],
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
 #[derive(Accounts)]
 pub struct InitializeMetadataPointer<'info> {
     /// CHECK: CPI Account
@@ -96,6 +124,55 @@ pub fn initialize_group_pointer<'info>(
     solana_program::program::invoke(&ix, &[ctx.accounts.mint]).map_err(Into::into)
 }
 
+#[derive(Accounts)]
+pub struct UpdateGroupPointer<'info> {
+    /// CHECK: CPI Account
+    pub mint: AccountInfo<'info>,
+    /// CHECK: CPI Account
+    pub authority: AccountInfo<'info>,
+}
+
+pub fn update_group_pointer<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, UpdateGroupPointer<'info>>,
+    group_address: Option<Pubkey>,
+) -> Result<()> {
+    let ix = group_pointer::instruction::update(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+        &[],
+        group_address,
+    )?;
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &[ctx.accounts.mint, ctx.accounts.authority],
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+#[derive(Accounts)]
+pub struct InitializeGroupMemberPointer<'info> {
+    /// CHECK: CPI Account
+    pub mint: AccountInfo<'info>,
+}
+
+pub fn initialize_group_member_pointer<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeGroupMemberPointer<'info>>,
+    authority: Option<Pubkey>,
+    member_address: Option<Pubkey>,
+) -> Result<()> {
+    let ix = group_member_pointer::instruction::initialize(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        authority,
+        member_address,
+    )?;
+
+    solana_program::program::invoke(&ix, &[ctx.accounts.mint]).map_err(Into::into)
+}
+
 #[derive(Accounts)]
 pub struct InitializeTransferFeeConfig<'info> {
     /// CHECK: CPI Account
@@ -121,6 +198,91 @@ pub fn initialize_transfer_fee_config<'info>(
     solana_program::program::invoke(&ix, &[ctx.accounts.mint]).map_err(Into::into)
 }
 
+#[derive(Accounts)]
+pub struct SetTransferFee<'info> {
+    /// CHECK: CPI Account
+    pub mint: AccountInfo<'info>,
+    /// CHECK: CPI Account
+    pub authority: AccountInfo<'info>,
+}
+
+pub fn set_transfer_fee<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, SetTransferFee<'info>>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<()> {
+    let ix = transfer_fee::instruction::set_transfer_fee(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+        &[],
+        transfer_fee_basis_points,
+        maximum_fee,
+    )?;
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &[ctx.accounts.mint, ctx.accounts.authority],
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+#[derive(Accounts)]
+pub struct HarvestWithheldTokensToMint<'info> {
+    /// CHECK: CPI Account
+    pub mint: AccountInfo<'info>,
+}
+
+pub fn harvest_withheld_tokens_to_mint<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, HarvestWithheldTokensToMint<'info>>,
+) -> Result<()> {
+    let sources: Vec<&Pubkey> = ctx.remaining_accounts.iter().map(|a| a.key).collect();
+    let ix = transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        &sources,
+    )?;
+
+    let mut account_infos = vec![ctx.accounts.mint];
+    account_infos.extend(ctx.remaining_accounts);
+
+    solana_program::program::invoke(&ix, &account_infos).map_err(Into::into)
+}
+
+#[derive(Accounts)]
+pub struct WithdrawWithheldTokensFromMint<'info> {
+    /// CHECK: CPI Account
+    pub mint: AccountInfo<'info>,
+    /// CHECK: CPI Account
+    pub destination: AccountInfo<'info>,
+    /// CHECK: CPI Account
+    pub authority: AccountInfo<'info>,
+}
+
+pub fn withdraw_withheld_tokens_from_mint<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, WithdrawWithheldTokensFromMint<'info>>,
+) -> Result<()> {
+    let ix = transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.destination.key,
+        ctx.accounts.authority.key,
+        &[],
+    )?;
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.mint,
+            ctx.accounts.destination,
+            ctx.accounts.authority,
+        ],
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
 #[derive(Accounts)]
 pub struct InitializeMetadata<'info> {
     /// CHECK: CPI Account
@@ -199,6 +361,46 @@ pub fn initialize_group<'info>(
     .map_err(Into::into)
 }
 
+#[derive(Accounts)]
+pub struct InitializeMember<'info> {
+    /// CHECK: CPI Account
+    pub member: AccountInfo<'info>,
+    /// CHECK: CPI Account
+    pub member_mint: AccountInfo<'info>,
+    /// CHECK: CPI Account
+    pub member_mint_authority: AccountInfo<'info>,
+    /// CHECK: CPI Account
+    pub group: AccountInfo<'info>,
+    /// CHECK: CPI Account
+    pub group_update_authority: AccountInfo<'info>,
+}
+
+pub fn initialize_member<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeMember<'info>>,
+) -> Result<()> {
+    let ix = spl_token_group_interface::instruction::initialize_member(
+        ctx.program.key,
+        ctx.accounts.member.key,
+        ctx.accounts.member_mint.key,
+        ctx.accounts.member_mint_authority.key,
+        ctx.accounts.group.key,
+        ctx.accounts.group_update_authority.key,
+    );
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.member,
+            ctx.accounts.member_mint,
+            ctx.accounts.member_mint_authority,
+            ctx.accounts.group,
+            ctx.accounts.group_update_authority,
+        ],
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
 #[derive(Accounts)]
 pub struct UpdateMetadataField<'info> {
     /// CHECK: CPI Account
@@ -1,5 +1,7 @@
+pub mod attestation;
 pub mod common;
 pub mod cpi;
 
+pub use attestation::*;
 pub use common::*;
 pub use cpi::*;
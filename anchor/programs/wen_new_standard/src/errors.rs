@@ -18,6 +18,20 @@ pub enum MetadataErrors {
     CreatorAddressInvalid,
     #[msg("Royalty basis points must be less than or equal to 10000.")]
     RoyaltyBasisPointsInvalid,
+    #[msg("Royalties are already set, modify them instead.")]
+    RoyaltiesAlreadySet,
+    #[msg("Royalties are not set.")]
+    RoyaltiesNotSet,
+    #[msg("Invalid group update authority.")]
+    InvalidGroupAuthority,
+    #[msg("Group still has members.")]
+    GroupNotEmpty,
+    #[msg("Group account is already migrated.")]
+    GroupAlreadyMigrated,
+    #[msg("Group is a master edition, only its prints can join it.")]
+    GroupIsMasterEdition,
+    #[msg("Buy amount must be greater than zero for mints with royalties.")]
+    BuyAmountInvalid,
 }
 
 #[error_code]
@@ -26,4 +40,10 @@ pub enum MintErrors {
     InvalidFreezeAuthority,
     #[msg("Invalid delegate authority.")]
     InvalidDelegateAuthority,
+    #[msg("Transfer hook can only run during a transfer.")]
+    NotTransferring,
+    #[msg("Mint is a group member, burn it with its group.")]
+    MintIsGroupMember,
+    #[msg("Invalid mint authority.")]
+    InvalidMintAuthority,
 }
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when a mint is burned and closed
+#[event]
+pub struct BurnMintEvent {
+    /// The burned mint
+    pub mint: Pubkey,
+    /// The group the mint was a member of, if any
+    pub group: Option<Pubkey>,
+    /// The member number the mint held in the group, if any
+    pub member_number: Option<u32>,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{get_creator_amounts, get_mint_metadata, get_royalty_amount, MetadataErrors};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct DistributeRoyaltiesArgs {
+    pub sale_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct DistributeRoyalties<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mint::token_program = payment_token_program,
+    )]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = payer,
+        token::token_program = payment_token_program,
+    )]
+    pub payer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub payment_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DistributeRoyalties<'info> {
+    fn pay_creator(&self, creator_token_account: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let cpi_accounts = TransferChecked {
+            from: self.payer_token_account.to_account_info(),
+            mint: self.payment_mint.to_account_info(),
+            to: creator_token_account,
+            authority: self.payer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.payment_token_program.to_account_info(), cpi_accounts);
+        transfer_checked(cpi_ctx, amount, self.payment_mint.decimals)?;
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeRoyalties<'info>>,
+    args: DistributeRoyaltiesArgs,
+) -> Result<()> {
+    let metadata = get_mint_metadata(&mut ctx.accounts.mint.to_account_info())?;
+    let royalty_amount = get_royalty_amount(&metadata, args.sale_amount)?;
+
+    // creator associated token accounts of the payment mint are passed as remaining accounts
+    for (creator, creator_amount) in get_creator_amounts(&metadata, royalty_amount)? {
+        let creator_token_account_key = get_associated_token_address_with_program_id(
+            &creator,
+            &ctx.accounts.payment_mint.key(),
+            &ctx.accounts.payment_token_program.key(),
+        );
+        let creator_token_account = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account| account.key() == creator_token_account_key)
+            .ok_or(MetadataErrors::CreatorAddressInvalid)?;
+
+        if creator_amount > 0 {
+            ctx.accounts
+                .pay_creator(creator_token_account.clone(), creator_amount)?;
+        }
+    }
+
+    Ok(())
+}
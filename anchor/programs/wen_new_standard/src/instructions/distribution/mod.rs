@@ -0,0 +1,3 @@
+pub mod distribute;
+
+pub use distribute::*;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{MasterEdition, MetadataErrors, TokenGroup, MASTER_EDITION_SEED};
+
+#[derive(Accounts)]
+pub struct CreateMasterEdition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = group.update_authority == authority.key() @ MetadataErrors::InvalidGroupAuthority,
+        constraint = group.size == 0 @ MetadataErrors::GroupNotEmpty,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init,
+        seeds = [MASTER_EDITION_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + MasterEdition::INIT_SPACE
+    )]
+    pub master_edition: Account<'info, MasterEdition>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateMasterEdition>) -> Result<()> {
+    // the group mint metadata is the template every print copies
+    let master_edition = &mut ctx.accounts.master_edition;
+    master_edition.group = ctx.accounts.group.key();
+    master_edition.supply = 0;
+    // a later group resize does not change how many prints were promised
+    master_edition.max_supply = ctx.accounts.group.max_size;
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod create;
+pub mod print;
+
+pub use create::*;
+pub use print::*;
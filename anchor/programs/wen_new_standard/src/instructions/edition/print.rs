@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::TokenMetadata, Mint, Token2022,
+    },
+};
+
+use crate::{
+    get_approve_account_pda, get_meta_list_size, get_mint_metadata,
+    update_account_lamports_to_minimum_balance, write_extra_meta_list, Manager, MasterEdition,
+    MetadataErrors, MintExtensions, NewMint, TokenGroup, TokenGroupMember, EDITION_FIELD,
+    MANAGER_SEED, MASTER_EDITION_SEED, MAX_SUPPLY_FIELD, MEMBER_ACCOUNT_SEED,
+    META_LIST_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct PrintEdition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account()]
+    /// CHECK: can be any account
+    pub receiver: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = group.update_authority == authority.key() @ MetadataErrors::InvalidGroupAuthority,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        seeds = [MASTER_EDITION_SEED, group.key().as_ref()],
+        bump,
+        has_one = group,
+    )]
+    pub master_edition: Account<'info, MasterEdition>,
+    #[account(
+        address = group.mint,
+        mint::token_program = token_program,
+    )]
+    pub group_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub mint: Signer<'info>,
+    /// CHECK: Localnet changes
+    #[account(mut)]
+    pub mint_token_account: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + TokenGroupMember::INIT_SPACE
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    #[account(
+        init,
+        payer = payer,
+        space = get_meta_list_size(get_approve_account_pda(mint.key())),
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: extra metas account, data is written in the handler
+    pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> PrintEdition<'info> {
+    fn new_mint(&self) -> NewMint<'info> {
+        NewMint {
+            payer: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+            receiver: self.receiver.to_account_info(),
+            mint: self.mint.to_account_info(),
+            mint_token_account: self.mint_token_account.to_account_info(),
+            manager: self.manager.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+pub fn handler(ctx: Context<PrintEdition>) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let mint = &ctx.accounts.mint;
+    let manager = &ctx.accounts.manager;
+    let new_mint = ctx.accounts.new_mint();
+
+    // every print counts as a member, the supply is capped by the snapshot taken at creation
+    let max_supply = ctx.accounts.master_edition.max_supply;
+    ctx.accounts.group.increment_size()?;
+    let member_number = ctx.accounts.group.increment_member_count()?;
+    let edition = ctx.accounts.master_edition.increment_supply()?;
+
+    // copy the template metadata and stamp the edition number
+    let template = get_mint_metadata(&mut ctx.accounts.group_mint.to_account_info())?;
+
+    let mut additional_metadata = template.additional_metadata;
+    additional_metadata.push((EDITION_FIELD.to_owned(), edition.to_string()));
+    additional_metadata.push((MAX_SUPPLY_FIELD.to_owned(), max_supply.to_string()));
+
+    let extensions = MintExtensions {
+        member_address: ctx.accounts.member.key(),
+        permanent_delegate: None,
+        non_transferable: false,
+    };
+
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(authority.key())).unwrap(),
+        mint: mint.key(),
+        name: template.name,
+        symbol: template.symbol,
+        uri: template.uri,
+        additional_metadata,
+    };
+
+    new_mint.create(&extensions, &metadata)?;
+
+    // the transfer hook resolves its extra accounts from the meta list
+    write_extra_meta_list(
+        &ctx.accounts.extra_metas_account.to_account_info(),
+        mint.key(),
+    )?;
+
+    new_mint.create_receiver_token_account()?;
+
+    new_mint.initialize_token_metadata(metadata)?;
+
+    // add the print to the group
+    let member = &mut ctx.accounts.member;
+    member.group = ctx.accounts.group.key();
+    member.mint = mint.key();
+    member.member_number = member_number;
+
+    // mint to receiver
+    new_mint.mint_to_receiver()?;
+
+    // remove mint authority
+    new_mint.set_mint_authority(Some(manager.key()))?;
+
+    // transfer minimum rent to mint account
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    spl_pod::optional_keys::OptionalNonZeroPubkey, token_metadata_update_authority, Mint,
+    Token2022, TokenMetadataUpdateAuthority,
+};
+
+use crate::{
+    GroupAuthorityTransfer, Manager, MetadataErrors, TokenGroup, GROUP_ACCOUNT_SEED,
+    GROUP_AUTHORITY_TRANSFER_SEED, MANAGER_SEED,
+};
+
+#[derive(Accounts)]
+pub struct CancelGroupAuthorityTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        has_one = mint,
+        constraint = group.update_authority == authority.key() @ MetadataErrors::InvalidGroupAuthority,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        close = payer,
+        seeds = [GROUP_AUTHORITY_TRANSFER_SEED, group.key().as_ref()],
+        bump,
+        has_one = group,
+    )]
+    pub group_authority_transfer: Account<'info, GroupAuthorityTransfer>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> CancelGroupAuthorityTransfer<'info> {
+    fn restore_metadata_authority(&self, bumps: &CancelGroupAuthorityTransferBumps) -> Result<()> {
+        let seeds: &[&[u8]; 2] = &[MANAGER_SEED, &[bumps.manager]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(), // metadata account is the mint, since data is stored in mint
+            current_authority: self.manager.to_account_info(),
+            new_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_metadata_update_authority(
+            cpi_ctx,
+            OptionalNonZeroPubkey::try_from(Some(self.authority.key()))?,
+        )?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CancelGroupAuthorityTransfer>) -> Result<()> {
+    // hand the escrowed metadata authority back, the pending transfer account is closed by anchor
+    ctx.accounts.restore_metadata_authority(&ctx.bumps)?;
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    burn, close_account, Burn, CloseAccount, Mint, Token2022, TokenAccount,
+};
+
+use crate::{Manager, MetadataErrors, TokenGroup, GROUP_ACCOUNT_SEED, MANAGER_SEED};
+
+#[derive(Accounts)]
+pub struct CloseGroupAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        close = payer,
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        has_one = mint,
+        constraint = group.update_authority == authority.key() @ MetadataErrors::InvalidGroupAuthority,
+        constraint = group.size == 0 @ MetadataErrors::GroupNotEmpty,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+    )]
+    pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> CloseGroupAccount<'info> {
+    fn burn_token(&self) -> Result<()> {
+        let cpi_accounts = Burn {
+            mint: self.mint.to_account_info(),
+            from: self.mint_token_account.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        burn(cpi_ctx, self.mint_token_account.amount)?;
+
+        Ok(())
+    }
+
+    fn close_token_account(&self) -> Result<()> {
+        let cpi_accounts = CloseAccount {
+            account: self.mint_token_account.to_account_info(),
+            destination: self.payer.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        close_account(cpi_ctx)?;
+
+        Ok(())
+    }
+
+    fn close_mint_account(&self, bumps: CloseGroupAccountBumps) -> Result<()> {
+        let seeds: &[&[u8]; 2] = &[MANAGER_SEED, &[bumps.manager]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = CloseAccount {
+            account: self.mint.to_account_info(),
+            destination: self.payer.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        close_account(cpi_ctx)?;
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CloseGroupAccount>) -> Result<()> {
+    // burn the group token
+    if ctx.accounts.mint_token_account.amount > 0 {
+        ctx.accounts.burn_token()?;
+    }
+
+    // close the token account
+    ctx.accounts.close_token_account()?;
+
+    // close the mint account, the group account is closed by anchor
+    ctx.accounts.close_mint_account(ctx.bumps)?;
+
+    Ok(())
+}
@@ -15,16 +15,17 @@ use anchor_spl::{
         mint_to, set_authority,
         spl_pod::optional_keys::OptionalNonZeroPubkey,
         spl_token_2022::{extension::*, instruction::AuthorityType},
-        spl_token_metadata_interface::state::TokenMetadata,
-        token_metadata_initialize, MintTo, SetAuthority, Token2022, TokenMetadataInitialize,
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize, token_metadata_update_field, MintTo, SetAuthority, Token2022,
+        TokenMetadataInitialize, TokenMetadataUpdateField,
     },
 };
 
 use crate::custom_cpi::*;
 
 use crate::{
-    update_account_lamports_to_minimum_balance, Manager, TokenGroup, GROUP_ACCOUNT_SEED,
-    MANAGER_SEED,
+    update_account_lamports_to_minimum_balance, validate_metadata_field, AddMetadataArgs, Manager,
+    TokenGroup, GROUP_ACCOUNT_SEED, MANAGER_SEED,
 };
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -33,6 +34,8 @@ pub struct CreateGroupAccountArgs {
     pub symbol: String,
     pub uri: String,
     pub max_size: u32,
+    // Group level fields such as description or website, royalty defaults go through add_royalties
+    pub additional_metadata: Vec<AddMetadataArgs>,
 }
 
 #[derive(Accounts)]
@@ -83,6 +86,17 @@ impl<'info> CreateGroupAccount<'info> {
         Ok(())
     }
 
+    fn update_metadata_field(&self, field: Field, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(), // metadata account is the mint, since data is stored in mint
+            update_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, field, value)?;
+        Ok(())
+    }
+
     fn mint_to_receiver(&self) -> Result<()> {
         let cpi_ctx = MintTo {
             mint: self.mint.to_account_info(),
@@ -131,7 +145,11 @@ pub fn handler(ctx: Context<CreateGroupAccount>, args: CreateGroupAccountArgs) -
         name: args.name.clone(),
         symbol: args.symbol.clone(),
         uri: args.uri.clone(),
-        additional_metadata: vec![],
+        additional_metadata: args
+            .additional_metadata
+            .iter()
+            .map(|metadata| (metadata.field.clone(), metadata.value.clone()))
+            .collect(),
     };
 
     let mint_size = ExtensionType::try_calculate_account_len::<TMint>(&mint_extension_types)?;
@@ -211,12 +229,20 @@ pub fn handler(ctx: Context<CreateGroupAccount>, args: CreateGroupAccountArgs) -
     ctx.accounts
         .initialize_metadata(args.name, args.symbol, args.uri)?;
 
+    // add group level metadata
+    for metadata in args.additional_metadata {
+        validate_metadata_field(&metadata.field)?;
+        ctx.accounts
+            .update_metadata_field(Field::Key(metadata.field), metadata.value)?;
+    }
+
     // using a custom group account until token22 implements group account
     let group = &mut ctx.accounts.group;
     group.max_size = args.max_size;
     group.update_authority = ctx.accounts.authority.key();
     group.mint = ctx.accounts.mint.key();
     group.size = 0;
+    group.member_count = 0;
 
     // mint to receiver
     ctx.accounts.mint_to_receiver()?;
@@ -0,0 +1,60 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    update_account_lamports_to_minimum_balance, MetadataErrors, TokenGroup, TokenGroupV1,
+    GROUP_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct MigrateGroupAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: group in the previous layout, deserialized in the handler
+    pub group: UncheckedAccount<'info>,
+    /// CHECK: only used to derive the group address
+    pub mint: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Anyone can migrate a group, the new layout only adds a count derived from the old one
+pub fn handler(ctx: Context<MigrateGroupAccount>) -> Result<()> {
+    let group_account = ctx.accounts.group.to_account_info();
+
+    let legacy_group = {
+        let data = group_account.try_borrow_data()?;
+        if data.len() != 8 + TokenGroupV1::LEN {
+            return Err(MetadataErrors::GroupAlreadyMigrated.into());
+        }
+        if data[..8] != TokenGroup::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        TokenGroupV1::deserialize(&mut &data[8..])?
+    };
+
+    let group = TokenGroup {
+        update_authority: legacy_group.update_authority,
+        mint: legacy_group.mint,
+        size: legacy_group.size,
+        max_size: legacy_group.max_size,
+        // Legacy groups numbered members by size, so numbering continues from there
+        member_count: legacy_group.size,
+    };
+
+    group_account.realloc(8 + TokenGroup::INIT_SPACE, false)?;
+    update_account_lamports_to_minimum_balance(
+        group_account.clone(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut data = group_account.try_borrow_mut_data()?;
+    group.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
@@ -1,5 +1,15 @@
+pub mod accept;
+pub mod cancel;
+pub mod close;
 pub mod create;
+pub mod migrate;
+pub mod transfer;
 pub mod update;
 
+pub use accept::*;
+pub use cancel::*;
+pub use close::*;
 pub use create::*;
+pub use migrate::*;
+pub use transfer::*;
 pub use update::*;
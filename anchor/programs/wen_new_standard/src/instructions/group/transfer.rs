@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    spl_pod::optional_keys::OptionalNonZeroPubkey, token_metadata_update_authority, Mint,
+    Token2022, TokenMetadataUpdateAuthority,
+};
+
+use crate::{
+    GroupAuthorityTransfer, Manager, MetadataErrors, TokenGroup, GROUP_ACCOUNT_SEED,
+    GROUP_AUTHORITY_TRANSFER_SEED, MANAGER_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct TransferGroupAuthorityArgs {
+    pub new_authority: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(args: TransferGroupAuthorityArgs)]
+pub struct TransferGroupAuthority<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        has_one = mint,
+        constraint = group.update_authority == authority.key() @ MetadataErrors::InvalidGroupAuthority,
+    )]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        init_if_needed,
+        seeds = [GROUP_AUTHORITY_TRANSFER_SEED, group.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + GroupAuthorityTransfer::INIT_SPACE
+    )]
+    pub group_authority_transfer: Account<'info, GroupAuthorityTransfer>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> TransferGroupAuthority<'info> {
+    fn escrow_metadata_authority(&self) -> Result<()> {
+        let cpi_accounts = TokenMetadataUpdateAuthority {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(), // metadata account is the mint, since data is stored in mint
+            current_authority: self.authority.to_account_info(),
+            new_authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_authority(
+            cpi_ctx,
+            OptionalNonZeroPubkey::try_from(Some(self.manager.key()))?,
+        )?;
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<TransferGroupAuthority>,
+    args: TransferGroupAuthorityArgs,
+) -> Result<()> {
+    let is_pending = ctx.accounts.group_authority_transfer.group == ctx.accounts.group.key();
+
+    // the manager holds the metadata authority until the new authority accepts
+    if !is_pending {
+        ctx.accounts.escrow_metadata_authority()?;
+    }
+
+    // proposing again replaces the pending authority
+    let group_authority_transfer = &mut ctx.accounts.group_authority_transfer;
+    group_authority_transfer.group = ctx.accounts.group.key();
+    group_authority_transfer.new_authority = args.new_authority;
+
+    Ok(())
+}
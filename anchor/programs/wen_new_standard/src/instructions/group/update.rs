@@ -4,7 +4,10 @@ use anchor_spl::token_interface::{
     TokenMetadataUpdateField,
 };
 
-use crate::{MetadataErrors, TokenGroup, GROUP_ACCOUNT_SEED};
+use crate::{
+    update_account_lamports_to_minimum_balance, validate_metadata_field, AddMetadataArgs,
+    MetadataErrors, TokenGroup, GROUP_ACCOUNT_SEED,
+};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct UpdateGroupAccountArgs {
@@ -12,6 +15,8 @@ pub struct UpdateGroupAccountArgs {
     pub symbol: String,
     pub uri: String,
     pub max_size: u32,
+    // Group level fields to add or overwrite, royalty defaults go through add_royalties
+    pub additional_metadata: Vec<AddMetadataArgs>,
 }
 
 #[derive(Accounts)]
@@ -20,15 +25,17 @@ pub struct UpdateGroupAccount<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account()]
-    /// CHECK: can be any account
-    pub authority: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
     #[account(
         mut,
         seeds = [GROUP_ACCOUNT_SEED, mint.key().as_ref()],
         bump,
+        has_one = mint,
+        constraint = group.update_authority == authority.key() @ MetadataErrors::InvalidGroupAuthority,
     )]
     pub group: Account<'info, TokenGroup>,
     #[account(
+        mut,
         mint::token_program = token_program,
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
@@ -66,5 +73,19 @@ pub fn handler(ctx: Context<UpdateGroupAccount>, args: UpdateGroupAccountArgs) -
     // update metadata uri
     ctx.accounts.update_metadata(Field::Uri, args.uri)?;
 
+    // update group level metadata
+    for metadata in args.additional_metadata {
+        validate_metadata_field(&metadata.field)?;
+        ctx.accounts
+            .update_metadata(Field::Key(metadata.field), metadata.value)?;
+    }
+
+    // transfer minimum rent to mint account
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,81 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_creator_amounts, get_mint_metadata, get_royalty_amount, get_royalty_basis_points,
+    ApproveAccount, MetadataErrors, APPROVE_ACCOUNT_SEED,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ApproveTransferArgs {
+    pub buy_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct ApproveTransfer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ApproveAccount::INIT_SPACE,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub approve_account: Account<'info, ApproveAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> ApproveTransfer<'info> {
+    fn pay_creator(&self, creator: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.payer.to_account_info(),
+            to: creator,
+        };
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+        transfer(cpi_ctx, amount)?;
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ApproveTransfer<'info>>,
+    args: ApproveTransferArgs,
+) -> Result<()> {
+    let metadata = get_mint_metadata(&mut ctx.accounts.mint.to_account_info())?;
+
+    // a zero sale price would move royalty bearing mints without paying their creators
+    if get_royalty_basis_points(&metadata)? > 0 {
+        require_gt!(args.buy_amount, 0, MetadataErrors::BuyAmountInvalid);
+    }
+
+    let royalty_amount = get_royalty_amount(&metadata, args.buy_amount)?;
+
+    // creators are passed as remaining accounts
+    for (creator, creator_amount) in get_creator_amounts(&metadata, royalty_amount)? {
+        let creator_account = ctx
+            .remaining_accounts
+            .iter()
+            .find(|account| account.key() == creator)
+            .ok_or(MetadataErrors::CreatorAddressInvalid)?;
+
+        if creator_amount > 0 {
+            ctx.accounts
+                .pay_creator(creator_account.clone(), creator_amount)?;
+        }
+    }
+
+    // stamp the slot, execute only lets transfers through within the same slot
+    ctx.accounts.approve_account.slot = Clock::get()?.slot;
+
+    Ok(())
+}
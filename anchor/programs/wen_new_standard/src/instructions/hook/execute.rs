@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    spl_token_2022::{
+        extension::{
+            transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::Account as TokenAccountState,
+    },
+    Mint, TokenAccount,
+};
+
+use crate::{
+    get_mint_metadata, get_royalty_basis_points, ApproveAccount, MetadataErrors, MintErrors,
+    APPROVE_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteTransferHook<'info> {
+    #[account(
+        token::mint = mint,
+    )]
+    pub source_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        token::mint = mint,
+    )]
+    pub destination_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: source account owner or delegate, checked by the token program
+    pub owner_delegate: UncheckedAccount<'info>,
+    #[account(
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: extra metas account
+    pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: only exists once a transfer has been approved
+    pub approve_account: UncheckedAccount<'info>,
+}
+
+impl<'info> ExecuteTransferHook<'info> {
+    fn check_is_transferring(&self) -> Result<()> {
+        let source_account_info = self.source_account.to_account_info();
+        let source_account_data = source_account_info.try_borrow_data()?;
+        let source_account =
+            StateWithExtensions::<TokenAccountState>::unpack(&source_account_data)?;
+        let transfer_hook_account = source_account.get_extension::<TransferHookAccount>()?;
+
+        if !bool::from(transfer_hook_account.transferring) {
+            return Err(MintErrors::NotTransferring.into());
+        }
+
+        Ok(())
+    }
+
+    fn consume_approval(&self) -> Result<()> {
+        if self.approve_account.data_is_empty() {
+            return Err(MetadataErrors::MissingApproveAccount.into());
+        }
+
+        let mut approve_account_data = self.approve_account.try_borrow_mut_data()?;
+        let mut approve_account = ApproveAccount::try_deserialize(&mut &approve_account_data[..])?;
+        if approve_account.slot != Clock::get()?.slot {
+            return Err(MetadataErrors::ExpiredApproveAccount.into());
+        }
+
+        // an approval only covers a single transfer
+        approve_account.slot = 0;
+        approve_account.try_serialize(&mut &mut approve_account_data[..])?;
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ExecuteTransferHook>, _amount: u64) -> Result<()> {
+    // only the token program can trigger the hook, mid transfer
+    ctx.accounts.check_is_transferring()?;
+
+    let metadata = get_mint_metadata(&mut ctx.accounts.mint.to_account_info())?;
+
+    // every transfer of a mint with royalties needs a fresh approval, gifts approve a zero amount
+    if get_royalty_basis_points(&metadata)? > 0 {
+        ctx.accounts.consume_approval()?;
+    }
+
+    Ok(())
+}
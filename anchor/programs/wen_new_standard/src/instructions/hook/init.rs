@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::{
+    get_approve_account_pda, get_meta_list_size, write_extra_meta_list, META_LIST_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct InitializeExtraMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        space = get_meta_list_size(get_approve_account_pda(mint.key())),
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: extra metas account, data is written in the handler
+    pub extra_metas_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+// Mints get their meta list on creation, this backfills mints created before that
+pub fn handler(ctx: Context<InitializeExtraMetaList>) -> Result<()> {
+    write_extra_meta_list(
+        &ctx.accounts.extra_metas_account.to_account_info(),
+        ctx.accounts.mint.key(),
+    )
+}
@@ -0,0 +1,7 @@
+pub mod approve;
+pub mod execute;
+pub mod init;
+
+pub use approve::*;
+pub use execute::*;
+pub use init::*;
@@ -4,7 +4,10 @@ use anchor_spl::token_interface::{
     burn, close_account, Burn, CloseAccount, Mint, Token2022, TokenAccount,
 };
 
-use crate::{Manager, MANAGER_SEED};
+use crate::{
+    close_program_account, BurnMintEvent, Manager, TokenGroup, TokenGroupMember,
+    APPROVE_ACCOUNT_SEED, MANAGER_SEED, MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED,
+};
 
 #[derive(Accounts)]
 pub struct BurnMintAccount<'info> {
@@ -21,6 +24,31 @@ pub struct BurnMintAccount<'info> {
         associated_token::authority = user,
     )]
     pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    pub group: Account<'info, TokenGroup>,
+    #[account(
+        mut,
+        close = payer,
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        has_one = group,
+        has_one = mint,
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: closed along with the mint, when it was created
+    pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: closed along with the mint, when a transfer was ever approved
+    pub approve_account: UncheckedAccount<'info>,
     #[account(
         seeds = [MANAGER_SEED],
         bump
@@ -61,6 +89,19 @@ impl<'info> BurnMintAccount<'info> {
         Ok(())
     }
 
+    fn close_hook_accounts(&self) -> Result<()> {
+        close_program_account(
+            self.extra_metas_account.to_account_info(),
+            self.payer.to_account_info(),
+        )?;
+        close_program_account(
+            self.approve_account.to_account_info(),
+            self.payer.to_account_info(),
+        )?;
+
+        Ok(())
+    }
+
     fn burn_token(&self) -> Result<()> {
         let cpi_accounts = Burn {
             mint: self.mint.to_account_info(),
@@ -84,7 +125,17 @@ pub fn handler(ctx: Context<BurnMintAccount>) -> Result<()> {
     // close the mint account
     ctx.accounts.close_mint_account(ctx.bumps)?;
 
-    // TODO: decrease collection number of the group
+    // close the transfer hook accounts of the mint
+    ctx.accounts.close_hook_accounts()?;
+
+    // decrease collection number of the group, the member account is closed by anchor
+    ctx.accounts.group.decrement_size()?;
+
+    emit!(BurnMintEvent {
+        mint: ctx.accounts.mint.key(),
+        group: Some(ctx.accounts.group.key()),
+        member_number: Some(ctx.accounts.member.member_number),
+    });
 
     Ok(())
 }
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    burn, close_account, Burn, CloseAccount, Mint, Token2022, TokenAccount,
+};
+
+use crate::{
+    close_program_account, BurnMintEvent, Manager, MintErrors, APPROVE_ACCOUNT_SEED, MANAGER_SEED,
+    MEMBER_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct BurnNonMemberMintAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account()]
+    pub user: Signer<'info>,
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::token_program = token_program,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    pub mint_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        constraint = member.data_is_empty() @ MintErrors::MintIsGroupMember,
+    )]
+    /// CHECK: must not exist, members are burned through `burn_mint_account`
+    pub member: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: closed along with the mint, when it was created
+    pub extra_metas_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: closed along with the mint, when a transfer was ever approved
+    pub approve_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> BurnNonMemberMintAccount<'info> {
+    fn close_token_account(&self) -> Result<()> {
+        let cpi_accounts = CloseAccount {
+            account: self.mint_token_account.to_account_info(),
+            destination: self.payer.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        close_account(cpi_ctx)?;
+
+        Ok(())
+    }
+
+    fn close_mint_account(&self, bumps: BurnNonMemberMintAccountBumps) -> Result<()> {
+        let seeds: &[&[u8]; 2] = &[MANAGER_SEED, &[bumps.manager]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = CloseAccount {
+            account: self.mint.to_account_info(),
+            destination: self.payer.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        close_account(cpi_ctx)?;
+
+        Ok(())
+    }
+
+    fn close_hook_accounts(&self) -> Result<()> {
+        close_program_account(
+            self.extra_metas_account.to_account_info(),
+            self.payer.to_account_info(),
+        )?;
+        close_program_account(
+            self.approve_account.to_account_info(),
+            self.payer.to_account_info(),
+        )?;
+
+        Ok(())
+    }
+
+    fn burn_token(&self) -> Result<()> {
+        let cpi_accounts = Burn {
+            mint: self.mint.to_account_info(),
+            from: self.mint_token_account.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        burn(cpi_ctx, 1)?;
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<BurnNonMemberMintAccount>) -> Result<()> {
+    // burn the token
+    ctx.accounts.burn_token()?;
+
+    // close the token account
+    ctx.accounts.close_token_account()?;
+
+    // close the mint account
+    ctx.accounts.close_mint_account(ctx.bumps)?;
+
+    // close the transfer hook accounts of the mint
+    ctx.accounts.close_hook_accounts()?;
+
+    emit!(BurnMintEvent {
+        mint: ctx.accounts.mint.key(),
+        group: None,
+        member_number: None,
+    });
+
+    Ok(())
+}
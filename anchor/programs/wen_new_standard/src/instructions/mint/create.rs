@@ -1,29 +1,18 @@
-use anchor_lang::{
-    prelude::*,
-    solana_program::entrypoint::ProgramResult,
-    system_program::{create_account, CreateAccount},
-};
+use anchor_lang::prelude::*;
 
 use anchor_spl::{
-    associated_token::{
-        create as create_associated_token, AssociatedToken, Create as CreateAssociatedToken,
-    },
-    token_2022::{
-        initialize_mint2, initialize_mint_close_authority, spl_token_2022::state::Mint as TMint,
-        InitializeMint2, InitializeMintCloseAuthority,
-    },
+    associated_token::AssociatedToken,
     token_interface::{
-        mint_to, set_authority,
-        spl_pod::optional_keys::OptionalNonZeroPubkey,
-        spl_token_2022::{extension::*, instruction::AuthorityType},
-        spl_token_metadata_interface::state::TokenMetadata,
-        token_metadata_initialize, MintTo, SetAuthority, Token2022, TokenMetadataInitialize,
+        set_authority, spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_2022::instruction::AuthorityType,
+        spl_token_metadata_interface::state::TokenMetadata, SetAuthority, Token2022,
     },
 };
 
-use crate::{update_account_lamports_to_minimum_balance, Manager, MANAGER_SEED};
-
-use crate::custom_cpi::*;
+use crate::{
+    get_approve_account_pda, get_meta_list_size, update_account_lamports_to_minimum_balance,
+    write_extra_meta_list, Manager, MintExtensions, NewMint, MANAGER_SEED, META_LIST_ACCOUNT_SEED,
+};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct CreateMintAccountArgs {
@@ -31,6 +20,10 @@ pub struct CreateMintAccountArgs {
     pub symbol: String,
     pub uri: String,
     pub permanent_delegate: Option<Pubkey>,
+    // Adds the NonTransferable extension, the mint can only be burned once minted
+    pub non_transferable: bool,
+    // Removes the mint authority right away instead of handing it to the manager
+    pub finalize: bool,
 }
 
 #[derive(Accounts)]
@@ -49,6 +42,15 @@ pub struct CreateMintAccount<'info> {
     /// CHECK: Localnet changes
     #[account(mut)]
     pub mint_token_account: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = get_meta_list_size(get_approve_account_pda(mint.key())),
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: extra metas account, data is written in the handler
+    pub extra_metas_account: UncheckedAccount<'info>,
     #[account(
         seeds = [MANAGER_SEED],
         bump
@@ -61,45 +63,18 @@ pub struct CreateMintAccount<'info> {
 }
 
 impl<'info> CreateMintAccount<'info> {
-    fn initialize_token_metadata(
-        &self,
-        name: String,
-        symbol: String,
-        uri: String,
-    ) -> ProgramResult {
-        let cpi_accounts = TokenMetadataInitialize {
-            token_program_id: self.token_program.to_account_info(),
-            mint: self.mint.to_account_info(),
-            metadata: self.mint.to_account_info(), // metadata account is the mint, since data is stored in mint
-            mint_authority: self.authority.to_account_info(),
-            update_authority: self.authority.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
-        token_metadata_initialize(cpi_ctx, name, symbol, uri)?;
-        Ok(())
-    }
-
-    fn mint_to_receiver(&self) -> Result<()> {
-        let cpi_ctx = MintTo {
-            mint: self.mint.to_account_info(),
-            to: self.mint_token_account.to_account_info(),
+    fn new_mint(&self) -> NewMint<'info> {
+        NewMint {
+            payer: self.payer.to_account_info(),
             authority: self.authority.to_account_info(),
-        };
-        let cpi_accounts = CpiContext::new(self.token_program.to_account_info(), cpi_ctx);
-        mint_to(cpi_accounts, 1)?;
-        Ok(())
-    }
-
-    fn update_mint_authority(&self) -> Result<()> {
-        let cpi_accounts = SetAuthority {
-            current_authority: self.authority.to_account_info(),
-            account_or_mint: self.mint.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
-        // manager needs to be the new authority so that when solana upgrades to support member accounts, the mint can be updated
-        // this will updated to None once solana supports member accounts
-        set_authority(cpi_ctx, AuthorityType::MintTokens, Some(self.manager.key()))?;
-        Ok(())
+            receiver: self.receiver.to_account_info(),
+            mint: self.mint.to_account_info(),
+            mint_token_account: self.mint_token_account.to_account_info(),
+            manager: self.manager.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
     }
 
     fn set_default_permanent_delegate(&self, bump: u8) -> Result<()> {
@@ -120,115 +95,35 @@ impl<'info> CreateMintAccount<'info> {
 }
 
 pub fn handler(ctx: Context<CreateMintAccount>, args: CreateMintAccountArgs) -> Result<()> {
-    let payer = &ctx.accounts.payer;
     let authority = &ctx.accounts.authority;
-    let receiver = &ctx.accounts.receiver;
     let mint = &ctx.accounts.mint;
     let manager = &ctx.accounts.manager;
-    let mint_token_account = &ctx.accounts.mint_token_account;
-
-    let system_program = &ctx.accounts.system_program;
-    let token_program = &ctx.accounts.token_program;
-    let associated_token_program = &ctx.accounts.associated_token_program;
+    let new_mint = ctx.accounts.new_mint();
 
-    let mint_extension_types = vec![
-        ExtensionType::MintCloseAuthority,
-        ExtensionType::MetadataPointer,
-        ExtensionType::GroupMemberPointer,
-        ExtensionType::PermanentDelegate,
-    ];
+    let extensions = MintExtensions {
+        member_address: mint.key(),
+        permanent_delegate: Some(args.permanent_delegate.unwrap_or(manager.key())),
+        non_transferable: args.non_transferable,
+    };
 
     let metadata = TokenMetadata {
         update_authority: OptionalNonZeroPubkey::try_from(Some(authority.key())).unwrap(),
         mint: mint.key(),
-        name: args.name.clone(),
-        symbol: args.symbol.clone(),
-        uri: args.uri.clone(),
+        name: args.name,
+        symbol: args.symbol,
+        uri: args.uri,
         additional_metadata: vec![],
     };
 
-    let mint_size = ExtensionType::try_calculate_account_len::<TMint>(&mint_extension_types)?;
-    let metadata_size = metadata.tlv_size_of()?;
-    let rent_lamports = Rent::get()?.minimum_balance(mint_size + metadata_size);
+    new_mint.create(&extensions, &metadata)?;
 
-    create_account(
-        CpiContext::new(
-            system_program.to_account_info(),
-            CreateAccount {
-                from: payer.to_account_info(),
-                to: mint.to_account_info(),
-            },
-        ),
-        rent_lamports,
-        u64::try_from(mint_size).unwrap(),
-        token_program.key,
+    // the transfer hook resolves its extra accounts from the meta list
+    write_extra_meta_list(
+        &ctx.accounts.extra_metas_account.to_account_info(),
+        mint.key(),
     )?;
 
-    initialize_mint_close_authority(
-        CpiContext::new(
-            token_program.to_account_info(),
-            InitializeMintCloseAuthority {
-                mint: mint.to_account_info(),
-            },
-        ),
-        Some(&manager.key()),
-    )?;
-
-    initialize_metadata_pointer(
-        CpiContext::new(
-            token_program.to_account_info(),
-            InitializeMetadataPointer {
-                mint: mint.to_account_info(),
-            },
-        ),
-        Some(authority.key()),
-        Some(mint.key()),
-    )?;
-
-    initialize_group_member_pointer(
-        CpiContext::new(
-            token_program.to_account_info(),
-            InitializeGroupMemberPointer {
-                mint: mint.to_account_info(),
-            },
-        ),
-        Some(manager.key()),
-        Some(mint.key()),
-    )?;
-
-    initialize_permanent_delegate(
-        CpiContext::new(
-            token_program.to_account_info(),
-            InitializePermanentDelegate {
-                mint: mint.to_account_info(),
-            },
-        ),
-        &args.permanent_delegate.unwrap_or(manager.key()),
-    )?;
-
-    initialize_mint2(
-        CpiContext::new(
-            token_program.to_account_info(),
-            InitializeMint2 {
-                mint: mint.to_account_info(),
-            },
-        ),
-        0,
-        &authority.key(),
-        Some(&manager.key()),
-    )?;
-
-    create_associated_token(CpiContext::new(
-        associated_token_program.to_account_info(),
-        CreateAssociatedToken {
-            payer: payer.to_account_info(),
-            associated_token: mint_token_account.to_account_info(),
-            authority: receiver.to_account_info(),
-            mint: mint.to_account_info(),
-            system_program: system_program.to_account_info(),
-            token_program: token_program.to_account_info(),
-        },
-    ))?;
+    new_mint.create_receiver_token_account()?;
 
     if args.permanent_delegate.is_none() {
         ctx.accounts
@@ -236,15 +131,19 @@ pub fn handler(ctx: Context<CreateMintAccount>, args: CreateMintAccountArgs) ->
     }
 
     // initialize token metadata
-    ctx.accounts
-        .initialize_token_metadata(args.name, args.symbol, args.uri)?;
+    new_mint.initialize_token_metadata(metadata)?;
 
     // mint to receiver
-    ctx.accounts.mint_to_receiver()?;
+    new_mint.mint_to_receiver()?;
 
-    // remove mint authority
-    ctx.accounts.update_mint_authority()?;
-    // TODO: Once Token Extension program supports Group/Member accounts natively, should lock Mint Authority
+    // remove mint authority, non finalized mints can be locked later through `finalize_mint`
+    if args.finalize {
+        new_mint.set_mint_authority(None)?;
+    } else {
+        // manager needs to be the new authority so that when solana upgrades to support member accounts, the mint can be updated
+        // this will updated to None once solana supports member accounts
+        new_mint.set_mint_authority(Some(manager.key()))?;
+    }
 
     // transfer minimum rent to mint account
     update_account_lamports_to_minimum_balance(
@@ -0,0 +1,67 @@
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+
+use anchor_spl::token_interface::{
+    set_authority, spl_token_2022::instruction::AuthorityType, Mint, SetAuthority, Token2022,
+};
+
+use crate::{
+    get_mint_metadata, Manager, MintErrors, TokenGroupMember, MANAGER_SEED, MEMBER_ACCOUNT_SEED,
+};
+
+#[derive(Accounts)]
+pub struct FinalizeMintAccount<'info> {
+    #[account()]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        constraint = mint.mint_authority == COption::Some(manager.key()) @MintErrors::InvalidMintAuthority
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    // Standalone mints are finalized at creation, others once they joined a group
+    #[account(
+        seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
+        bump,
+        has_one = mint,
+    )]
+    pub member: Account<'info, TokenGroupMember>,
+    #[account(
+        seeds = [MANAGER_SEED],
+        bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> FinalizeMintAccount<'info> {
+    fn remove_mint_authority(&self, bumps: FinalizeMintAccountBumps) -> Result<()> {
+        let seeds: &[&[u8]; 2] = &[MANAGER_SEED, &[bumps.manager]];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = SetAuthority {
+            current_authority: self.manager.to_account_info(),
+            account_or_mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        set_authority(cpi_ctx, AuthorityType::MintTokens, None)?;
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<FinalizeMintAccount>) -> Result<()> {
+    // only the metadata update authority can lock the supply
+    let metadata = get_mint_metadata(&mut ctx.accounts.mint.to_account_info())?;
+    if Option::<Pubkey>::from(metadata.update_authority) != Some(ctx.accounts.authority.key()) {
+        return Err(MintErrors::InvalidMintAuthority.into());
+    }
+
+    // remove the manager mint authority, nothing can be minted anymore
+    ctx.accounts.remove_mint_authority(ctx.bumps)?;
+
+    Ok(())
+}
@@ -5,8 +5,8 @@ use anchor_spl::token_interface::{
 };
 
 use crate::{
-    get_bump_in_seed_form, Manager, TokenGroup, TokenGroupMember, MANAGER_SEED,
-    MEMBER_ACCOUNT_SEED, TOKEN22,
+    get_bump_in_seed_form, Manager, MetadataErrors, TokenGroup, TokenGroupMember, MANAGER_SEED,
+    MASTER_EDITION_SEED, MEMBER_ACCOUNT_SEED, TOKEN22,
 };
 
 #[derive(Accounts)]
@@ -21,6 +21,13 @@ pub struct AddGroup<'info> {
         constraint = group.update_authority == authority.key(),
     )]
     pub group: Account<'info, TokenGroup>,
+    #[account(
+        seeds = [MASTER_EDITION_SEED, group.key().as_ref()],
+        bump,
+        constraint = master_edition.data_is_empty() @ MetadataErrors::GroupIsMasterEdition,
+    )]
+    /// CHECK: only exists for master edition groups, which take prints only
+    pub master_edition: UncheckedAccount<'info>,
     #[account(
         init,
         seeds = [MEMBER_ACCOUNT_SEED, mint.key().as_ref()],
@@ -71,7 +78,8 @@ pub fn handler(ctx: Context<AddGroup>) -> Result<()> {
     let member = &mut ctx.accounts.member;
     member.group = group.key();
     member.mint = ctx.accounts.mint.key();
-    member.member_number = group.size;
+    // size drops when members leave, the member count keeps numbers unique
+    member.member_number = group.increment_member_count()?;
 
     let member_address = member.key();
 
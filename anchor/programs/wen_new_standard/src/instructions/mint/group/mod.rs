@@ -1,3 +1,5 @@
 pub mod add;
+pub mod remove;
 
 pub use add::*;
+pub use remove::*;
@@ -1,5 +1,3 @@
-use std::str::FromStr;
-
 use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
 
 use anchor_spl::token_interface::{
@@ -7,7 +5,7 @@ use anchor_spl::token_interface::{
     TokenMetadataUpdateField,
 };
 
-use crate::{errors::MetadataErrors, update_account_lamports_to_minimum_balance};
+use crate::{update_account_lamports_to_minimum_balance, validate_metadata_field};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct AddMetadataArgs {
@@ -45,18 +43,13 @@ impl<'info> AddMetadata<'info> {
 
 pub fn handler(ctx: Context<AddMetadata>, args: Vec<AddMetadataArgs>) -> Result<()> {
     for metadata_arg in args {
-        // validate that the field is not a publickey
-        match Pubkey::from_str(&metadata_arg.field) {
-            Ok(_) => {
-                return Err(MetadataErrors::InvalidField.into());
-            }
-            Err(_) => {
-                ctx.accounts.update_token_metadata_field(
-                    Field::Key(metadata_arg.field),
-                    metadata_arg.value.to_string(),
-                )?;
-            }
-        }
+        // validate that the field is not a publickey nor a reserved field
+        validate_metadata_field(&metadata_arg.field)?;
+
+        ctx.accounts.update_token_metadata_field(
+            Field::Key(metadata_arg.field),
+            metadata_arg.value.to_string(),
+        )?;
     }
 
     // transfer minimum rent to mint account
@@ -1,12 +1,10 @@
-use std::str::FromStr;
-
 use anchor_lang::{prelude::*, solana_program::program::invoke};
 
 use anchor_spl::token_interface::{Mint, Token2022};
 
 use spl_token_metadata_interface::instruction::remove_key;
 
-use crate::{errors::MetadataErrors, update_account_lamports_to_minimum_balance};
+use crate::{update_account_lamports_to_minimum_balance, validate_metadata_field};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct RemoveMetadataArgs {
@@ -52,16 +50,11 @@ impl<'info> RemoveMetadata<'info> {
 
 pub fn handler(ctx: Context<RemoveMetadata>, args: Vec<RemoveMetadataArgs>) -> Result<()> {
     for metadata_arg in args {
-        // validate that the field is not a publickey
-        match Pubkey::from_str(&metadata_arg.field) {
-            Ok(_) => {
-                return Err(MetadataErrors::InvalidField.into());
-            }
-            Err(_) => {
-                ctx.accounts
-                    .remove_token_metadata_field(metadata_arg.field)?;
-            }
-        }
+        // validate that the field is not a publickey nor a reserved field
+        validate_metadata_field(&metadata_arg.field)?;
+
+        ctx.accounts
+            .remove_token_metadata_field(metadata_arg.field)?;
     }
 
     // transfer minimum rent to mint account
@@ -1,14 +1,22 @@
 pub mod burn;
+pub mod burn_non_member;
 pub mod create;
+pub mod finalize;
 pub mod freeze;
+mod shared;
 pub mod thaw;
 
 pub mod group;
 pub mod metadata;
+pub mod royalties;
 
 pub use burn::*;
+pub use burn_non_member::*;
 pub use create::*;
+pub use finalize::*;
 pub use freeze::*;
 pub use group::*;
 pub use metadata::*;
+pub use royalties::*;
+pub(crate) use shared::*;
 pub use thaw::*;
@@ -0,0 +1,111 @@
+use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
+
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field, Mint, Token2022,
+    TokenMetadataUpdateField,
+};
+
+use crate::{
+    errors::MetadataErrors, get_mint_metadata, has_royalties,
+    update_account_lamports_to_minimum_balance, ROYALTY_BASIS_POINTS_FIELD,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct CreatorWithShare {
+    pub address: Pubkey,
+    pub share: u8,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateRoyaltiesArgs {
+    pub royalty_basis_points: u16,
+    pub creators: Vec<CreatorWithShare>,
+}
+
+impl UpdateRoyaltiesArgs {
+    pub fn validate(&self) -> Result<()> {
+        if self.royalty_basis_points > 10000 {
+            return Err(MetadataErrors::RoyaltyBasisPointsInvalid.into());
+        }
+
+        let total_share: u16 = self
+            .creators
+            .iter()
+            .map(|creator| u16::from(creator.share))
+            .sum();
+        if total_share != 100 {
+            return Err(MetadataErrors::CreatorShareInvalid.into());
+        }
+
+        for (index, creator) in self.creators.iter().enumerate() {
+            if self.creators[..index]
+                .iter()
+                .any(|other| other.address == creator.address)
+            {
+                return Err(MetadataErrors::CreatorAddressInvalid.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AddRoyalties<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> AddRoyalties<'info> {
+    fn update_token_metadata_field(&self, field: Field, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            update_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, field, value)?;
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<AddRoyalties>, args: UpdateRoyaltiesArgs) -> Result<()> {
+    args.validate()?;
+
+    // royalties can only be added once, modify_royalties changes them afterwards
+    let metadata = get_mint_metadata(&mut ctx.accounts.mint.to_account_info())?;
+    if has_royalties(&metadata) {
+        return Err(MetadataErrors::RoyaltiesAlreadySet.into());
+    }
+
+    ctx.accounts.update_token_metadata_field(
+        Field::Key(ROYALTY_BASIS_POINTS_FIELD.to_owned()),
+        args.royalty_basis_points.to_string(),
+    )?;
+
+    // creators are stored with their pubkey as field and their share as value
+    for creator in args.creators {
+        ctx.accounts.update_token_metadata_field(
+            Field::Key(creator.address.to_string()),
+            creator.share.to_string(),
+        )?;
+    }
+
+    // transfer minimum rent to mint account
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
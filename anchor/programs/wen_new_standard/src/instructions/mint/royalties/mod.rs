@@ -0,0 +1,5 @@
+pub mod add;
+pub mod modify;
+
+pub use add::*;
+pub use modify::*;
@@ -0,0 +1,103 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{entrypoint::ProgramResult, program::invoke},
+};
+
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field, Mint, Token2022,
+    TokenMetadataUpdateField,
+};
+use spl_token_metadata_interface::instruction::remove_key;
+
+use crate::{
+    errors::MetadataErrors, get_creator_shares, get_mint_metadata, has_royalties,
+    update_account_lamports_to_minimum_balance, UpdateRoyaltiesArgs, ROYALTY_BASIS_POINTS_FIELD,
+};
+
+#[derive(Accounts)]
+pub struct ModifyRoyalties<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+impl<'info> ModifyRoyalties<'info> {
+    fn update_token_metadata_field(&self, field: Field, value: String) -> ProgramResult {
+        let cpi_accounts = TokenMetadataUpdateField {
+            token_program_id: self.token_program.to_account_info(),
+            metadata: self.mint.to_account_info(),
+            update_authority: self.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token_metadata_update_field(cpi_ctx, field, value)?;
+        Ok(())
+    }
+
+    fn remove_token_metadata_field(&self, field: String) -> Result<()> {
+        invoke(
+            &remove_key(
+                &self.token_program.key(),
+                &self.mint.key(),
+                &self.authority.key(),
+                field,
+                false,
+            ),
+            &[
+                self.mint.to_account_info(),
+                self.authority.to_account_info(),
+            ],
+        )?;
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ModifyRoyalties>, args: UpdateRoyaltiesArgs) -> Result<()> {
+    args.validate()?;
+
+    let metadata = get_mint_metadata(&mut ctx.accounts.mint.to_account_info())?;
+    if !has_royalties(&metadata) {
+        return Err(MetadataErrors::RoyaltiesNotSet.into());
+    }
+
+    // drop the creators that are not part of the new split
+    for (creator, _) in get_creator_shares(&metadata)? {
+        if !args
+            .creators
+            .iter()
+            .any(|new_creator| new_creator.address == creator)
+        {
+            ctx.accounts
+                .remove_token_metadata_field(creator.to_string())?;
+        }
+    }
+
+    ctx.accounts.update_token_metadata_field(
+        Field::Key(ROYALTY_BASIS_POINTS_FIELD.to_owned()),
+        args.royalty_basis_points.to_string(),
+    )?;
+
+    for creator in args.creators {
+        ctx.accounts.update_token_metadata_field(
+            Field::Key(creator.address.to_string()),
+            creator.share.to_string(),
+        )?;
+    }
+
+    // transfer minimum rent to mint account
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{create_account, CreateAccount},
+};
+
+use anchor_spl::{
+    associated_token::{create as create_associated_token, Create as CreateAssociatedToken},
+    token_2022::{
+        initialize_mint2, initialize_mint_close_authority, spl_token_2022::state::Mint as TMint,
+        InitializeMint2, InitializeMintCloseAuthority,
+    },
+    token_interface::{
+        mint_to, set_authority,
+        spl_token_2022::{extension::*, instruction::AuthorityType},
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize, token_metadata_update_field, MintTo, SetAuthority,
+        TokenMetadataInitialize, TokenMetadataUpdateField,
+    },
+};
+
+use crate::custom_cpi::*;
+
+/// Extensions a new mint carries besides the close authority, pointers and transfer hook
+pub(crate) struct MintExtensions {
+    pub member_address: Pubkey,
+    pub permanent_delegate: Option<Pubkey>,
+    pub non_transferable: bool,
+}
+
+/// Accounts used to create a mint, hold its metadata and mint it to the receiver
+pub(crate) struct NewMint<'info> {
+    pub payer: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub receiver: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub mint_token_account: AccountInfo<'info>,
+    pub manager: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> NewMint<'info> {
+    /// Creates the mint account with rent for `metadata` and initializes its extensions
+    pub fn create(&self, extensions: &MintExtensions, metadata: &TokenMetadata) -> Result<()> {
+        let mut mint_extension_types = vec![
+            ExtensionType::MintCloseAuthority,
+            ExtensionType::MetadataPointer,
+            ExtensionType::GroupMemberPointer,
+        ];
+        if extensions.permanent_delegate.is_some() {
+            mint_extension_types.push(ExtensionType::PermanentDelegate);
+        }
+        mint_extension_types.push(ExtensionType::TransferHook);
+        if extensions.non_transferable {
+            mint_extension_types.push(ExtensionType::NonTransferable);
+        }
+
+        let mint_size = ExtensionType::try_calculate_account_len::<TMint>(&mint_extension_types)?;
+        let metadata_size = metadata.tlv_size_of()?;
+        let rent_lamports = Rent::get()?.minimum_balance(mint_size + metadata_size);
+
+        create_account(
+            CpiContext::new(
+                self.system_program.clone(),
+                CreateAccount {
+                    from: self.payer.clone(),
+                    to: self.mint.clone(),
+                },
+            ),
+            rent_lamports,
+            u64::try_from(mint_size).unwrap(),
+            self.token_program.key,
+        )?;
+
+        initialize_mint_close_authority(
+            CpiContext::new(
+                self.token_program.clone(),
+                InitializeMintCloseAuthority {
+                    mint: self.mint.clone(),
+                },
+            ),
+            Some(self.manager.key),
+        )?;
+
+        initialize_metadata_pointer(
+            CpiContext::new(
+                self.token_program.clone(),
+                InitializeMetadataPointer {
+                    mint: self.mint.clone(),
+                },
+            ),
+            Some(self.authority.key()),
+            Some(self.mint.key()),
+        )?;
+
+        initialize_group_member_pointer(
+            CpiContext::new(
+                self.token_program.clone(),
+                InitializeGroupMemberPointer {
+                    mint: self.mint.clone(),
+                },
+            ),
+            Some(self.manager.key()),
+            Some(extensions.member_address),
+        )?;
+
+        if let Some(permanent_delegate) = extensions.permanent_delegate {
+            initialize_permanent_delegate(
+                CpiContext::new(
+                    self.token_program.clone(),
+                    InitializePermanentDelegate {
+                        mint: self.mint.clone(),
+                    },
+                ),
+                &permanent_delegate,
+            )?;
+        }
+
+        initialize_transfer_hook(
+            CpiContext::new(
+                self.token_program.clone(),
+                InitializeTransferHook {
+                    mint: self.mint.clone(),
+                },
+            ),
+            Some(self.manager.key()),
+        )?;
+
+        if extensions.non_transferable {
+            initialize_mint_non_transferable(CpiContext::new(
+                self.token_program.clone(),
+                InitializeMintNonTransferrable {
+                    mint: self.mint.clone(),
+                },
+            ))?;
+        }
+
+        initialize_mint2(
+            CpiContext::new(
+                self.token_program.clone(),
+                InitializeMint2 {
+                    mint: self.mint.clone(),
+                },
+            ),
+            0,
+            self.authority.key,
+            Some(self.manager.key),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn create_receiver_token_account(&self) -> Result<()> {
+        create_associated_token(CpiContext::new(
+            self.associated_token_program.clone(),
+            CreateAssociatedToken {
+                payer: self.payer.clone(),
+                associated_token: self.mint_token_account.clone(),
+                authority: self.receiver.clone(),
+                mint: self.mint.clone(),
+                system_program: self.system_program.clone(),
+                token_program: self.token_program.clone(),
+            },
+        ))
+    }
+
+    /// Writes `metadata` into the mint, the authority stays its update authority
+    pub fn initialize_token_metadata(&self, metadata: TokenMetadata) -> Result<()> {
+        let cpi_accounts = TokenMetadataInitialize {
+            token_program_id: self.token_program.clone(),
+            mint: self.mint.clone(),
+            metadata: self.mint.clone(), // metadata account is the mint, since data is stored in mint
+            mint_authority: self.authority.clone(),
+            update_authority: self.authority.clone(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.clone(), cpi_accounts);
+        token_metadata_initialize(cpi_ctx, metadata.name, metadata.symbol, metadata.uri)?;
+
+        for (field, value) in metadata.additional_metadata {
+            let cpi_accounts = TokenMetadataUpdateField {
+                token_program_id: self.token_program.clone(),
+                metadata: self.mint.clone(),
+                update_authority: self.authority.clone(),
+            };
+            let cpi_ctx = CpiContext::new(self.token_program.clone(), cpi_accounts);
+            token_metadata_update_field(cpi_ctx, Field::Key(field), value)?;
+        }
+        Ok(())
+    }
+
+    pub fn mint_to_receiver(&self) -> Result<()> {
+        let cpi_accounts = MintTo {
+            mint: self.mint.clone(),
+            to: self.mint_token_account.clone(),
+            authority: self.authority.clone(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.clone(), cpi_accounts);
+        mint_to(cpi_ctx, 1)
+    }
+
+    /// Hands the mint authority over, `None` removes it for good
+    pub fn set_mint_authority(&self, new_authority: Option<Pubkey>) -> Result<()> {
+        let cpi_accounts = SetAuthority {
+            current_authority: self.authority.clone(),
+            account_or_mint: self.mint.clone(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.clone(), cpi_accounts);
+        set_authority(cpi_ctx, AuthorityType::MintTokens, new_authority)
+    }
+}
@@ -1,7 +1,13 @@
+pub mod distribution;
+pub mod edition;
 pub mod group;
+pub mod hook;
 pub mod manager;
 pub mod mint;
 
+pub use distribution::*;
+pub use edition::*;
 pub use group::*;
+pub use hook::*;
 pub use manager::*;
 pub use mint::*;
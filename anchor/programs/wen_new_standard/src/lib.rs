@@ -4,12 +4,14 @@ use anchor_lang::prelude::*;
 
 pub mod custom_cpi;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 pub use custom_cpi::*;
 pub use errors::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 pub use utils::*;
@@ -46,6 +48,36 @@ pub mod wen_new_standard {
         instructions::group::update::handler(ctx, args)
     }
 
+    /// propose a new update authority for a group
+    pub fn transfer_group_authority(
+        ctx: Context<TransferGroupAuthority>,
+        args: TransferGroupAuthorityArgs,
+    ) -> Result<()> {
+        instructions::group::transfer::handler(ctx, args)
+    }
+
+    /// accept the update authority of a group
+    pub fn accept_group_authority(ctx: Context<AcceptGroupAuthority>) -> Result<()> {
+        instructions::group::accept::handler(ctx)
+    }
+
+    /// cancel a pending group authority transfer, giving the metadata authority back
+    pub fn cancel_group_authority_transfer(
+        ctx: Context<CancelGroupAuthorityTransfer>,
+    ) -> Result<()> {
+        instructions::group::cancel::handler(ctx)
+    }
+
+    /// move a group to the layout with a member count
+    pub fn migrate_group_account(ctx: Context<MigrateGroupAccount>) -> Result<()> {
+        instructions::group::migrate::handler(ctx)
+    }
+
+    /// close an empty group
+    pub fn close_group_account(ctx: Context<CloseGroupAccount>) -> Result<()> {
+        instructions::group::close::handler(ctx)
+    }
+
     /// create mint
     pub fn create_mint_account(
         ctx: Context<CreateMintAccount>,
@@ -59,6 +91,11 @@ pub mod wen_new_standard {
         instructions::mint::group::add::handler(ctx)
     }
 
+    /// remove mint from group
+    pub fn remove_mint_from_group(ctx: Context<RemoveGroup>) -> Result<()> {
+        instructions::mint::group::remove::handler(ctx)
+    }
+
     /// add additional metadata to mint
     pub fn add_metadata(ctx: Context<AddMetadata>, args: Vec<AddMetadataArgs>) -> Result<()> {
         instructions::mint::metadata::add::handler(ctx, args)
@@ -72,6 +109,19 @@ pub mod wen_new_standard {
         instructions::mint::metadata::remove::handler(ctx, args)
     }
 
+    /// add royalties to mint
+    pub fn add_royalties(ctx: Context<AddRoyalties>, args: UpdateRoyaltiesArgs) -> Result<()> {
+        instructions::mint::royalties::add::handler(ctx, args)
+    }
+
+    /// modify royalties of mint
+    pub fn modify_royalties(
+        ctx: Context<ModifyRoyalties>,
+        args: UpdateRoyaltiesArgs,
+    ) -> Result<()> {
+        instructions::mint::royalties::modify::handler(ctx, args)
+    }
+
     /// freeze mint
     pub fn freeze_mint_account(ctx: Context<FreezeDelegatedAccount>) -> Result<()> {
         instructions::mint::freeze::handler(ctx)
@@ -82,8 +132,64 @@ pub mod wen_new_standard {
         instructions::mint::thaw::handler(ctx)
     }
 
+    /// finalize mint, removing the mint authority
+    pub fn finalize_mint(ctx: Context<FinalizeMintAccount>) -> Result<()> {
+        instructions::mint::finalize::handler(ctx)
+    }
+
     /// burn mint
     pub fn burn_mint_account(ctx: Context<BurnMintAccount>) -> Result<()> {
         instructions::mint::burn::handler(ctx)
     }
+
+    /// burn mint that is not a member of any group
+    pub fn burn_non_member_mint_account(ctx: Context<BurnNonMemberMintAccount>) -> Result<()> {
+        instructions::mint::burn_non_member::handler(ctx)
+    }
+
+    /*
+        Edition instructions
+    */
+    /// turn a group into a master edition, its mint metadata becomes the print template
+    pub fn create_master_edition(ctx: Context<CreateMasterEdition>) -> Result<()> {
+        instructions::edition::create::handler(ctx)
+    }
+
+    /// print a numbered edition of a master edition
+    pub fn print_edition(ctx: Context<PrintEdition>) -> Result<()> {
+        instructions::edition::print::handler(ctx)
+    }
+
+    /*
+        Distribution instructions
+    */
+    /// distribute the royalties of a sale among the creators
+    pub fn distribute_royalties<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeRoyalties<'info>>,
+        args: DistributeRoyaltiesArgs,
+    ) -> Result<()> {
+        instructions::distribution::distribute::handler(ctx, args)
+    }
+
+    /*
+        Transfer hook instructions
+    */
+    /// init extra account meta list for the transfer hook
+    pub fn initialize_extra_meta_list(ctx: Context<InitializeExtraMetaList>) -> Result<()> {
+        instructions::hook::init::handler(ctx)
+    }
+
+    /// approve transfer, paying royalties to the creators
+    pub fn approve_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveTransfer<'info>>,
+        args: ApproveTransferArgs,
+    ) -> Result<()> {
+        instructions::hook::approve::handler(ctx, args)
+    }
+
+    /// execute transfer hook
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn execute(ctx: Context<ExecuteTransferHook>, amount: u64) -> Result<()> {
+        instructions::hook::execute::handler(ctx, amount)
+    }
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::MetadataErrors;
+
+/// Data struct for a `MasterEdition`, turns a group into a template for numbered prints
+#[account()]
+#[derive(InitSpace)]
+pub struct MasterEdition {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// The number of editions printed so far, burned prints keep their number
+    pub supply: u32,
+    /// The group max size when the master edition was created, caps the supply
+    pub max_supply: u32,
+}
+
+impl MasterEdition {
+    /// Increment the supply, returning the new edition number
+    pub fn increment_supply(&mut self) -> Result<u32> {
+        let edition = self
+            .supply
+            .checked_add(1)
+            .ok_or(MetadataErrors::SizeExceedsMaxSize)?;
+        if edition > self.max_supply {
+            return Err(MetadataErrors::SizeExceedsMaxSize.into());
+        }
+        self.supply = edition;
+        Ok(edition)
+    }
+}
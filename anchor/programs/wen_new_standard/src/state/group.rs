@@ -16,6 +16,21 @@ pub struct TokenGroup {
     pub size: u32,
     /// The maximum number of group members
     pub max_size: u32,
+    /// The number of members ever added, member numbers are never reused
+    pub member_count: u32,
+}
+
+/// `TokenGroup` layout before `member_count`, groups in it go through `migrate_group_account`
+#[derive(AnchorDeserialize)]
+pub struct TokenGroupV1 {
+    pub update_authority: Pubkey,
+    pub mint: Pubkey,
+    pub size: u32,
+    pub max_size: u32,
+}
+
+impl TokenGroupV1 {
+    pub const LEN: usize = 32 + 32 + 4 + 4;
 }
 
 impl TokenGroup {
@@ -26,6 +41,7 @@ impl TokenGroup {
             update_authority,
             size: 0,
             max_size,
+            member_count: 0,
         }
     }
 
@@ -53,9 +69,8 @@ impl TokenGroup {
         Ok(new_size)
     }
 
-    /// Increment the size for a group, returning the new size
+    /// Decrement the size for a group, returning the new size
     pub fn decrement_size(&mut self) -> Result<u32> {
-        // The new size cannot be greater than the max size
         let new_size = self
             .size
             .checked_sub(1)
@@ -63,4 +78,23 @@ impl TokenGroup {
         self.size = new_size;
         Ok(new_size)
     }
+
+    /// Increment the member count for a group, returning the number of the new member
+    pub fn increment_member_count(&mut self) -> Result<u32> {
+        self.member_count = self
+            .member_count
+            .checked_add(1)
+            .ok_or::<ProgramError>(PodSliceError::CalculationFailure.into())?;
+        Ok(self.member_count)
+    }
+}
+
+/// Data struct for a pending `TokenGroup` authority transfer
+#[account()]
+#[derive(InitSpace)]
+pub struct GroupAuthorityTransfer {
+    /// The pubkey of the `TokenGroup`
+    pub group: Pubkey,
+    /// The authority proposed to take over the group
+    pub new_authority: Pubkey,
 }
@@ -1,4 +1,9 @@
-use crate::{APPROVE_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED};
+use std::str::FromStr;
+
+use crate::{
+    MetadataErrors, APPROVE_ACCOUNT_SEED, EDITION_FIELD, MAX_SUPPLY_FIELD, META_LIST_ACCOUNT_SEED,
+    ROYALTY_BASIS_POINTS_FIELD,
+};
 use anchor_lang::{
     prelude::Result,
     solana_program::{
@@ -8,6 +13,7 @@ use anchor_lang::{
         pubkey::Pubkey,
         rent::Rent,
         system_instruction::transfer,
+        system_program,
         sysvar::Sysvar,
     },
     Lamports,
@@ -19,6 +25,7 @@ use anchor_spl::token_interface::spl_token_2022::{
 };
 use spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList};
 use spl_token_metadata_interface::state::TokenMetadata;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 
 pub fn get_bump_in_seed_form(bump: &u8) -> [u8; 1] {
     let bump_val = *bump;
@@ -42,6 +49,23 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     Ok(())
 }
 
+/// Closes a PDA of this program into `destination`, skipping it when it was never created
+pub fn close_program_account<'info>(
+    account: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+) -> Result<()> {
+    if account.owner != &crate::id() {
+        return Ok(());
+    }
+
+    destination.add_lamports(account.get_lamports())?;
+    account.sub_lamports(account.get_lamports())?;
+    account.assign(&system_program::ID);
+    account.realloc(0, false)?;
+
+    Ok(())
+}
+
 pub fn get_mint_metadata(account: &mut AccountInfo) -> Result<TokenMetadata> {
     let mint_data = account.data.borrow();
     let mint_with_extension = StateWithExtensions::<Mint>::unpack(&mint_data)?;
@@ -85,3 +109,87 @@ pub fn get_meta_list(approve_account: Pubkey) -> Vec<ExtraAccountMeta> {
 pub fn get_meta_list_size(approve_account: Pubkey) -> usize {
     ExtraAccountMetaList::size_of(get_meta_list(approve_account).len()).unwrap()
 }
+
+/// Writes the extra account metas the transfer hook of `mint` resolves, into an allocated account
+pub fn write_extra_meta_list(extra_metas_account: &AccountInfo, mint: Pubkey) -> Result<()> {
+    let mut data = extra_metas_account.try_borrow_mut_data()?;
+
+    // the approve account is the only extra account the hook needs
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut data,
+        &get_meta_list(get_approve_account_pda(mint)),
+    )?;
+
+    Ok(())
+}
+
+/// Fields managed by WNS itself, which can not be set through the metadata instructions
+pub fn is_reserved_field(field: &str) -> bool {
+    [ROYALTY_BASIS_POINTS_FIELD, EDITION_FIELD, MAX_SUPPLY_FIELD].contains(&field)
+}
+
+/// Free form metadata fields can neither be reserved fields nor pubkeys, which hold creator shares
+pub fn validate_metadata_field(field: &str) -> Result<()> {
+    if is_reserved_field(field) || Pubkey::from_str(field).is_ok() {
+        return Err(MetadataErrors::InvalidField.into());
+    }
+    Ok(())
+}
+
+pub fn has_royalties(metadata: &TokenMetadata) -> bool {
+    metadata
+        .additional_metadata
+        .iter()
+        .any(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD)
+}
+
+/// Royalty basis points stored in the mint metadata, 0 when royalties are not set
+pub fn get_royalty_basis_points(metadata: &TokenMetadata) -> Result<u16> {
+    match metadata
+        .additional_metadata
+        .iter()
+        .find(|(key, _)| key == ROYALTY_BASIS_POINTS_FIELD)
+    {
+        Some((_, value)) => value
+            .parse::<u16>()
+            .map_err(|_| MetadataErrors::RoyaltyBasisPointsInvalid.into()),
+        None => Ok(0),
+    }
+}
+
+pub fn get_royalty_amount(metadata: &TokenMetadata, amount: u64) -> Result<u64> {
+    let royalty_basis_points = get_royalty_basis_points(metadata)?;
+    let royalty_amount = u128::from(amount) * u128::from(royalty_basis_points) / 10000;
+
+    u64::try_from(royalty_amount).map_err(|_| MetadataErrors::RoyaltyBasisPointsInvalid.into())
+}
+
+/// Creators are stored as metadata fields keyed by their pubkey, with their share as value
+pub fn get_creator_shares(metadata: &TokenMetadata) -> Result<Vec<(Pubkey, u8)>> {
+    let mut creators = vec![];
+    for (key, value) in metadata.additional_metadata.iter() {
+        if let Ok(creator) = Pubkey::from_str(key) {
+            let share = value
+                .parse::<u8>()
+                .map_err(|_| MetadataErrors::CreatorShareInvalid)?;
+            creators.push((creator, share));
+        }
+    }
+    Ok(creators)
+}
+
+/// Splits the royalty amount among the creators according to their shares
+pub fn get_creator_amounts(
+    metadata: &TokenMetadata,
+    royalty_amount: u64,
+) -> Result<Vec<(Pubkey, u64)>> {
+    get_creator_shares(metadata)?
+        .into_iter()
+        .map(|(creator, share)| {
+            let creator_amount = u128::from(royalty_amount) * u128::from(share) / 100;
+            u64::try_from(creator_amount)
+                .map(|amount| (creator, amount))
+                .map_err(|_| MetadataErrors::CreatorShareInvalid.into())
+        })
+        .collect()
+}